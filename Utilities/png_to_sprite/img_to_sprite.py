@@ -7,6 +7,7 @@
 #-------------------------------
 
 #USAGE
+#No longer needed for engine sprites, build.rs generates them from every png in assets/sprites.
 #Put resulting file in the sprites folder and declare it a public mod in mod.rs.
 
 #In the code when you want to initialize a sprite you can insert the sprite instance directly into the object you are attaching to or
@@ -3,10 +3,27 @@
 // -------------------------------
 //  declares c dependencies during build
 //  i could have done everything c code does
-//  with native rust crates but learning to do this is real important
+//  with native rust crates but learning to do this is real important.
+//  also turns every png in assets/sprites into
+//  run length encoded sprite constants in OUT_DIR
 // -------------------------------
 use cc::Build;
-use std::fs::read_dir;
+use png::{Decoder, Transformations};
+use std::{
+    collections::HashSet,
+    env,
+    fmt::Write,
+    fs::{create_dir_all, read, read_dir, write, File},
+    io::BufReader,
+    path::{Path, PathBuf},
+    process::exit,
+};
+
+//same conversion the runtime loader uses, so generated sprites match loaded ones pixel for pixel
+#[path = "src/graphics/sprites/convert.rs"]
+mod convert;
+
+const SPRITE_DIR:&str = "assets/sprites";
 
 fn main(){
     let mut build = Build::new();
@@ -22,6 +39,122 @@ fn main(){
     build.include("c").compile("img_processing");
 
     //tells cargo to only rebuild if changed
-    println!("cargo:rerun-if-changed=c");
-    
-}
\ No newline at end of file
+    println!("cargo:rerun-if-changed=c_src");
+
+    generate_sprites();
+}
+
+//decodes every png in the sprite folder and writes one module per sprite into OUT_DIR/sprites.rs, pixel data goes next to it as runs.
+//only the runs end up in the binary, the full image sprite is decoded from them the first time it is used
+fn generate_sprites(){
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    let data_dir = out_dir.join("sprites");
+    create_dir_all(&data_dir).unwrap();
+    //watching the folder catches added and removed files, watching convert catches changes to how pixels are converted
+    println!("cargo:rerun-if-changed={SPRITE_DIR}");
+    println!("cargo:rerun-if-changed=src/graphics/sprites/convert.rs");
+
+    let mut paths:Vec<PathBuf> = match read_dir(SPRITE_DIR){
+        Ok(entries) => entries
+            .map(|entry|entry.unwrap().path())
+            .filter(|path|path.extension().and_then(|s|s.to_str()).is_some_and(|extension|extension.eq_ignore_ascii_case("png")))
+            .collect(),
+        //no sprite folder just means no generated sprites
+        Err(_) => Vec::new(),
+    };
+    //sorted so the generated file doesnt change between machines
+    paths.sort();
+
+    let mut generated = String::from("// generated by build.rs from assets/sprites, do not edit\n");
+    let mut names = HashSet::new();
+    for path in paths{
+        println!("cargo:rerun-if-changed={}", path.display());
+        let name = module_name(&path);
+        if !names.insert(name.clone()){
+            fail(&format!("sprite {} clashes with another sprite called {name}", path.display()));
+        }
+        let (data, width, height) = match decode(&path){
+            Ok(image) => image,
+            Err(error) => fail(&format!("could not decode sprite {}: {error}", path.display())),
+        };
+        let runs_path = data_dir.join(format!("{name}.rle"));
+        write_sprite_module(&mut generated, &name, &path, &runs_path, &data, width, height);
+    }
+    write_if_changed(&out_dir.join("sprites.rs"), generated.as_bytes());
+}
+
+//premultiplied rgba along with the width and height
+fn decode(path:&Path) -> Result<(Vec<u8>, usize, usize), String>{
+    let file = File::open(path).map_err(|error|error.to_string())?;
+    let mut decoder = Decoder::new(BufReader::new(file));
+    decoder.set_transformations(Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info().map_err(|error|error.to_string())?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer).map_err(|error|error.to_string())?;
+    buffer.truncate(info.buffer_size());
+    let mut data = convert::expand_to_rgba(&buffer, info.color_type).ok_or("palette was not expanded")?;
+    convert::premultiply(&mut data);
+    Ok((data, info.width as usize, info.height as usize))
+}
+
+fn write_sprite_module(generated:&mut String, name:&str, source:&Path, runs_path:&Path, data:&[u8], width:usize, height:usize){
+    let upper = name.to_uppercase();
+    let (translucent, solid) = convert::split_ranges(data, width);
+    let (runs, rle_translucent, rle_solid) = convert::encode_runs(data, &translucent, &solid);
+    write_if_changed(runs_path, &runs);
+    let ranges = |ranges:&[convert::RangeFields]|ranges.iter()
+        .map(|[line, src_index, src_end_index, x_begin, x_end]|format!(
+            "PixelRange{{line:{line},src_index:{src_index},src_end_index:{src_end_index},x_begin:{x_begin},x_end:{x_end}}},\n"
+        ))
+        .collect::<String>();
+    write!(generated, "
+///generated from {source}
+///dimensions: {width}x{height}
+pub mod {name}_sprite{{
+use std::sync::LazyLock;
+use crate::{{graphics::sprites::{{rle::RleSprite, Sprite, SpriteSlice, PixelRange}}, shared::Size}};
+
+///decoded from {upper}_RLE the first time it is used, clones share the pixels
+pub static {upper}_SPRITE:LazyLock<Sprite> = LazyLock::new(||{upper}_RLE.decode());
+
+///run length encoded, {runs_len} bytes of runs instead of {data_len} bytes of rgba
+pub const {upper}_RLE:RleSprite = RleSprite{{runs:SpriteSlice::Static(include_bytes!({runs_path:?})),size: Size{{width:{width},height:{height}}},
+translucent_ranges:SpriteSlice::Static(&{upper}_RLE_TRANSLUCENT), solid_ranges:SpriteSlice::Static(&{upper}_RLE_SOLID)}};
+
+const {upper}_RLE_TRANSLUCENT:[PixelRange;{translucent_count}]= [
+{rle_translucent_ranges}];
+
+const {upper}_RLE_SOLID:[PixelRange;{solid_count}]= [
+{rle_solid_ranges}];
+}}
+",
+        source = source.display(),
+        translucent_count = translucent.len(),
+        solid_count = solid.len(),
+        runs_len = runs.len(),
+        data_len = data.len(),
+        rle_translucent_ranges = ranges(&rle_translucent),
+        rle_solid_ranges = ranges(&rle_solid),
+    ).unwrap();
+}
+
+//lowercase file stem with anything that cant go in an identifier turned into underscores
+fn module_name(path:&Path) -> String{
+    let stem = path.file_stem().and_then(|stem|stem.to_str()).unwrap_or_default();
+    let name:String = stem.chars().map(|c|if c.is_ascii_alphanumeric(){c.to_ascii_lowercase()}else{'_'}).collect();
+    if !name.starts_with(|c:char|c.is_ascii_alphabetic()){
+        fail(&format!("sprite {} needs a file name starting with a letter to become a module name", path.display()));
+    }
+    name
+}
+
+//only touches files whose contents changed, so the crate isnt rebuilt when the build script reruns for nothing
+fn write_if_changed(path:&Path, contents:&[u8]){
+    if read(path).is_ok_and(|existing|existing == contents){return;}
+    write(path, contents).unwrap_or_else(|error|fail(&format!("could not write {}: {error}", path.display())));
+}
+
+fn fail(message:&str) -> !{
+    eprintln!("error: {message}");
+    exit(1);
+}
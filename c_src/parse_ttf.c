@@ -2,6 +2,7 @@
 #include <stdlib.h>
 #include <stdio.h>
 #include <stdint.h>
+#include <string.h>
 #include "stb_truetype.h"
 
 //Loaded font Type contains a fat pointer to font data and sbtt_fontinfo handle
@@ -12,6 +13,10 @@ typedef struct{
     size_t font_size;
     //stbtt_fonntinfo handle
     stbtt_fontinfo* font_info_ptr;
+    //nonzero if font_ptr was malloced here and gets freed on unload, zero if the caller owns the buffer and keeps it alive
+    int owned;
+    //index of the face stbtt is reading, always zero unless the font is a collection
+    int face;
 } LoadedFont;
 
 //contains the width and height of the glyph generated from ttf file along with the a pointer to the generated black and white bm
@@ -26,39 +31,32 @@ typedef struct{
     int xoff; int yoff;
 } LoadedGlyph;
 
-//implements loading function in c to be called in rust program
-LoadedFont c_load_font(const char* font_path, const char* font_name){
-    //opens font file
-    FILE *fontfile = fopen(font_path, "rb");
-    //checks if font file exists and if so returns null
-    if(!fontfile){
-        fprintf(stderr,"Could not find font %s in fonts folder\n",font_name);
-        return (LoadedFont){NULL,0,NULL};
-    }
-    //finds the bounds of sprite file in memory
-    fseek(fontfile, 0, SEEK_END);
-    long font_size = ftell(fontfile);
-    //goes to beginning of file again
-    fseek(fontfile,0, SEEK_SET);
-    //allocates memory for the font file
-    unsigned char *font_ptr = malloc(font_size);
-    //if buffer is null close file and return null and free buf
-    if(!font_ptr){
-        fprintf(stderr,"Malloc for %s data failed\n", font_name);
-        fclose(fontfile);
-        free (font_ptr);
-        return (LoadedFont){NULL,0,NULL};
-    }
-    //reads the file, saves it to buffer and outputs size for error checking
-    size_t items_mem = fread(font_ptr, 1, font_size, fontfile);
-    //closes fontfile
-    fclose(fontfile);
-    //check if the read was successful
-    if (items_mem != (size_t) font_size){
-        fprintf(stderr,"Read of %s was unsuccessful\n", font_name);
-        //if not frees buffer and closes file while returning a null loaded font_t
-        free(font_ptr);
-        return (LoadedFont){NULL,0,NULL};
+//faces in font data, one for a plain font and however many a collection says it holds. zero if a collection is too short to say
+static int face_count(const unsigned char* font_ptr, size_t font_size){
+    //a collection header is 12 bytes followed by one offset for every face
+    if (stbtt__isfont((stbtt_uint8*)font_ptr)){return 1;}
+    if (font_size < 12){return 0;}
+    int count = stbtt_GetNumberOfFonts(font_ptr);
+    if (count < 0 || (size_t)count > (font_size - 12)/4){return 0;}
+    return count;
+}
+
+//offset of the table directory for face, -1 if there is no such face or its offset points outside the data
+static int face_offset(const unsigned char* font_ptr, size_t font_size, int face){
+    if (face < 0 || face >= face_count(font_ptr, font_size)){return -1;}
+    int offset = stbtt_GetFontOffsetForIndex(font_ptr, face);
+    //the table directory header alone is 12 bytes
+    if (offset < 0 || (size_t)offset + 12 > font_size){return -1;}
+    return offset;
+}
+
+//checks the header of font data and hands it to stbtt. font_ptr is freed on failure if owned is set, otherwise it is left to the caller
+static LoadedFont init_font(unsigned char* font_ptr, size_t font_size, int owned, const char* font_name){
+    //too short to even hold a header, reading one would run off the end of the buffer
+    if (font_size < 4){
+        fprintf(stderr,"Font file %s is too short to be a font\n", font_name);
+        if (owned){free(font_ptr);}
+        return (LoadedFont){NULL,0,NULL,0,0};
     }
     //creates big endian containing the header for comparisons. god pattern matching is rough in c
     uint32_t header = 
@@ -72,37 +70,93 @@ LoadedFont c_load_font(const char* font_path, const char* font_name){
     if(!(((uint32_t){0x00010000} == header)||
     ((uint32_t){0x4F54544F} == header)||
     ((uint32_t){0x74727565} == header)||
-    ((uint32_t){0x77746366} == header))){
+    ((uint32_t){0x74746366} == header))){
         fprintf(stderr,"Font file %s has invalid header\n", font_name);
-        free(font_ptr);
-        return (LoadedFont){NULL,0,NULL};
+        if (owned){free(font_ptr);}
+        return (LoadedFont){NULL,0,NULL,0,0};
     }
     //allocates new block of memory for stbtt fontinfo to live
     stbtt_fontinfo* font_info_ptr = malloc(sizeof(stbtt_fontinfo));
     //checks for malloc failure
     if (!font_info_ptr){
         fprintf(stderr, "Malloc for sbtt_font info for font %s\n", font_name);
-        return (LoadedFont){NULL,0,NULL};
+        if (owned){free(font_ptr);}
+        return (LoadedFont){NULL,0,NULL,0,0};
     }
-    //inits, then if it initializes successfully return a pointer to the loaded font instance in memory
-    int loaded = stbtt_InitFont(font_info_ptr, font_ptr, 0);
+    //inits the first face, then if it initializes successfully return a pointer to the loaded font instance in memory
+    int offset = face_offset(font_ptr, font_size, 0);
+    int loaded = offset >= 0 && stbtt_InitFont(font_info_ptr, font_ptr, offset);
     if (loaded){
         return (LoadedFont){
             font_ptr,font_size,
             font_info_ptr,
+            owned,
+            0,
         };
     }
     fprintf(stderr, "Failed to load font %s\n",font_name);
     // frees buffer and else returns null loaded font
-    free(font_ptr);
+    if (owned){free(font_ptr);}
     free(font_info_ptr);
-    return (LoadedFont){NULL,0,NULL};
+    return (LoadedFont){NULL,0,NULL,0,0};
+}
+
+//loads a font from font data rust has already read and validated. with copy set the data is copied into a buffer freed on unload,
+//otherwise it is used in place so the caller has to keep it alive for as long as the font and it is never freed here
+LoadedFont c_load_font_from_memory(const unsigned char* font_ptr, size_t font_size, const char* font_name, int copy){
+    if (!font_ptr){
+        fprintf(stderr,"Font %s was given a null buffer\n", font_name);
+        return (LoadedFont){NULL,0,NULL,0,0};
+    }
+    if (!copy){
+        //stbtt only ever reads font data, the cast is just so it fits in the same struct as owned buffers
+        return init_font((unsigned char*)font_ptr, font_size, 0, font_name);
+    }
+    //allocates memory for the font data, at least a byte so empty data doesnt look like a failed malloc
+    unsigned char *owned_ptr = malloc(font_size > 0 ? font_size : 1);
+    if(!owned_ptr){
+        fprintf(stderr,"Malloc for %s data failed\n", font_name);
+        return (LoadedFont){NULL,0,NULL,0,0};
+    }
+    memcpy(owned_ptr, font_ptr, font_size);
+    return init_font(owned_ptr, font_size, 1, font_name);
+}
+
+//number of faces in the font, more than one only for collections
+int c_face_count(LoadedFont* loaded_font){
+    return face_count(loaded_font->font_ptr, loaded_font->font_size);
+}
+
+//points stbtt at another face of the same font data. returns zero and leaves the current face alone if face doesnt exist or fails to init
+int c_select_face(LoadedFont* loaded_font, int face){
+    int offset = face_offset(loaded_font->font_ptr, loaded_font->font_size, face);
+    if (offset < 0){
+        fprintf(stderr,"Font has no face %d\n", face);
+        return 0;
+    }
+    //inits into a copy first so a face that fails doesnt break the one already loaded
+    stbtt_fontinfo font_info;
+    if (!stbtt_InitFont(&font_info, loaded_font->font_ptr, offset)){
+        fprintf(stderr,"Failed to load face %d\n", face);
+        return 0;
+    }
+    *loaded_font->font_info_ptr = font_info;
+    loaded_font->face = face;
+    return 1;
+}
+
+//returns a pointer into the font data to the name table entry name_id for the given platform, encoding and language, null if there isnt one.
+//the string isnt null terminated, its length in bytes is written to length
+const char* c_get_font_name_string(LoadedFont* loaded_font, int* length, int platform_id, int encoding_id, int language_id, int name_id){
+    return stbtt_GetFontNameString(loaded_font->font_info_ptr, length, platform_id, encoding_id, language_id, name_id);
 }
 
 //called to free the memory occupied by font once font is unloaded. called by rust on drop
 void c_unload_font(LoadedFont* loaded_font){
-    //frees font file and stbtt handle from memory
-    free(loaded_font->font_ptr);
+    //frees font file if it was loaded here and stbtt handle from memory
+    if (loaded_font->owned){
+        free(loaded_font->font_ptr);
+    }
     free(loaded_font->font_info_ptr);
 }
 
@@ -121,14 +175,15 @@ LoadedGlyph c_generate_glyph(LoadedFont* loaded_font, int codepoint, float px){
     );
     //calculates size of glyph bmp
     size_t size = width*height;
-    //if stbtt_GetCodepointBitmap returns a null loaded glyph
-    if (glyph_ptr == NULL){
-        fprintf(stderr,"stbtt returned null pointer to glyph data");
+    //if size is equal to zero return null loadedglyph, checked first because stbtt returns null for empty bitmaps too.
+    //not printed, glyphs with nothing to draw are normal and rust tells the caller what happened
+    if (size == 0){
+        free(glyph_ptr);
         return (LoadedGlyph){0};
     }
-    //if size is equal to zero return null loadedglyph
-    if (size == 0){
-        fprintf(stderr,"stbtt returned glyph with size zero");
+    //if stbtt_GetCodepointBitmap returns a null loaded glyph
+    if (glyph_ptr == NULL){
+        fprintf(stderr,"stbtt returned null pointer to glyph data\n");
         return (LoadedGlyph){0};
     }
 
@@ -139,16 +194,83 @@ LoadedGlyph c_generate_glyph(LoadedFont* loaded_font, int codepoint, float px){
         xoff, yoff,
     };
 }
+//generates a signed distance field for codepoint instead of a bitmap. every pixel is onedge_value on the outline, going up by
+//pixel_dist_scale for each pixel further inside and down for each pixel outside, with padding pixels of room around the outline.
+//freed with c_unload_glyph like any other glyph
+LoadedGlyph c_generate_sdf_glyph(LoadedFont* loaded_font, int codepoint, float px, int padding, unsigned char onedge_value, float pixel_dist_scale){
+    int width, height, xoff, yoff;
+    float scale = stbtt_ScaleForPixelHeight(loaded_font->font_info_ptr, px);
+    unsigned char *glyph_ptr = stbtt_GetCodepointSDF(
+        loaded_font->font_info_ptr,
+        scale,
+        codepoint,
+        padding, onedge_value, pixel_dist_scale,
+        &width,&height,&xoff,&yoff
+    );
+    //stbtt returns null for glyphs with nothing to draw, rust tells the caller why
+    if (glyph_ptr == NULL){
+        return (LoadedGlyph){0};
+    }
+    return (LoadedGlyph){
+        glyph_ptr, (size_t)width*height,
+        width, height,
+        xoff, yoff,
+    };
+}
 //unloads malloc held by glyph
 void c_unload_glyph(LoadedGlyph* loaded_glyph){
     //frees the memory associated with the glyph
     free(loaded_glyph->ptr);
 }
-//inputs 2 chars and returns an int 
-inline int c_get_kerning(LoadedFont* loaded_font, int ch1, int ch2){
+//inputs 2 chars and returns an int, unscaled so multiply by the scale for the font size.
+//not inline, a plain inline definition doesnt give rust a symbol to link against
+int c_get_kerning(LoadedFont* loaded_font, int ch1, int ch2){
     //passes a ptr from the stbb_fontinfo struct into stbtt_GetCodepointKernAdvance along with both characters. generates kerning and passes it back to rust
     return stbtt_GetCodepointKernAdvance(
         loaded_font->font_info_ptr, 
         ch1, ch2
     );
 }
+
+//scale factor that turns unscaled font units into pixels for a font px tall
+float c_scale_for_pixel_height(LoadedFont* loaded_font, float px){
+    return stbtt_ScaleForPixelHeight(loaded_font->font_info_ptr, px);
+}
+
+//writes the advance width and left side bearing of codepoint in unscaled font units
+void c_get_codepoint_hmetrics(LoadedFont* loaded_font, int codepoint, int* advance_width, int* left_side_bearing){
+    stbtt_GetCodepointHMetrics(loaded_font->font_info_ptr, codepoint, advance_width, left_side_bearing);
+}
+
+//returns nonzero if codepoint has no outline to draw, like a space. lets rust skip generating a bitmap that would come back empty
+int c_is_glyph_empty(LoadedFont* loaded_font, int codepoint){
+    return stbtt_IsGlyphEmpty(
+        loaded_font->font_info_ptr,
+        stbtt_FindGlyphIndex(loaded_font->font_info_ptr, codepoint)
+    );
+}
+
+//writes the ascent, descent and line gap of the font in unscaled font units. descent is negative since it sits below the baseline
+void c_get_font_vmetrics(LoadedFont* loaded_font, int* ascent, int* descent, int* line_gap){
+    stbtt_GetFontVMetrics(loaded_font->font_info_ptr, ascent, descent, line_gap);
+}
+
+//returns the index of the glyph for codepoint, zero if the font has no glyph for it
+int c_find_glyph_index(LoadedFont* loaded_font, int codepoint){
+    return stbtt_FindGlyphIndex(loaded_font->font_info_ptr, codepoint);
+}
+
+//writes the bounding box of the outline of codepoint in unscaled font units with y going up. returns zero if it has no outline
+int c_get_codepoint_box(LoadedFont* loaded_font, int codepoint, int* x0, int* y0, int* x1, int* y1){
+    return stbtt_GetCodepointBox(loaded_font->font_info_ptr, codepoint, x0, y0, x1, y1);
+}
+
+//writes the box the bitmap of codepoint would cover at scale, in pixels with y going down. same box c_generate_glyph would give without rasterizing
+void c_get_codepoint_bitmap_box(LoadedFont* loaded_font, int codepoint, float scale, int* x0, int* y0, int* x1, int* y1){
+    stbtt_GetCodepointBitmapBox(loaded_font->font_info_ptr, codepoint, scale, scale, x0, y0, x1, y1);
+}
+
+//writes the box every glyph in the font fits in, unscaled font units with y going up
+void c_get_font_bounding_box(LoadedFont* loaded_font, int* x0, int* y0, int* x1, int* y1){
+    stbtt_GetFontBoundingBox(loaded_font->font_info_ptr, x0, y0, x1, y1);
+}
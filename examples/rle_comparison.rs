@@ -0,0 +1,62 @@
+// ===============================
+//        RLE_COMPARISON.RS
+// -------------------------------
+//  prints how much memory a run length encoded sprite
+//  takes next to an object made from it, and how long
+//  drawing and decoding take. timings only mean
+//  something in release:
+//  cargo run --release --example rle_comparison
+// -------------------------------
+
+use std::{hint::black_box, mem::size_of, time::{Duration, Instant}};
+
+use josengine::{
+    graphics::{render_target::Framebuffer, renderer::RenderHook, sprites::{
+        blackbuck_sprite::BLACKBUCK_RLE, main_menu_sprite::MAIN_MENU_RLE, mario_sprite::MARIO_RLE, rle::RleSprite,
+        transparency2_sprite::TRANSPARENCY2_RLE, PixelRange, Sprite,
+    }},
+    object::Object,
+    shared::{Point, Rect, BPP},
+};
+
+const ROUNDS:u32 = 200;
+
+//average time draw takes over ROUNDS runs
+fn time(draw:&mut dyn FnMut()) -> Duration{
+    let start = Instant::now();
+    for _ in 0..ROUNDS{draw();}
+    start.elapsed()/ROUNDS
+}
+
+//bytes of pixels and ranges a sprite holds
+fn sprite_bytes(sprite:&Sprite) -> usize{
+    sprite.raw_len() + (sprite.solid_ranges.len() + sprite.translucent_ranges.len())*size_of::<PixelRange>()
+}
+
+//bytes of runs and ranges an rle sprite holds
+fn rle_bytes(rle:&RleSprite) -> usize{
+    rle.encoded_len() + (rle.solid_ranges.len() + rle.translucent_ranges.len())*size_of::<PixelRange>()
+}
+
+fn main(){
+    let sprites:[(&str, RleSprite);4] = [
+        ("blackbuck", BLACKBUCK_RLE),
+        ("main_menu", MAIN_MENU_RLE),
+        ("mario", MARIO_RLE),
+        ("transparency2", TRANSPARENCY2_RLE),
+    ];
+    println!("{:<14}{:>10}{:>10}{:>8}{:>12}{:>12}{:>12}", "sprite", "rle", "object", "ratio", "object blit", "rle blit", "decode");
+    for (name, rle) in sprites{
+        let size = rle.size;
+        let clip = Rect::of_size(size);
+        let mut hook = RenderHook::create_render_hook(size, Framebuffer::new(size));
+        //objects only hold the decoded sprite, the runs stay in the binary
+        let object = Object::from_rle(Point::at(0.0, 0.0), &rle);
+        let mut buffer = vec![0;size.pixels()*BPP];
+        let object_time = time(&mut ||hook.buffer_object(&object, black_box(&mut buffer), &size, &clip));
+        let rle_time = time(&mut ||hook.buffer_rle(&rle, Point::at(0.0, 0.0), black_box(&mut buffer), &size, &clip));
+        let decode_time = time(&mut ||{black_box(rle.decode());});
+        let (rle_len, object_len) = (rle_bytes(&rle), sprite_bytes(&object.sprite));
+        println!("{name:<14}{rle_len:>10}{object_len:>10}{:>8.2}{object_time:>12?}{rle_time:>12?}{decode_time:>12?}", rle_len as f32/object_len as f32);
+    }
+}
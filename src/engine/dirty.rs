@@ -0,0 +1,48 @@
+// ===============================
+//             DIRTY.RS
+// -------------------------------
+//  Tracks which parts of the display changed
+//  since the last frame so only those get
+//  re-composited and copied to the backbuffer
+// -------------------------------
+
+use smallvec::SmallVec;
+
+use crate::shared::Rect;
+
+///past this many separate regions everything is collapsed into one bounding rect, walking a long list costs more than the overdraw
+const MAX_REGIONS:usize = 32;
+
+///DirtyRegions is a small list of rects that need redrawing, touching rects are merged as they come in
+#[derive(Clone, Debug, Default)]
+pub struct DirtyRegions{
+    regions:SmallVec<[Rect;MAX_REGIONS]>,
+}
+
+impl DirtyRegions{
+    ///Marks rect as dirty, merging it with every region it touches
+    pub fn add(&mut self, rect:Rect){
+        if rect.is_empty(){return;}
+        let mut merged = rect;
+        //merging can make the rect touch regions it didnt before, so keep going until nothing else touches it
+        while let Some(index) = self.regions.iter().position(|region|region.touches(&merged)){
+            merged = merged.union(&self.regions.swap_remove(index));
+        }
+        self.regions.push(merged);
+        if self.regions.len() > MAX_REGIONS{
+            let bounds = self.regions.iter().fold(Rect::default(), |bounds, region|bounds.union(region));
+            self.regions.clear();
+            self.regions.push(bounds);
+        }
+    }
+    ///Rects that need redrawing, none of them touch each other
+    pub fn regions(&self) -> &[Rect]{
+        &self.regions
+    }
+    pub fn is_empty(&self) -> bool{
+        self.regions.is_empty()
+    }
+    pub fn clear(&mut self){
+        self.regions.clear();
+    }
+}
@@ -6,20 +6,25 @@
 // -------------------------------
 
 
-use std::mem::{replace, swap};
+use std::{mem::{replace, swap}, ops::Range};
 
 use crate::{
-    engine::screens::Screen, 
+    engine::{dirty::DirtyRegions, layer::{Layer, LayerVisibility}, screens::Screen}, 
     graphics::{
+        blend::{Blend, BlendMode},
         renderer::{
             Camera, 
             Pixel, 
             RenderHook
-        }
+        },
+        render_target::RenderTarget,
+        sprites::palette::Palette,
+        texthandler::Text,
+        transform::{Affine, Transform}
     }, 
     io::input::InputState,
-    object::Object,
-    shared::{Point, Size, BPP}
+    object::{Object, ObjectHandle, ObjectRegistry},
+    shared::{Point, Rect, Size, BPP}
 };
 use smallvec::SmallVec;
 
@@ -30,24 +35,49 @@ pub enum DrawCall{
     ClearDisplay,
     ///Moves Display
     Move(Point),
-    ///Takes the key for the sprite and a point to move it to and moves it to that position
-    DrawObject(usize),
-    ///takes previous pixel drawcall and saves to framebuffer
-    DrawPixels,
+    ///Takes the handle of an object and draws it at its current point
+    DrawObject(ObjectHandle),
+    ///takes previous pixel drawcalls and paints them onto the pixel canvases for a layer and z value in the order they were sent,
+    ///canvases keep their pixels until the display is cleared
+    DrawPixels(Layer, i32),
+    ///draws a string with a sized font this frame, send it again every frame the text should stay up
+    DrawText(Text),
     //Passes through a vector directly to the renderer. Keep vector in screen struct, dont constantly
     //redeclare pls
     // Passthrough(Arc<Mutex<Vec<Pixel>>>)
 }
 
 pub enum LogicCall{
-    ///initializes object into buffer
-    InitObject(usize, Object),
+    ///replaces the object behind a handle with a new object
+    InitObject(ObjectHandle, Object),
     ///sets point of object in buffer
-    SetObject(usize, Point),
+    SetObject(ObjectHandle, Point),
     ///moves object relative to its current point
-    MoveObject(usize, Point, f32),
+    MoveObject(ObjectHandle, Point, f32),
+    ///removes object from the registry, its handle is stale afterwards
+    DespawnObject(ObjectHandle),
+    ///moves object to a layer and sets its z inside that layer
+    SetObjectLayer(ObjectHandle, Layer, i32),
+    ///sets the flip, rotation and scale an object is drawn with
+    SetObjectTransform(ObjectHandle, Transform),
+    ///sets or clears the arbitrary rotation and scale of an object
+    SetObjectAffine(ObjectHandle, Option<Affine>),
+    ///switches the animator of an object to the named clip, restarts it unless the clip is already playing
+    PlayAnimation(ObjectHandle, &'static str),
+    ///turns clamping to the display on or off for an object, unclamped objects can move off screen and get clipped
+    SetObjectClamp(ObjectHandle, bool),
+    ///draws an indexed object with a palette of its own, none goes back to the sprites palette
+    SetObjectPalette(ObjectHandle, Option<Palette>),
+    ///sets the opacity, tint and blend mode an object is drawn with
+    SetObjectBlend(ObjectHandle, Blend),
+    ///cycles a range of an indexed objects palette by an amount, the object gets its own copy of the palette first
+    RotatePalette(ObjectHandle, Range<usize>, isize),
+    ///shows or hides everything drawn on a layer
+    SetLayerVisible(Layer, bool),
     ///Draws at point with color, deletes any text
     PixelAt(Point, [u8;4]),
+    ///Draws at point with color mixed into the display with a blend mode
+    BlendedPixelAt(Point, [u8;4], BlendMode),
     ///Deletes at point
     DeleteAt(Point),
     ///Fills between two points with a static string
@@ -82,11 +112,22 @@ pub struct Display{
     pub needs_init:bool,
     ///Camera buffer stores all cameras in the scene. by default only 16 are available but you can put however many you want in
     cameras:[Camera;16],
-    ///object buffer contains all the objects that the screen might write to
-    pub objects:Vec<Object>,
-    objects_max_index:usize,
-    ///display batches obect draw then executes it all at once
-    pub object_draw_buffer:SmallVec<[usize;512]>,
+    ///object registry contains all the objects that the screen spawned, addressed by generational handles
+    pub objects:ObjectRegistry,
+    ///display batches object and pixel draws, sorts them by layer and z and then executes them all at once
+    pub render_queue:SmallVec<[RenderItem;512]>,
+    ///persistent pixel layers, a stack for every layer and z that pixels were drawn to with the latest canvas of each stack last
+    canvases:SmallVec<[PixelCanvas;4]>,
+    ///text drawn this frame or the last one, kept so unchanged text isnt rasterized again every frame
+    texts:Vec<DrawnText>,
+    ///per layer visibility toggles
+    pub layers:LayerVisibility,
+    ///areas of the display that changed since the last render
+    dirty:DirtyRegions,
+    ///render frame counter, used to spot objects that stopped being drawn
+    frame:u64,
+    ///set when the whole display buffer has to go to the backbuffer instead of just the dirty regions
+    full_copy:bool,
     ///Stores a buffer containing all display draw commands
     pub draw_buffer:SmallVec<[DrawCall;256]>,
     ///Stores a buffer for all engine logical commands
@@ -112,9 +153,14 @@ impl Display{
             //the size of the smallvec stack allocated array for both draw and logic calls is set to 256 but preallocates another 256
             //in the heap so you dont have to dynamically resize if for whatever reason you go over 256 draw or logic calls in one update
             cameras:[Camera::default();16],
-            objects:vec![Object::default();2048],
-            objects_max_index:0,
-            object_draw_buffer:SmallVec::with_capacity(2048),
+            objects:ObjectRegistry::with_capacity(2048),
+            render_queue:SmallVec::with_capacity(2048),
+            canvases:SmallVec::new(),
+            texts:Vec::new(),
+            layers:LayerVisibility::default(),
+            dirty:DirtyRegions::default(),
+            frame:0,
+            full_copy:true,
             draw_buffer:SmallVec::with_capacity(2048),
             logic_buffer:SmallVec::with_capacity(2048),
             display_buffer:Vec::with_capacity(display_size.pixels()*BPP),
@@ -123,7 +169,7 @@ impl Display{
     
     }
     ///initializes the display instance with a certain screen, called on screen change
-    pub fn init_display(&mut self, input_state:&InputState, renderer:&mut RenderHook){
+    pub fn init_display<T:RenderTarget>(&mut self, input_state:&InputState, renderer:&mut RenderHook<T>){
         //clears the display buffer for the display instance
             self.draw_buffer.clear();
             //filld renderer frame buffer with zeros
@@ -136,8 +182,17 @@ impl Display{
                 let old = replace(&mut self.screen, next_screen);
                 drop(old);
             }
+            //despawns every object left over from the last screen, any handles it held are stale now
+            self.objects.clear();
+            //layers hidden by the last screen come back
+            self.layers.reset();
+            //pixels painted by the last screen go with it and the new screen starts from a full redraw
+            self.canvases.clear();
+            self.texts.clear();
+            self.objects.vacated.clear();
+            self.mark_all_dirty();
             //runs the init screen command for loaded screen
-            self.screen.init_screen(&mut self.draw_buffer, &mut self.logic_buffer);
+            self.screen.init_screen(&mut self.draw_buffer, &mut self.logic_buffer, &mut self.objects);
             //updateds display logic and render logic
             self.logic_update(input_state);
             //renders screen
@@ -152,7 +207,7 @@ impl Display{
         //only processes newest draw calls
         self.draw_buffer.clear();
         //destructures the return screen and kill from the screen update function
-        let (return_screen, kill) = self.screen.update(&mut self.logic_buffer, input_state);
+        let (return_screen, kill) = self.screen.update(&mut self.logic_buffer, &mut self.objects, input_state);
         //if the screen decides to kill the game return true to the engine to exit window
         if kill{return true;}
         //if screen returns a screen then save to displays next screen field and flags the display for update.
@@ -165,35 +220,108 @@ impl Display{
         //drains logic call commands in the logic buffer
         for command in self.logic_buffer.drain(..){
             match command{
-                LogicCall::InitObject(key, object) =>{
-                    //sets the object at index key in the object buffer to an object instance
-                    self.objects[key] = object;
-                    if key > self.objects_max_index{
-                        self.objects_max_index = key;
+                LogicCall::InitObject(handle, object) =>{
+                    //swaps the object behind the handle for the new object instance
+                    if let Err(error) = self.objects.replace(handle, object){
+                        eprintln!("{error}");
                     }
                 }
-                LogicCall::SetObject(key, point) =>{
-                    let object = &mut self.objects[key];
-                    let Point{mut x,mut y} = point;
+                LogicCall::DespawnObject(handle) =>{
+                    if let Err(error) = self.objects.despawn(handle){
+                        eprintln!("{error}");
+                    }
+                }
+                LogicCall::SetObjectLayer(handle, layer, z) =>{
+                    match self.objects.get_mut(handle){
+                        Ok(object) =>{
+                            object.layer = layer;
+                            object.z = z;
+                        }
+                        Err(error) => eprintln!("{error}"),
+                    }
+                }
+                LogicCall::SetObjectTransform(handle, transform) =>{
+                    match self.objects.get_mut(handle){
+                        Ok(object) => object.transform = transform,
+                        Err(error) => eprintln!("{error}"),
+                    }
+                }
+                LogicCall::SetObjectAffine(handle, affine) =>{
+                    match self.objects.get_mut(handle){
+                        Ok(object) => object.affine = affine,
+                        Err(error) => eprintln!("{error}"),
+                    }
+                }
+                LogicCall::PlayAnimation(handle, name) =>{
+                    let object = match self.objects.get_mut(handle){
+                        Ok(object) => object,
+                        Err(error) =>{eprintln!("{error}"); continue;}
+                    };
+                    let Some(animator) = object.animator.as_mut() else{
+                        eprintln!("Tried to play animation {name} on an object without an animator!");
+                        continue;
+                    };
+                    if animator.play(name){
+                        object.sprite = animator.sprite().clone();
+                    }else{
+                        eprintln!("Object has no animation clip called {name}!");
+                    }
+                }
+                LogicCall::SetObjectClamp(handle, clamp) =>{
+                    match self.objects.get_mut(handle){
+                        Ok(object) => object.clamp = clamp,
+                        Err(error) => eprintln!("{error}"),
+                    }
+                }
+                LogicCall::SetObjectBlend(handle, blend) =>{
+                    match self.objects.get_mut(handle){
+                        Ok(object) => object.blend = blend,
+                        Err(error) => eprintln!("{error}"),
+                    }
+                }
+                LogicCall::SetObjectPalette(handle, palette) =>{
+                    match self.objects.get_mut(handle){
+                        Ok(object) => object.palette = palette,
+                        Err(error) => eprintln!("{error}"),
+                    }
+                }
+                LogicCall::RotatePalette(handle, range, amount) =>{
+                    match self.objects.get_mut(handle){
+                        Ok(object) =>{
+                            let Some(palette) = object.palette_mut() else{
+                                eprintln!("Tried to rotate the palette of an object without an indexed sprite!");
+                                continue;
+                            };
+                            palette.rotate(range, amount);
+                        }
+                        Err(error) => eprintln!("{error}"),
+                    }
+                }
+                LogicCall::SetLayerVisible(layer, visible) =>{
+                    //anything on the layer could be anywhere so toggling it redraws everything
+                    if self.layers.is_visible(layer) != visible{
+                        self.layers.set(layer, visible);
+                        self.dirty.add(Rect::of_size(self.display_size));
+                        self.full_copy = true;
+                    }
+                }
+                LogicCall::SetObject(handle, point) =>{
+                    let object = match self.objects.get_mut(handle){
+                        Ok(object) => object,
+                        Err(error) =>{eprintln!("{error}"); continue;}
+                    };
                     //moves the position of the object in the object buffer by changing its point
-                    x = x.clamp(0.0,(self.display_size.width.saturating_sub(object.sprite.size.width)) as f32);
-                    y = y.clamp(0.0,(self.display_size.height.saturating_sub(object.sprite.size.height)) as f32);
-                    object.point = Point::at(x,y);
-                    //pushes object to the drawbuffer
-                    //self.draw_buffer.push(DrawCall::DrawObject(key));
+                    object.place(point, self.display_size);
                 }
-                LogicCall::MoveObject(key,dir, amount) =>{
-                    //sets object to a mutable reference to the object buffer at index key
-                    let object = &mut self.objects[key];
-                    //gets a point from the objec nudged by the direction and the amount
-                    let Point{mut x, mut y} = object.point.nudge(dir, amount);
-                    //clamps x and y to only be able to move in bounds and saturating sub to avoid crashing due to trying to index outside of the bounds
-                    x = x.clamp(0.0,(self.display_size.width.saturating_sub(object.sprite.size.width)) as f32);
-                    y = y.clamp(0.0,(self.display_size.height.saturating_sub(object.sprite.size.height)) as f32);
-                    //sets objects point to x and y 
-                    object.point = Point::at(x,y);
-                    //pushes object to the drawbuffer
-                    //self.draw_buffer.push(DrawCall::DrawObject(key));
+                LogicCall::MoveObject(handle,dir, amount) =>{
+                    //sets object to a mutable reference to the object behind the handle
+                    let object = match self.objects.get_mut(handle){
+                        Ok(object) => object,
+                        Err(error) =>{eprintln!("{error}"); continue;}
+                    };
+                    //gets a point from the objec nudged by the direction and the amount, clamped objects stay on the display
+                    let point = object.point.nudge(dir, amount);
+                    object.place(point, self.display_size);
                 }
                 LogicCall::SetCamera(key,size,point) => {
                     //sets camera to be a mutable reference to cameras at index key (clamped to a maximum of 15 for the max index of the camera array)
@@ -208,6 +336,8 @@ impl Display{
                     x = x.clamp(0.0,(self.display_size.width.saturating_sub(camera.camera_size.width)) as f32);
                     y = y.clamp(0.0,(self.display_size.height.saturating_sub(camera.camera_size.height)) as f32);
                     camera.point = Point::at(x,y);
+                    //the camera is looking at a different part of the display now
+                    self.full_copy = true;
                 }
                 LogicCall::MoveCamera(key,dir,amount)=>{
                     let Point{mut x, mut y} = self.cameras[key.min(15)].point.nudge(dir, amount);
@@ -216,6 +346,7 @@ impl Display{
                     x = x.clamp(0.0,(self.display_size.width.saturating_sub(camera.camera_size.width)) as f32);
                     y = y.clamp(0.0,(self.display_size.height.saturating_sub(camera.camera_size.height)) as f32);
                     camera.point = Point::at(x,y);
+                    self.full_copy = true;
                 }
                 LogicCall::SetOutputCamera(key) =>{
                     //sets the current output camera to the index of the camera array at key
@@ -224,19 +355,27 @@ impl Display{
                     }else{
                         self.current_camera = None;
                     }
+                    self.full_copy = true;
                 }
                 LogicCall::SetDisplaySize(size)=>{
                     //sets display size, this is not the render size but the size in in game units of how large the display is.
                     self.display_size = size;
                     self.display_buffer.resize(size.pixels()*BPP, 0);
+                    //canvases are laid out over their own bounds so they survive the resize, anything past the new size just isnt composited
+                    self.dirty.add(Rect::of_size(size));
+                    self.full_copy = true;
                 }
                 LogicCall::PixelAt(mut point,color) =>{
                     point = point + self.position;
                     self.pixel_buffer.push(Pixel::with_color(point, color));
                 }
+                LogicCall::BlendedPixelAt(mut point, color, blend) =>{
+                    point = point + self.position;
+                    self.pixel_buffer.push(Pixel::with_color(point, color).with_blend(blend));
+                }
                 LogicCall::DeleteAt(mut point)=>{
                     point = point + self.position;
-                    self.pixel_buffer.push(Pixel{point, color:[0,0,0,255], translucent:false});
+                    self.pixel_buffer.push(Pixel{point, color:[0,0,0,255], translucent:false, blend:BlendMode::Normal});
                 }
                 LogicCall::FillRange(mut point,mut point2,color)=>{
                     point = point + self.position;
@@ -255,82 +394,269 @@ impl Display{
                 }
             }
         }
+        //animations advance once per logic tick, after the screen had its chance to switch clips
+        for object in self.objects.iter_peek_mut(){
+            if let Some(animator) = object.animator.as_mut() && animator.tick(){
+                object.sprite = animator.sprite().clone();
+                object.needs_draw = true;
+            }
+        }
         false
     }
-    // pub fn redraw(&mut self){
-    //     //lets object contain a slice of all objects in buffer
-    //     let objects = &self.object_draw_buffer[..self.objects_max_index];
-    //     //iterates through objects and enumerates results
-    //     for (id, object) in objects.iter().enumerate(){
-    //         //iterates again through buffer
-    //         for other in objects[..id].iter().enumerate(){
-    //             //skips object if it is at same index ads object it is currently checking
-    //             if other.0 == id{continue;}
-    //             //if object contains other object, add both to object draw buffer to be redrawn
-    //             if self.object_draw_buffer[].contains(&other.1){
-    //                 self.object_draw_buffer.extend([
-    //                     id,
-    //                     other.0
-    //                 ]);
-    //             }
-    //         }
-    //     }
-    // }
+    ///marks the whole display as needing a redraw and a full copy to the backbuffer
+    fn mark_all_dirty(&mut self){
+        self.dirty.add(Rect::of_size(self.display_size));
+        self.full_copy = true;
+    }
     ///matches each display command to the corresponding function and executes it
-    pub fn render(&mut self, renderer:&mut RenderHook){
+    pub fn render<T:RenderTarget>(&mut self, renderer:&mut RenderHook<T>){
+        self.frame += 1;
+        let display_rect = Rect::of_size(self.display_size);
         //runs screens draw command
         self.screen.draw(&mut self.draw_buffer);
         //drains the command buffer and matches the command to the proper renderer commands
         for command in self.draw_buffer.drain(..){
             match command{
                 DrawCall::ClearDisplay =>{
-                    self.display_buffer.fill(0);
+                    //clearing drops every canvas, anything not drawn again this frame disappears
+                    self.canvases.clear();
+                    self.render_queue.retain(|item|!matches!(item.kind, RenderKind::Canvas(_)));
+                    self.dirty.add(display_rect);
                 }
                 DrawCall::Move(point) =>{
                     self.position = self.position + point;
                 }
-                DrawCall::DrawObject(id) => {
-                     if self.objects[id].sprite.size <= self.display_size{
-                        self.object_draw_buffer.push(id)
-                    }else{
-                        eprintln!("Tried to draw object larger than display size!")
+                DrawCall::DrawObject(handle) => {
+                    match self.objects.peek_mut(handle){
+                        Ok(object) =>{
+                            object.drawn_frame = self.frame;
+                            //redraws where the object was and where it is now if it changed or moved, only the part on the display matters
+                            let rect = object.rect();
+                            if object.needs_draw || object.drawn != Some(rect){
+                                self.dirty.add(object.drawn.map_or(rect, |drawn|drawn.union(&rect)).intersect(&display_rect));
+                            }
+                            self.render_queue.push(RenderItem{
+                                layer:object.layer,
+                                z:object.z,
+                                kind:RenderKind::Object(handle),
+                            });
+                        }
+                        //stale handles are reported rather than drawing whatever lives in the slot now
+                        Err(error) => eprintln!("{error}"),
+                    }
+                }
+                DrawCall::DrawPixels(layer, z) =>{
+                    //a canvas is mixed into the display with one blend mode, so every run of pixels sent with the same mode goes on its own.
+                    //runs are painted in the order they were sent, a run with another mode than the latest canvas of this layer and z starts
+                    //a new one over it, so pixels sent later always end up over the ones sent before them
+                    for batch in self.pixel_buffer.chunk_by(|pixel, next|pixel.blend == next.blend){
+                        let blend = batch[0].blend;
+                        let latest = self.canvases.iter().rposition(|canvas|canvas.layer == layer && canvas.z == z);
+                        let index = match latest{
+                            Some(index) if self.canvases[index].blend == blend => index,
+                            _ =>{
+                                self.canvases.push(PixelCanvas::new(layer, z, blend));
+                                self.canvases.len() - 1
+                            }
+                        };
+                        //the canvas only grows as far as the pixels on the display reach
+                        let area = batch.iter().fold(Rect::default(), |area, pixel|area.union(&pixel.rect().intersect(&display_rect)));
+                        let canvas = &mut self.canvases[index];
+                        canvas.grow(area);
+                        //paints the pending pixels onto the canvas so later pixel calls dont end up on this layer
+                        renderer.buffer_pixels(&mut canvas.data, &canvas.bounds, batch);
+                        if !self.render_queue.iter().any(|item|matches!(item.kind, RenderKind::Canvas(queued) if queued == index)){
+                            self.render_queue.push(RenderItem{layer, z, kind:RenderKind::Canvas(index)});
+                        }
+                    }
+                    //pixels off the display were skipped, intersecting leaves them empty so they dont dirty anything
+                    for pixel in self.pixel_buffer.drain(..){
+                        self.dirty.add(pixel.rect().intersect(&display_rect));
                     }
                 }
-                DrawCall::DrawPixels =>{
-                    renderer.buffer_pixels(
-                        &mut self.display_buffer[..self.display_size.pixels()*BPP],
-                        self.display_size,
-                        &mut self.pixel_buffer);
+                DrawCall::DrawText(text) =>{
+                    //the same text as last frame keeps its sprite and doesnt dirty anything, anything else is rasterized again
+                    let index = match self.texts.iter().position(|drawn|drawn.drawn_frame != self.frame && drawn.text.same_as(&text)){
+                        Some(index) => index,
+                        None =>{
+                            let (sprite, offset) = text.render();
+                            let object = Object::new(text.point + offset, sprite).unclamped().on_layer(text.layer).with_z(text.z);
+                            self.dirty.add(object.rect().intersect(&display_rect));
+                            self.texts.push(DrawnText{text, object, drawn_frame:self.frame});
+                            self.texts.len() - 1
+                        }
+                    };
+                    let drawn = &mut self.texts[index];
+                    drawn.drawn_frame = self.frame;
+                    self.render_queue.push(RenderItem{layer:drawn.object.layer, z:drawn.object.z, kind:RenderKind::Text(index)});
                 }
 
             };
         }
-        
-        //iters through object draw buffer and draws all objects that are marked with a dirty flag
-        for object_id in self.object_draw_buffer.drain(..){
-            let object = &mut self.objects[object_id];
-            if object.needs_draw{
-                renderer.buffer_object(&object, &mut self.display_buffer[..self.display_size.pixels()*BPP],&self.display_size);
+        //canvases keep their pixels even if nothing was drawn to them this frame. they go under the ones painted this frame, in the order
+        //they were made so every stack stays in the order its pixels were sent
+        let kept:SmallVec<[RenderItem;4]> = self.canvases.iter().enumerate()
+            .filter(|&(index, _)|!self.render_queue.iter().any(|item|matches!(item.kind, RenderKind::Canvas(queued) if queued == index)))
+            .map(|(index, canvas)|RenderItem{layer:canvas.layer, z:canvas.z, kind:RenderKind::Canvas(index)})
+            .collect();
+        self.render_queue.insert_many(0, kept);
+        //objects that were on screen last frame but werent drawn this frame leave their area behind
+        for object in self.objects.iter_peek_mut(){
+            if object.drawn_frame != self.frame && let Some(drawn) = object.drawn.take(){
+                self.dirty.add(drawn.intersect(&display_rect));
+            }
+        }
+        for vacated in self.objects.vacated.drain(..){
+            self.dirty.add(vacated);
+        }
+        //text that wasnt drawn again leaves its area behind, it is dropped once this frame is rendered
+        for drawn in self.texts.iter().filter(|drawn|drawn.drawn_frame != self.frame){
+            self.dirty.add(drawn.object.rect().intersect(&display_rect));
+        }
+
+        //sorts everything queued this frame by layer then z. sort is stable so equal keys keep the order they were pushed in
+        self.render_queue.sort_by_key(|item|(item.layer, item.z));
+        let display_buffer = &mut self.display_buffer[..self.display_size.pixels()*BPP];
+        //every dirty region is cleared and rebuilt from whatever visible items overlap it
+        for region in self.dirty.regions(){
+            let region = region.intersect(&display_rect);
+            if region.is_empty(){continue;}
+            clear_region(display_buffer, self.display_size, &region);
+            for item in self.render_queue.iter(){
+                if !self.layers.is_visible(item.layer){continue;}
+                match item.kind{
+                    RenderKind::Object(handle) =>{
+                        //handle was checked when the draw call was queued and nothing despawns between then and now
+                        let Ok(object) = self.objects.get(handle) else{continue;};
+                        let clip = region.intersect(&object.rect());
+                        if !clip.is_empty(){
+                            renderer.buffer_object(object, display_buffer, &self.display_size, &clip);
+                        }
+                    }
+                    RenderKind::Text(index) =>{
+                        let object = &self.texts[index].object;
+                        let clip = region.intersect(&object.rect());
+                        if !clip.is_empty(){
+                            renderer.buffer_object(object, display_buffer, &self.display_size, &clip);
+                        }
+                    }
+                    RenderKind::Canvas(index) =>{
+                        let canvas = &self.canvases[index];
+                        renderer.buffer_canvas(&canvas.data, &canvas.bounds, display_buffer, self.display_size, &region, canvas.blend);
+                    }
+                }
+            }
+        }
+        //everything queued is now on screen where it currently is
+        for item in self.render_queue.drain(..){
+            if let RenderKind::Object(handle) = item.kind && let Ok(object) = self.objects.peek_mut(handle){
+                object.needs_draw = false;
+                object.drawn = Some(object.rect());
             }
-            //object.needs_draw = false;
         }
+        let frame = self.frame;
+        self.texts.retain(|drawn|drawn.drawn_frame == frame);
         if let Some(camera) = self.current_camera{
             //checks if camera size is the same as pixels framebuffer size, if not changes it.
             let output_camera= self.cameras[camera];
             if output_camera.camera_size != renderer.render_size{
                 renderer.change_size(output_camera.camera_size);
+                self.full_copy = true;
+            }
+            //camera captures the portion of the display buffer it is looking at, only the dirty parts unless something invalidated the whole view
+            if self.full_copy{
+                renderer.capture(&self.display_buffer[..self.display_size.pixels()*BPP], &self.display_size, &output_camera);
+            }else{
+                renderer.capture_regions(&self.display_buffer, &self.display_size, &output_camera, self.dirty.regions());
             }
-            //camera captures the portion of the display buffer it is looking at
-            renderer.capture(&mut self.display_buffer[..self.display_size.pixels()*BPP], &self.display_size, &output_camera);
         //if no camera is active copy entire display to pixel buffer.
         }else{
             //checks if render size is the same as display size and updates it if not
             if self.display_size != renderer.render_size{
                 renderer.change_size(self.display_size);
+                self.full_copy = true;
+            }
+            //copys the changed parts of the render buffer to pixels back buffer, or all of it after a resize
+            if self.full_copy{
+                renderer.copy_buffer(&self.display_buffer);
+            }else{
+                renderer.copy_regions(&self.display_buffer, &self.display_size, self.dirty.regions());
             }
-            //copys entire render buffer to pixels back buffer
-            renderer.copy_buffer(&self.display_buffer);
         }
+        self.dirty.clear();
+        self.full_copy = false;
+    }
+}
+
+//fills a rect of the display buffer with zeros, rect is already clipped to the display
+fn clear_region(display_buffer:&mut [u8], display_size:Size, region:&Rect){
+    for y in region.y0..region.y1{
+        let start = (y as usize*display_size.width + region.x0 as usize)*BPP;
+        display_buffer[start..start + region.width() as usize*BPP].fill(0);
+    }
+}
+
+///RenderItem is one queued draw, sorted by layer and z before anything is drawn
+pub struct RenderItem{
+    pub layer:Layer,
+    pub z:i32,
+    pub kind:RenderKind,
+}
+
+///What a render item draws
+pub enum RenderKind{
+    ///an object in the registry
+    Object(ObjectHandle),
+    ///a pixel canvas, index into the displays canvases
+    Canvas(usize),
+    ///text drawn this frame, index into the displays texts
+    Text(usize),
+}
+
+///DrawnText is a text draw call along with the sprite it was rasterized into, wrapped in an object so it draws like one
+pub struct DrawnText{
+    pub text:Text,
+    pub object:Object,
+    ///render frame the text was last drawn in
+    pub drawn_frame:u64,
+}
+
+///PixelCanvas is a transparent by default buffer that a run of pixel draw calls with one layer, z and blend mode is painted onto. it only covers the part
+///of the display pixels have been painted on and is kept between frames so screens only have to send the pixels that changed
+pub struct PixelCanvas{
+    pub layer:Layer,
+    pub z:i32,
+    ///mode the whole canvas is mixed into the display with, pixels on it are composited onto each other normally
+    pub blend:BlendMode,
+    ///area of the display the canvas covers, empty until something is painted
+    pub bounds:Rect,
+    ///pixels of bounds in reading order
+    pub data:Vec<u8>,
+}
+
+impl PixelCanvas{
+    ///Empty canvas, takes up no memory until pixels are painted on it
+    pub fn new(layer:Layer, z:i32, blend:BlendMode) -> Self{
+        PixelCanvas{layer, z, blend, bounds:Rect::default(), data:Vec::new()}
+    }
+    ///Grows the canvas to cover area as well, keeping whatever was already painted
+    pub fn grow(&mut self, area:Rect){
+        let bounds = self.bounds.union(&area);
+        if bounds == self.bounds{return;}
+        let mut data = vec![0;bounds.width() as usize*bounds.height() as usize*BPP];
+        let length = self.bounds.width() as usize*BPP;
+        for y in self.bounds.y0..self.bounds.y1{
+            let src = (y - self.bounds.y0) as usize*length;
+            let dst = ((y - bounds.y0) as usize*bounds.width() as usize + (self.bounds.x0 - bounds.x0) as usize)*BPP;
+            data[dst..dst + length].copy_from_slice(&self.data[src..src + length]);
+        }
+        self.bounds = bounds;
+        self.data = data;
+    }
+    ///Wipes the canvas and gives its memory back
+    pub fn clear(&mut self){
+        self.bounds = Rect::default();
+        self.data = Vec::new();
     }
 }
 
@@ -11,45 +11,70 @@ use std::time::Instant;
 use pixels::Pixels;
 use crate::{
 
-    engine::{display::Display, screens::Screen}, graphics::renderer::RenderHook, io::input::Input, shared::{Point, Size}};
+    engine::{display::Display, screens::Screen}, graphics::{render_target::{Framebuffer, RenderTarget}, renderer::RenderHook}, io::input::Input, shared::{Point, Size}};
 
 pub enum EngineCommand{
     End,
     Draw,
 }
 
-pub struct Engine{
+///Clock decides where the engine gets its frame time from
+pub enum Clock{
+    ///Wall clock time since the last frame, stores the instant of the last frame
+    Realtime(Instant),
+    ///Every frame advances by a fixed number of seconds, used to step the engine deterministically
+    Synthetic(f32),
+}
+
+pub struct Engine<T:RenderTarget = Pixels>{
     display:Display,
-    pub renderer:RenderHook,
+    pub renderer:RenderHook<T>,
     pub input:Input,
-    last_time:Instant,
+    pub clock:Clock,
     pub tickrate:f32,
     accumulator:f32,
 }
 
 //initializes the engine
-impl Engine{
-    pub fn initialize(tickrate:f32, display_point:Point, screen:Box<dyn Screen>,pixels:Pixels) -> Self{
+impl<T:RenderTarget> Engine<T>{
+    pub fn initialize(tickrate:f32, display_point:Point, screen:Box<dyn Screen>,target:T) -> Self{
+        Self::assemble(tickrate, display_point, screen, target, Input::new(), Clock::Realtime(Instant::now()))
+    }
+
+    //shared by the windowed and headless constructors
+    fn assemble(tickrate:f32, display_point:Point, screen:Box<dyn Screen>,target:T, input:Input, clock:Clock) -> Self{
         Engine{
         //initializes display size at 1, 1 and render size 1, 1, will update on init.
         display:Display::new_at(display_point, screen, Size::is(1, 1)),
-        renderer:RenderHook::create_render_hook(Size::is(1, 1),pixels),
-        input:Input::new(),
-        last_time:Instant::now(),
+        renderer:RenderHook::create_render_hook(Size::is(1, 1),target),
+        input,
+        clock,
         tickrate:1.0/tickrate,
         accumulator:0.0,
         }
     }
+
+    ///Returns the display instance, mostly useful for inspecting the display buffer of a headless engine
+    pub fn display(&self) -> &Display{
+        &self.display
+    }
     
     fn update_time(&mut self){
-        //sets a time to be now
-        let now = Instant::now();
-        //sets the current frame time to be now minus the time of the last frame, clamps it to 0.25 seconds maximum
-        let frame_time = (now - self.last_time).as_secs_f32().min(0.25);
+        let frame_time = match &mut self.clock{
+            Clock::Realtime(last_time) =>{
+                //sets a time to be now
+                let now = Instant::now();
+                //sets the current frame time to be now minus the time of the last frame, clamps it to 0.25 seconds maximum
+                let frame_time = (now - *last_time).as_secs_f32().min(0.25);
+                //saves now to last_time
+                *last_time = now;
+                frame_time
+            }
+            //synthetic clock always advances by the same step
+            Clock::Synthetic(step) => *step,
+        };
         //adds frametime to the accumulator
         self.accumulator += frame_time;
-        //saves now to last_time
-        self.last_time = now;
     }
     
     ///Engine runes for one cycle
@@ -77,3 +102,11 @@ impl Engine{
     }
 }
 
+impl Engine<Framebuffer>{
+    ///Initializes an engine that renders into a cpu framebuffer and advances one tick per run_once.
+    ///Needs no window, gpu or input devices, input_state is left alone so it can be scripted
+    pub fn headless(tickrate:f32, display_point:Point, screen:Box<dyn Screen>) -> Self{
+        Self::assemble(tickrate, display_point, screen, Framebuffer::new(Size::is(1, 1)), Input::headless(), Clock::Synthetic(1.0/tickrate))
+    }
+}
+
@@ -0,0 +1,46 @@
+// ===============================
+//             LAYER.RS
+// -------------------------------
+//  Render layers. everything drawn in a frame is
+//  sorted by layer then z before it hits the
+//  display buffer, so push order stops mattering
+// -------------------------------
+
+///Layer is a numbered render layer, lower layers are drawn first. the named layers cover the usual scene setup but any number works
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Layer(pub u8);
+
+impl Layer{
+    pub const BACKGROUND:Layer = Layer(0);
+    pub const WORLD:Layer = Layer(64);
+    pub const FOREGROUND:Layer = Layer(128);
+    pub const UI:Layer = Layer(192);
+}
+
+///number of distinct layers, one per value of the u8 inside layer
+pub const LAYER_COUNT:usize = 256;
+
+///LayerVisibility stores a visibility toggle for every layer, everything starts visible
+#[derive(Clone, Debug)]
+pub struct LayerVisibility{
+    visible:[bool;LAYER_COUNT],
+}
+
+impl Default for LayerVisibility{
+    fn default() -> Self{
+        LayerVisibility{visible:[true;LAYER_COUNT]}
+    }
+}
+
+impl LayerVisibility{
+    pub fn set(&mut self, layer:Layer, visible:bool){
+        self.visible[layer.0 as usize] = visible;
+    }
+    pub fn is_visible(&self, layer:Layer) -> bool{
+        self.visible[layer.0 as usize]
+    }
+    ///Makes every layer visible again
+    pub fn reset(&mut self){
+        self.visible = [true;LAYER_COUNT];
+    }
+}
@@ -1,3 +1,5 @@
 pub mod engine;
 pub mod display;
+pub mod dirty;
+pub mod layer;
 pub mod screens;
\ No newline at end of file
@@ -9,36 +9,38 @@
 use smallvec::SmallVec;
 
 use crate::{
-    engine::{display::{DrawCall, LogicCall}, screens::{snake::Snake, Screen}}, graphics::sprites::{blackbuck_sprite::BLACKBUCK_SPRITE, transparency2_sprite::TRANSPARENCY2_SPRITE}, io::input::InputState, object::Object, shared::{Point, Size}};
+    engine::{display::{DrawCall, LogicCall}, layer::Layer, screens::{snake::Snake, Screen}}, graphics::sprites::{blackbuck_sprite::BLACKBUCK_SPRITE, transparency2_sprite::TRANSPARENCY2_SPRITE}, io::input::InputState, object::{Object, ObjectHandle, ObjectRegistry}, shared::{Point, Size}};
 pub struct MainMenu{
+    background:ObjectHandle,
+    player:ObjectHandle,
 }
 
 
 impl Screen for MainMenu{
-    fn init_screen(&self,drawbuffer:&mut SmallVec<[DrawCall;256]>,logic_buffer:&mut SmallVec<[LogicCall;256]>){
+    fn init_screen(&mut self,drawbuffer:&mut SmallVec<[DrawCall;256]>,logic_buffer:&mut SmallVec<[LogicCall;256]>, objects:&mut ObjectRegistry){
+        //spawns the background and the movable translucent sprite, keeping the handles to draw them with
+        self.background = objects.spawn(Object::new(Point::at(0.0,0.0), BLACKBUCK_SPRITE.clone()).on_layer(Layer::BACKGROUND));
+        self.player = objects.spawn(Object::new(Point::at(0.0,0.0), TRANSPARENCY2_SPRITE.clone()));
         logic_buffer.extend([
-            LogicCall::InitObject(1,Object::new(Point::at(0.0,0.0), TRANSPARENCY2_SPRITE)),
             //sets output camera to camera 0
             LogicCall::SetOutputCamera(Some(0)),
-            //initializes object set to background into position 1 on the 
-            LogicCall::InitObject(0, Object::new(Point::at(0.0,0.0), BLACKBUCK_SPRITE)),
             //sets display size to 300 300
             LogicCall::SetDisplaySize(Size::is(512,512)),
             //sets camera 0 to size 50 at point 0
             LogicCall::SetCamera(0, Size::is(100, 100), Point::at(0.0,0.0)),
         ]);
-        //drawcall to draw object 1 (main menu screen)
+        //background is on the background layer so it always ends up under the player
         drawbuffer.extend([
-                DrawCall::DrawObject(0),
-                DrawCall::DrawObject(1),
+                DrawCall::DrawObject(self.background),
+                DrawCall::DrawObject(self.player),
                 
             
         ]);
     }
             
-    fn update(&mut self, logic_buffer:&mut SmallVec<[LogicCall;256]>  ,input_state:&InputState) -> (Option<Box<dyn Screen>>,bool){
-            //pushes the camera by the current input direction
-            logic_buffer.push(LogicCall::MoveObject(1, input_state.direction, 5.0));
+    fn update(&mut self, logic_buffer:&mut SmallVec<[LogicCall;256]>, _objects:&mut ObjectRegistry, input_state:&InputState) -> (Option<Box<dyn Screen>>,bool){
+            //pushes the player by the current input direction
+            logic_buffer.push(LogicCall::MoveObject(self.player, input_state.direction, 5.0));
 
             //if shift or east button on controller is pressed then initialize and switch to camera to camera 1 
             if input_state.east{
@@ -57,8 +59,8 @@ impl Screen for MainMenu{
     //draws screen
     fn draw(&self, drawbuffer:&mut SmallVec<[DrawCall;256]>){
         drawbuffer.extend([
-                DrawCall::DrawObject(0),
-                DrawCall::DrawObject(1),
+                DrawCall::DrawObject(self.background),
+                DrawCall::DrawObject(self.player),
                 
             ]);
     }
@@ -67,7 +69,11 @@ impl Screen for MainMenu{
     impl MainMenu{
         //public constructor for main menu
         pub fn init() -> Box<dyn Screen>{
-            Box::new(MainMenu{})
+            //handles are filled in when the screen is initialized
+            Box::new(MainMenu{
+                background:ObjectHandle::DANGLING,
+                player:ObjectHandle::DANGLING,
+            })
             }
     
     }
@@ -7,7 +7,7 @@
 
 use smallvec::SmallVec;
 
-use crate::{engine::display::{DrawCall, LogicCall}, io::input::InputState};
+use crate::{engine::display::{DrawCall, LogicCall}, io::input::InputState, object::ObjectRegistry};
 
 
 pub mod snake;
@@ -15,10 +15,10 @@ pub mod main_menu;
 
 ///Screen trait impliments all the methods for an individual scene to be able to be run by the display struct
 pub trait Screen{
-    ///Initializes The screen
-    fn init_screen(&self, drawbuffer:&mut SmallVec<[DrawCall;256]>, logic_buffer:&mut SmallVec<[LogicCall;256]>);
+    ///Initializes The screen. objects spawned into the registry hand back the handles the screen uses to address them
+    fn init_screen(&mut self, drawbuffer:&mut SmallVec<[DrawCall;256]>, logic_buffer:&mut SmallVec<[LogicCall;256]>, objects:&mut ObjectRegistry);
     ///Updates any logic and returns the next screen once the screen is finished, or a boolean that kills the main loop.
-    fn update(&mut self, logic_buffer:&mut SmallVec<[LogicCall;256]>,input:&InputState) -> (Option<Box<dyn Screen>>,bool);
+    fn update(&mut self, logic_buffer:&mut SmallVec<[LogicCall;256]>, objects:&mut ObjectRegistry, input:&InputState) -> (Option<Box<dyn Screen>>,bool);
     ///Draws the current screen state
     fn draw(&self,drawbuffer:&mut SmallVec<[DrawCall;256]>);
 }
\ No newline at end of file
@@ -7,9 +7,10 @@
 
 //declare crates to import
 
-use rand::{rng, Rng};
+use std::sync::{Arc, OnceLock};
+use rand::{rng, rngs::StdRng, Rng, SeedableRng};
 use smallvec::SmallVec;
-use crate::{engine::{display::{DrawCall, LogicCall}, screens::{main_menu::MainMenu, Screen}}, io::input::InputState, shared::*};
+use crate::{engine::{display::{DrawCall, LogicCall}, layer::Layer, screens::{main_menu::MainMenu, Screen}}, graphics::{parse_ttf_bindings, texthandler::{self, ascii_charset, SizedFont, Text}}, io::input::InputState, object::ObjectRegistry, shared::*};
 
 //Initializes Constants
 
@@ -19,6 +20,16 @@ const SPEED:u8 =3;
 //BOARD_SIZE decides the size of board
 const BOARD_SIZE:f32 = 20.0;
 
+//SCORE_HEIGHT is the room left under the board for the score
+const SCORE_HEIGHT:f32 = 10.0;
+
+//font the score is drawn in and how many pixels tall
+const SCORE_FONT:&str = "ttf_test.ttf";
+const SCORE_FONT_SIZE:f32 = 8.0;
+
+//the score font is loaded the first time a snake needs it and shared by every snake after, none if it couldnt be loaded
+static SCORE_FONT_CACHE:OnceLock<Option<Arc<SizedFont>>> = OnceLock::new();
+
 //Sets characters and colors for the thiongs in the game.
 //By default snake apple and wall are aliases of DELETE constant from shared, due to default behavior
 //for them being to just change the background color
@@ -32,6 +43,8 @@ const WALL_COLOR:[u8;4] = [0, 0, 255, 127];
 const APPLE_COLOR:[u8;4] = [167, 199, 231, 255];
 
 const BACKGROUND_COLOR:[u8;4] = [248,185,212, 255];
+
+const SCORE_COLOR:[u8;4] = [175, 105, 238, 255];
 //define structs
 
 ///Apple stores a Point and two bools, one signifying whether apple has been eaten, one for if it needs to be drawn
@@ -58,13 +71,16 @@ pub struct Snake{
     apple:Apple,
     score:u16,
     frame_counter:u8,
+    rng:StdRng,
+    //none if the font failed to load, the game still plays without a score on screen
+    score_font:Option<Arc<SizedFont>>,
 }
 
 //Implements the screen trait for snake.
 impl Screen for Snake{
     ///Initializes screen by drawing border, drawing the game 
     
-    fn init_screen(&self, drawbuffer: &mut SmallVec<[DrawCall;256]>,logic_buffer:&mut SmallVec<[LogicCall;256]>){
+    fn init_screen(&mut self, _drawbuffer: &mut SmallVec<[DrawCall;256]>,logic_buffer:&mut SmallVec<[LogicCall;256]>, _objects:&mut ObjectRegistry){
         //initializes all variables for the display.
         logic_buffer.extend([
             //Draws Snake
@@ -77,22 +93,16 @@ impl Screen for Snake{
             LogicCall::Rectangle(Point::at(0.0,0.0), Point::at(BOARD_SIZE+1.0,BOARD_SIZE+1.0), WALL_COLOR),
             //disables output camera
             LogicCall::SetOutputCamera(None),
-            LogicCall::SetDisplaySize(Size::is(BOARD_SIZE as usize + 2,BOARD_SIZE as usize + 2)),
-        ]);
-        //draws initial elements
-        drawbuffer.extend([
-            //Sends all pixel draw commands to the frame buffer
-            DrawCall::DrawObject(1),
-
+            LogicCall::SetDisplaySize(Size::is(BOARD_SIZE as usize + 2,(BOARD_SIZE + SCORE_HEIGHT) as usize + 2)),
         ]);
     }
 
-    fn update(&mut self,logic_buffer:&mut SmallVec<[LogicCall;256]>, input_state:&InputState) -> (Option<Box<dyn Screen>>,bool){
+    fn update(&mut self,logic_buffer:&mut SmallVec<[LogicCall;256]>, _objects:&mut ObjectRegistry, input_state:&InputState) -> (Option<Box<dyn Screen>>,bool){
         logic_buffer.extend([
             //Deletes last tail
             LogicCall::PixelAt(self.last_tail, BACKGROUND_COLOR),
             //Draw snake coord
-            LogicCall::PixelAt(self.coord,SNAKE_COLOR),
+            LogicCall::PixelAt(self.coord, SNAKE_COLOR),
             //Draw apple
             LogicCall::PixelAt(self.apple.coord, APPLE_COLOR),
 
@@ -126,14 +136,22 @@ impl Screen for Snake{
         //initializes buffer for display commands
         drawbuffer.extend([
             //Sends all pixel draw commands to the frame buffer
-            DrawCall::DrawPixels,
+            DrawCall::DrawPixels(Layer::WORLD, 0),
         ]);
+        //draws the score under the board
+        if let Some(font) = &self.score_font{
+            drawbuffer.push(DrawCall::DrawText(Text::new(font.clone(), self.score.to_string(), Point::at(1.0, BOARD_SIZE+2.0), SCORE_COLOR)));
+        }
     }
 }
 //Implement methods for Snake
 impl Snake{
     ///public constructor for snake.
     pub fn init() -> Box<dyn Screen> {
+        Snake::init_seeded(rng().random())
+    }
+    ///constructor for snake with a fixed seed for apple placement, same seed and same inputs always play out the same game
+    pub fn init_seeded(seed:u64) -> Box<dyn Screen> {
         let mut snake = Snake{
             dir:RIGHT,
             next_dir:RIGHT,
@@ -149,22 +167,39 @@ impl Snake{
             },
             score:0,
             frame_counter:0,
+            rng:StdRng::seed_from_u64(seed),
+            score_font:SCORE_FONT_CACHE.get_or_init(Snake::load_score_font).clone(),
         };
         snake.new_apple();
         Box::new(snake)
     }
+    ///Loads and sizes the font the score is drawn with, prints why and returns none if it cant. only runs once, through SCORE_FONT_CACHE
+    fn load_score_font() -> Option<Arc<SizedFont>>{
+        let loaded_font = match parse_ttf_bindings::load_font(SCORE_FONT){
+            Ok(loaded_font) => loaded_font,
+            Err(error) =>{
+                eprintln!("Failed to load score font {SCORE_FONT}: {error}");
+                return None;
+            }
+        };
+        match texthandler::load_font(loaded_font).sized(SCORE_FONT_SIZE, &ascii_charset()){
+            Ok(font) => Some(font),
+            Err(error) =>{
+                eprintln!("Failed to size score font {SCORE_FONT}: {error}");
+                None
+            }
+        }
+    }
     ///Creates a new apple and checks for collisions with snake. repeats until it no collision
     fn new_apple(&mut self){
-        //Initializes random number generator
-        let mut rng = rng();
         //Loop is active until apple is created and doesnt collide with snake
         loop{
             //Initializes apple
             let apple = Apple{
             coord:Point{
                 //randomly selects x and y values for apple
-                x:rng.random_range(1..=BOARD_SIZE as i32)as f32,
-                y:rng.random_range(1..=BOARD_SIZE as i32)as f32,
+                x:self.rng.random_range(1..=BOARD_SIZE as i32)as f32,
+                y:self.rng.random_range(1..=BOARD_SIZE as i32)as f32,
             },
             eaten:false,
             };
@@ -0,0 +1,153 @@
+// ===============================
+//            ANIMATION.RS
+// -------------------------------
+//  Sprite sheets and animation clips. a sheet cuts
+//  one image into a grid of frames, clips play
+//  those frames back and the animator on an object
+//  steps through them once per logic tick
+// -------------------------------
+
+use std::sync::Arc;
+
+use crate::{graphics::sprites::Sprite, shared::Size};
+
+///How a clip carries on after its last frame
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PlayMode{
+    ///starts over from the first frame
+    #[default]
+    Loop,
+    ///plays backwards to the first frame, then forwards again
+    PingPong,
+    ///stops on the last frame
+    Once,
+}
+
+///AnimationFrame is one step of a clip, a frame of the sheet and how many ticks it stays up for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AnimationFrame{
+    pub frame:usize,
+    ///ticks the frame is shown for, 0 is treated as 1
+    pub duration:u32,
+}
+
+///Clip is a named sequence of sheet frames
+#[derive(Clone, Debug)]
+pub struct Clip{
+    pub name:String,
+    pub frames:Vec<AnimationFrame>,
+    pub mode:PlayMode,
+}
+
+///SpriteSheet is every frame cut out of one image along with the clips that play them
+#[derive(Clone, Debug)]
+pub struct SpriteSheet{
+    pub frame_size:Size,
+    ///frames in reading order, left to right then top to bottom
+    pub frames:Vec<Sprite>,
+    pub clips:Vec<Clip>,
+}
+
+impl SpriteSheet{
+    ///Cuts sheet into frame_size cells. cells that would hang off the right or bottom edge are dropped
+    pub fn from_grid(sheet:&Sprite, frame_size:Size) -> Self{
+        let columns = sheet.size.width/frame_size.width.max(1);
+        let rows = sheet.size.height/frame_size.height.max(1);
+        let frames = (0..rows)
+            .flat_map(|row|(0..columns).map(move |column|(column, row)))
+            .map(|(column, row)|sheet.crop(column*frame_size.width, row*frame_size.height, frame_size))
+            .collect();
+        SpriteSheet{frame_size, frames, clips:Vec::new()}
+    }
+    ///Adds a clip made of (frame, duration) pairs
+    pub fn with_clip(mut self, name:&str, frames:&[(usize, u32)], mode:PlayMode) -> Self{
+        self.clips.push(Clip{
+            name:name.to_string(),
+            frames:frames.iter().map(|&(frame, duration)|AnimationFrame{frame, duration}).collect(),
+            mode,
+        });
+        self
+    }
+    ///Index of the clip called name
+    pub fn clip(&self, name:&str) -> Option<usize>{
+        self.clips.iter().position(|clip|clip.name == name)
+    }
+    pub fn frame(&self, index:usize) -> &Sprite{
+        &self.frames[index]
+    }
+}
+
+///Animator plays clips from a shared sheet on an object. the display ticks it every logic update and swaps the objects sprite when the frame changes
+#[derive(Clone, Debug)]
+pub struct Animator{
+    pub sheet:Arc<SpriteSheet>,
+    clip:usize,
+    ///position inside the clips frame list
+    step:usize,
+    elapsed:u32,
+    ///ping pong direction, false while playing backwards
+    forward:bool,
+    finished:bool,
+}
+
+impl Animator{
+    ///Animator playing the clip called name from the start, none if the sheet has no such clip
+    pub fn new(sheet:Arc<SpriteSheet>, name:&str) -> Option<Self>{
+        let clip = sheet.clip(name)?;
+        Some(Animator{sheet, clip, step:0, elapsed:0, forward:true, finished:false})
+    }
+    ///Switches to the clip called name and starts it over, keeps playing if it is already the current clip.
+    ///returns false if the sheet has no such clip
+    pub fn play(&mut self, name:&str) -> bool{
+        let Some(clip) = self.sheet.clip(name) else{return false;};
+        if clip != self.clip || self.finished{
+            *self = Animator{sheet:self.sheet.clone(), clip, step:0, elapsed:0, forward:true, finished:false};
+        }
+        true
+    }
+    pub fn clip(&self) -> &Clip{
+        &self.sheet.clips[self.clip]
+    }
+    ///True once a clip played with once has shown its last frame for its whole duration
+    pub fn is_finished(&self) -> bool{
+        self.finished
+    }
+    ///Index of the sheet frame being shown
+    pub fn frame(&self) -> usize{
+        self.clip().frames.get(self.step).map_or(0, |frame|frame.frame)
+    }
+    ///Sprite for the frame being shown
+    pub fn sprite(&self) -> &Sprite{
+        self.sheet.frame(self.frame())
+    }
+    ///Advances one tick, returns true if the frame changed
+    pub fn tick(&mut self) -> bool{
+        let clip = &self.sheet.clips[self.clip];
+        let Some(current) = clip.frames.get(self.step) else{return false;};
+        if self.finished{return false;}
+        self.elapsed += 1;
+        if self.elapsed < current.duration.max(1){return false;}
+        self.elapsed = 0;
+        let last = clip.frames.len() - 1;
+        let previous = self.frame();
+        match clip.mode{
+            PlayMode::Loop => self.step = if self.step == last{0}else{self.step + 1},
+            PlayMode::Once =>{
+                if self.step == last{
+                    self.finished = true;
+                }else{
+                    self.step += 1;
+                }
+            }
+            PlayMode::PingPong =>{
+                //turns around at either end without showing the end frame twice
+                if last == 0{return false;}
+                if self.forward && self.step == last || !self.forward && self.step == 0{
+                    self.forward = !self.forward;
+                }
+                self.step = if self.forward{self.step + 1}else{self.step - 1};
+            }
+        }
+        self.frame() != previous
+    }
+}
@@ -0,0 +1,355 @@
+// ===============================
+//           BITMAPFONT.RS
+// -------------------------------
+//  loads fixed size pixel fonts, bdf and psf files along
+//  with font sheets cut from a sprite. every pixel is either
+//  on or off so text comes out crisp. they all end up as the
+//  same sized fonts ttf files are rasterized into
+// -------------------------------
+
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    fs,
+    io,
+    path::Path,
+};
+
+use crate::{
+    graphics::{sprites::Sprite, texthandler::{sized_from_bitmaps, SizedFont}},
+    shared::Size,
+};
+
+//magic numbers at the start of psf files
+const PSF1_MAGIC:[u8;2] = [0x36, 0x04];
+const PSF2_MAGIC:[u8;4] = [0x72, 0xb5, 0x4a, 0x86];
+//psf1 mode bits, 512 glyphs instead of 256 and a unicode table after the glyphs
+const PSF1_MODE512:u8 = 0x01;
+const PSF1_MODEHASTAB:u8 = 0x02;
+const PSF1_MODESEQ:u8 = 0x04;
+//psf2 flag for a unicode table after the glyphs
+const PSF2_HAS_UNICODE_TABLE:u32 = 0x01;
+//more glyphs than there are chars cant all be drawn, so a psf saying it has more is broken
+const PSF_MAX_GLYPHS:usize = 0x110000;
+
+///Everything that can go wrong loading a bitmap font
+#[derive(Debug)]
+pub enum BitmapFontError{
+    ///the file couldnt be opened or read
+    Io(io::Error),
+    ///the data isnt a font of the kind it was loaded as, or contradicts itself
+    Malformed(String),
+}
+
+impl Display for BitmapFontError{
+    fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result{
+        match self{
+            BitmapFontError::Io(error) => write!(f, "Couldnt read bitmap font: {error}"),
+            BitmapFontError::Malformed(reason) => write!(f, "Malformed bitmap font: {reason}"),
+        }
+    }
+}
+
+impl Error for BitmapFontError{
+    fn source(&self) -> Option<&(dyn Error + 'static)>{
+        match self{
+            BitmapFontError::Io(error) => Some(error),
+            BitmapFontError::Malformed(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for BitmapFontError{
+    fn from(error:io::Error) -> Self{
+        BitmapFontError::Io(error)
+    }
+}
+
+//shorthand for bailing out of a parser
+fn malformed<T>(reason:impl Into<String>) -> Result<T, BitmapFontError>{
+    Err(BitmapFontError::Malformed(reason.into()))
+}
+
+///BitmapGlyph is one char of a bitmap font before it goes into a sized font
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BitmapGlyph{
+    pub char:char,
+    pub size:Size,
+    ///offset from the pen position on the baseline to the top left of the bitmap, same as ttf glyphs
+    pub xoff:i32,
+    pub yoff:i32,
+    pub advance:f32,
+    ///one byte a pixel, 255 for pixels that are on
+    pub coverage:Vec<u8>,
+}
+
+//unpacks rows of bits, most significant bit first and every row padded to a whole byte, into coverage
+fn unpack_rows(bits:&[u8], size:Size) -> Vec<u8>{
+    let row_bytes = size.width.div_ceil(8);
+    let mut coverage = Vec::with_capacity(size.pixels());
+    for row in bits.chunks(row_bytes).take(size.height){
+        for x in 0..size.width{
+            let on = row.get(x/8).is_some_and(|byte|byte & (0x80 >> (x%8)) != 0);
+            coverage.push(if on {255} else {0});
+        }
+    }
+    //rows missing off the end of the data are left blank
+    coverage.resize(size.pixels(), 0);
+    coverage
+}
+
+///Loads a bdf font file
+pub fn load_bdf(path:impl AsRef<Path>) -> Result<SizedFont, BitmapFontError>{
+    parse_bdf(&fs::read_to_string(path)?)
+}
+
+///Parses the text of a bdf font. glyphs without an encoding are skipped, the font size is its pixel size or the height of its bounding box
+pub fn parse_bdf(text:&str) -> Result<SizedFont, BitmapFontError>{
+    let mut lines = text.lines().enumerate().map(|(number, line)|(number + 1, line.trim()));
+    match lines.next(){
+        Some((_, line)) if line.starts_with("STARTFONT") =>{}
+        _ => return malformed("bdf doesnt start with STARTFONT"),
+    }
+    //parses the numbers after a keyword, fails with the line number if there arent enough of them
+    let numbers = |number:usize, fields:&[&str], count:usize| -> Result<Vec<i32>, BitmapFontError>{
+        let values:Vec<i32> = fields.iter().skip(1).take(count).filter_map(|field|field.parse().ok()).collect();
+        if values.len() < count{
+            return malformed(format!("line {number}: {} needs {count} numbers", fields[0]));
+        }
+        Ok(values)
+    };
+    let (mut bounding_box, mut ascent, mut descent, mut pixel_size) = (None, None, None, None);
+    let mut glyphs = Vec::new();
+    while let Some((number, line)) = lines.next(){
+        let fields:Vec<&str> = line.split_whitespace().collect();
+        match fields.first().copied(){
+            Some("FONTBOUNDINGBOX") => bounding_box = Some(numbers(number, &fields, 4)?),
+            Some("FONT_ASCENT") => ascent = Some(numbers(number, &fields, 1)?[0]),
+            Some("FONT_DESCENT") => descent = Some(numbers(number, &fields, 1)?[0]),
+            Some("PIXEL_SIZE") => pixel_size = Some(numbers(number, &fields, 1)?[0]),
+            Some("STARTCHAR") =>{
+                let (mut encoding, mut advance, mut bbx) = (None, None, bounding_box.clone());
+                let mut bits = Vec::new();
+                loop{
+                    let Some((number, line)) = lines.next() else{
+                        return malformed(format!("line {number}: glyph is missing ENDCHAR"));
+                    };
+                    let fields:Vec<&str> = line.split_whitespace().collect();
+                    match fields.first().copied(){
+                        Some("ENCODING") => encoding = Some(numbers(number, &fields, 1)?[0]),
+                        Some("DWIDTH") => advance = Some(numbers(number, &fields, 1)?[0]),
+                        Some("BBX") => bbx = Some(numbers(number, &fields, 4)?),
+                        Some("BITMAP") =>{
+                            //rows of hex until ENDCHAR, each one the width of the bbx rounded up to a byte
+                            for (number, row) in lines.by_ref(){
+                                if row == "ENDCHAR"{break;}
+                                if row.len() % 2 != 0{
+                                    return malformed(format!("line {number}: {row} isnt whole bytes of hex"));
+                                }
+                                for pair in row.as_bytes().chunks(2){
+                                    let byte = std::str::from_utf8(pair).ok().and_then(|pair|u8::from_str_radix(pair, 16).ok());
+                                    match byte{
+                                        Some(byte) => bits.push(byte),
+                                        None => return malformed(format!("line {number}: {row} isnt hex")),
+                                    }
+                                }
+                            }
+                            break;
+                        }
+                        Some("ENDCHAR") => break,
+                        _ =>{}
+                    }
+                }
+                let Some(bbx) = bbx else{
+                    return malformed(format!("line {number}: glyph has no BBX and the font has no FONTBOUNDINGBOX"));
+                };
+                //-1 marks glyphs outside the encoding, ones past unicode are skipped too
+                let Some(char) = encoding.and_then(|encoding|u32::try_from(encoding).ok()).and_then(char::from_u32) else{continue};
+                if bbx[0] < 0 || bbx[1] < 0{
+                    return malformed(format!("line {number}: glyph {char:?} has a negative size"));
+                }
+                //a bbx bigger than the font or than the rows given would have the glyph allocated from numbers nothing backs up
+                if let Some(bounding_box) = &bounding_box && (bbx[0] > bounding_box[0] || bbx[1] > bounding_box[1]){
+                    return malformed(format!("line {number}: glyph {char:?} is bigger than the FONTBOUNDINGBOX"));
+                }
+                let size = Size::is(bbx[0] as usize, bbx[1] as usize);
+                if bits.len() < size.width.div_ceil(8)*size.height{
+                    return malformed(format!("line {number}: glyph {char:?} has fewer BITMAP rows than its BBX"));
+                }
+                glyphs.push(BitmapGlyph{
+                    char,
+                    size,
+                    //bbx offsets are from the pen to the bottom left with y going up, glyphs here go from the top left with y going down
+                    xoff:bbx[2],
+                    yoff:-(bbx[3] + bbx[1]),
+                    advance:advance.unwrap_or(bbx[0]) as f32,
+                    coverage:unpack_rows(&bits, size),
+                });
+            }
+            Some("ENDFONT") => break,
+            _ =>{}
+        }
+    }
+    let Some(bounding_box) = bounding_box else{
+        return malformed("bdf has no FONTBOUNDINGBOX");
+    };
+    //without the properties the bounding box says how far glyphs reach
+    let ascent = ascent.unwrap_or(bounding_box[1] + bounding_box[3]);
+    let descent = descent.unwrap_or(-bounding_box[3]);
+    let font_size = pixel_size.unwrap_or(bounding_box[1]);
+    Ok(sized_from_bitmaps(font_size as f32, ascent, descent, glyphs))
+}
+
+///Loads a psf font file, version 1 or 2
+pub fn load_psf(path:impl AsRef<Path>) -> Result<SizedFont, BitmapFontError>{
+    parse_psf(&fs::read(path)?)
+}
+
+///Parses a psf font, the kind linux consoles use. glyphs fill their whole cell and the baseline sits at the bottom of it.
+///fonts without a unicode table map glyph n to char n
+pub fn parse_psf(data:&[u8]) -> Result<SizedFont, BitmapFontError>{
+    let read_u32 = |at:usize|data.get(at..at + 4).map(|bytes|u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]));
+    //works out the layout of either version, the glyphs are packed the same way in both
+    let (glyph_count, size, glyphs_at, table) = if data.starts_with(&PSF1_MAGIC){
+        let (Some(&mode), Some(&height)) = (data.get(2), data.get(3)) else{
+            return malformed("psf1 header is cut short");
+        };
+        let glyph_count = if mode & PSF1_MODE512 != 0 {512} else {256};
+        (glyph_count, Size::is(8, height as usize), 4, (mode & (PSF1_MODEHASTAB | PSF1_MODESEQ) != 0).then_some(1))
+    }else if data.starts_with(&PSF2_MAGIC){
+        let header:Option<Vec<u32>> = (2..8).map(|field|read_u32(field*4)).collect();
+        let Some(&[header_size, flags, glyph_count, glyph_bytes, height, width]) = header.as_deref() else{
+            return malformed("psf2 header is cut short");
+        };
+        let size = Size::is(width as usize, height as usize);
+        if glyph_bytes as usize != size.width.div_ceil(8)*size.height{
+            return malformed(format!("psf2 glyphs are {glyph_bytes} bytes but {width}x{height} needs {}", size.width.div_ceil(8)*size.height));
+        }
+        (glyph_count as usize, size, header_size as usize, (flags & PSF2_HAS_UNICODE_TABLE != 0).then_some(2))
+    }else{
+        return malformed("not a psf font");
+    };
+    //zero sized glyphs would take no bytes, letting any glyph count pass as fitting in the file
+    if size.pixels() == 0{
+        return malformed(format!("psf glyphs are {}x{}", size.width, size.height));
+    }
+    if glyph_count > PSF_MAX_GLYPHS{
+        return malformed(format!("psf says it has {glyph_count} glyphs, more than there are chars"));
+    }
+    let glyph_bytes = size.width.div_ceil(8)*size.height;
+    let table_at = glyph_count.checked_mul(glyph_bytes).and_then(|bytes|bytes.checked_add(glyphs_at)).filter(|&end|end <= data.len());
+    let Some(table_at) = table_at else{
+        return malformed(format!("psf says it has {glyph_count} glyphs but the file ends first"));
+    };
+    //which chars every glyph draws
+    let chars:Vec<Vec<char>> = match table{
+        None => (0..glyph_count).map(|index|char::from_u32(index as u32).into_iter().collect()).collect(),
+        Some(1) => psf1_table(&data[table_at..], glyph_count),
+        Some(_) => psf2_table(&data[table_at..], glyph_count),
+    };
+    let mut glyphs = Vec::new();
+    for (index, chars) in chars.into_iter().enumerate(){
+        let bits = &data[glyphs_at + index*glyph_bytes..glyphs_at + (index + 1)*glyph_bytes];
+        let coverage = unpack_rows(bits, size);
+        for char in chars{
+            glyphs.push(BitmapGlyph{char, size, xoff:0, yoff:-(size.height as i32), advance:size.width as f32, coverage:coverage.clone()});
+        }
+    }
+    Ok(sized_from_bitmaps(size.height as f32, size.height as i32, 0, glyphs))
+}
+
+//chars for every glyph from a psf1 unicode table, little endian u16s ending in 0xffff per glyph. multi char sequences after 0xfffe are skipped
+fn psf1_table(table:&[u8], glyph_count:usize) -> Vec<Vec<char>>{
+    let mut words = table.chunks_exact(2).map(|pair|u16::from_le_bytes([pair[0], pair[1]]));
+    (0..glyph_count).map(|_|{
+        let mut chars = Vec::new();
+        let mut in_sequence = false;
+        for word in words.by_ref(){
+            match word{
+                0xffff => break,
+                0xfffe => in_sequence = true,
+                _ if !in_sequence => chars.extend(char::from_u32(word as u32)),
+                _ =>{}
+            }
+        }
+        chars
+    }).collect()
+}
+
+//chars for every glyph from a psf2 unicode table, utf 8 ending in 0xff per glyph. multi char sequences after 0xfe are skipped
+fn psf2_table(table:&[u8], glyph_count:usize) -> Vec<Vec<char>>{
+    let mut entries = table.split(|&byte|byte == 0xff);
+    (0..glyph_count).map(|_|{
+        let entry = entries.next().unwrap_or_default();
+        let singles = entry.split(|&byte|byte == 0xfe).next().unwrap_or_default();
+        String::from_utf8_lossy(singles).chars().filter(|&char|char != char::REPLACEMENT_CHARACTER).collect()
+    }).collect()
+}
+
+///FontSheet describes a sprite holding a grid of glyphs, one cell a char in reading order
+#[derive(Clone, Debug, PartialEq)]
+pub struct FontSheet{
+    ///size of every cell in the grid
+    pub cell:Size,
+    ///chars in the order their cells appear, left to right then top to bottom
+    pub charmap:String,
+    ///pixels from the top of a cell down to the baseline
+    pub baseline:usize,
+    ///trims empty columns off both sides of every glyph and advances by what is left plus this many pixels, none keeps every glyph a cell wide
+    pub spacing:Option<usize>,
+}
+
+impl FontSheet{
+    ///Monospaced sheet with the baseline at the bottom of the cells
+    pub fn new(cell:Size, charmap:impl Into<String>) -> Self{
+        FontSheet{cell, charmap:charmap.into(), baseline:cell.height, spacing:None}
+    }
+    pub fn with_baseline(mut self, baseline:usize) -> Self{
+        self.baseline = baseline;
+        self
+    }
+    ///Glyphs are only as wide as what they draw, with spacing pixels between them. chars with nothing drawn keep the cell width
+    pub fn proportional(mut self, spacing:usize) -> Self{
+        self.spacing = Some(spacing);
+        self
+    }
+    ///Cuts every cell out of sheet, a pixel is as covered as it is opaque
+    pub fn load(&self, sheet:&Sprite) -> Result<SizedFont, BitmapFontError>{
+        let (cell, baseline) = (self.cell, self.baseline as i32);
+        if cell.pixels() == 0{
+            return malformed("font sheet cells have no size");
+        }
+        let columns = sheet.size.width/cell.width;
+        let cells = columns*(sheet.size.height/cell.height);
+        if self.charmap.chars().count() > cells{
+            return malformed(format!("charmap has {} chars but the sheet only has {cells} cells", self.charmap.chars().count()));
+        }
+        let glyphs = self.charmap.chars().enumerate().map(|(index, char)|{
+            let (left, top) = ((index%columns)*cell.width, (index/columns)*cell.height);
+            let mut coverage = Vec::with_capacity(cell.pixels());
+            for y in top..top + cell.height{
+                for x in left..left + cell.width{
+                    coverage.push(sheet.pixel(x, y)[3]);
+                }
+            }
+            let mut glyph = BitmapGlyph{char, size:cell, xoff:0, yoff:-baseline, advance:cell.width as f32, coverage};
+            if let Some(spacing) = self.spacing{
+                trim_columns(&mut glyph, spacing);
+            }
+            glyph
+        }).collect();
+        Ok(sized_from_bitmaps(cell.height as f32, baseline, cell.height as i32 - baseline, glyphs))
+    }
+}
+
+//takes the empty columns off both sides of glyph and sets its advance to what is left plus spacing, empty glyphs are left as they are
+fn trim_columns(glyph:&mut BitmapGlyph, spacing:usize){
+    let width = glyph.size.width;
+    let column_empty = |x:usize|glyph.coverage.iter().skip(x).step_by(width).all(|&covered|covered == 0);
+    let Some(first) = (0..width).find(|&x|!column_empty(x)) else{return};
+    let last = (0..width).rev().find(|&x|!column_empty(x)).unwrap_or(first);
+    let trimmed = last + 1 - first;
+    glyph.coverage = glyph.coverage.chunks_exact(width).flat_map(|row|row[first..=last].iter().copied()).collect();
+    glyph.size = Size::is(trimmed, glyph.size.height);
+    glyph.advance = (trimmed + spacing) as f32;
+}
@@ -0,0 +1,61 @@
+// ===============================
+//            BLEND.RS
+// -------------------------------
+//  How an object or pixel is mixed into whatever
+//  is under it. opacity and tint scale the source
+//  color first, the blend mode then decides how
+//  it combines with the display
+// -------------------------------
+
+///How a premultiplied source color combines with the display under it, alpha always builds up the same way as normal
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BlendMode{
+    ///source over, what everything used before blend modes
+    #[default]
+    Normal,
+    ///adds the source on top, lightens, good for glows and flashes
+    Add,
+    ///multiplies by the source, darkens, good for shadows
+    Multiply,
+    ///inverse of multiply, lightens without blowing out as fast as add
+    Screen,
+    ///takes the source away from the display, darkens towards black
+    Subtract,
+}
+
+///Blend is the opacity, tint and blend mode an object is drawn with. the default draws the sprite exactly as stored
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Blend{
+    pub mode:BlendMode,
+    ///0 is invisible, 255 fully opaque, for fading objects in and out
+    pub opacity:u8,
+    ///every color channel is multiplied by the matching tint channel, white leaves the sprite as is
+    pub tint:[u8;3],
+}
+
+impl Default for Blend{
+    fn default() -> Self{
+        Blend::NORMAL
+    }
+}
+
+impl Blend{
+    pub const NORMAL:Blend = Blend{mode:BlendMode::Normal, opacity:255, tint:[255;3]};
+
+    pub fn with_mode(mut self, mode:BlendMode) -> Self{
+        self.mode = mode;
+        self
+    }
+    pub fn with_opacity(mut self, opacity:u8) -> Self{
+        self.opacity = opacity;
+        self
+    }
+    pub fn with_tint(mut self, tint:[u8;3]) -> Self{
+        self.tint = tint;
+        self
+    }
+    ///True if drawing with the blend is plain source over, lets the renderer keep its straight copy paths
+    pub fn is_normal(&self) -> bool{
+        *self == Blend::NORMAL
+    }
+}
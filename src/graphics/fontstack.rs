@@ -0,0 +1,127 @@
+// ===============================
+//           FONTSTACK.RS
+// -------------------------------
+//  a list of fonts searched in order for every char, so text
+//  mixing scripts and symbols can be drawn from whichever
+//  font has each char. chars none of them have get a
+//  replacement instead of an error
+// -------------------------------
+
+use std::{collections::HashMap, io::Error};
+
+use crate::{
+    graphics::{
+        bitmapfont::BitmapGlyph,
+        parse_ttf_bindings::{find_glyph_index, generate_glyph, LoadedFont},
+        texthandler::{rasterize_chars, sized_from_bitmaps, FontMetrics, RasterizedChars, SizedFont},
+    },
+    shared::Size,
+};
+
+///Replacement is what gets drawn for a char no font in the stack has
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Replacement{
+    ///an outlined box about the size of a letter, so it is clear something is missing
+    #[default]
+    Tofu,
+    ///the glyph of another char, like '?' or U+FFFD, taken from whichever font has it. falls back on tofu if none do
+    Char(char),
+    ///nothing at all, the char is left out like it was never there
+    Skip,
+}
+
+///FontStack is an ordered list of fonts, every char comes from the first one that has a glyph for it
+pub struct FontStack{
+    fonts:Vec<LoadedFont>,
+    pub replacement:Replacement,
+}
+
+impl FontStack{
+    ///Stack with primary at the bottom, its metrics decide how tall lines are
+    pub fn new(primary:LoadedFont) -> Self{
+        FontStack{fonts:vec![primary], replacement:Replacement::Tofu}
+    }
+    ///Adds a font to search after the ones already in the stack
+    pub fn with_fallback(mut self, fallback:LoadedFont) -> Self{
+        self.fonts.push(fallback);
+        self
+    }
+    pub fn with_replacement(mut self, replacement:Replacement) -> Self{
+        self.replacement = replacement;
+        self
+    }
+    pub fn len(&self) -> usize{
+        self.fonts.len()
+    }
+    pub fn is_empty(&self) -> bool{
+        self.fonts.is_empty()
+    }
+    ///Index of the first font in the stack with a glyph for char, none if no font has it
+    pub fn font_for(&mut self, char:char) -> Option<usize>{
+        self.fonts.iter_mut().position(|font|find_glyph_index(font, char).is_some())
+    }
+    ///Rasterizes every char in charset at font_size pixels tall, each one from the first font that has it. chars none of them have
+    ///get the replacement, so anything in charset can be drawn
+    pub fn sized(&mut self, font_size:f32, charset:&[char]) -> Result<SizedFont, Error>{
+        let mut charset = charset.to_vec();
+        charset.sort_unstable();
+        charset.dedup();
+        //line metrics come from the primary font so lines dont jump around when a fallback is used
+        let metrics = FontMetrics::new(&mut self.fonts[0], font_size);
+        let (ascent, descent) = (metrics.ascent.round() as i32, metrics.descent.round() as i32);
+        //chars grouped by the font they come from, kerning is only looked up between chars of the same font
+        let mut by_font = vec![Vec::new();self.fonts.len()];
+        let mut missing = Vec::new();
+        for &char in charset.iter(){
+            match self.font_for(char){
+                Some(font) => by_font[font].push(char),
+                None => missing.push(char),
+            }
+        }
+        let mut bitmaps = Vec::with_capacity(charset.len());
+        let mut kerning = HashMap::new();
+        for (font, chars) in by_font.iter().enumerate().filter(|(_, chars)|!chars.is_empty()){
+            let rasterized = rasterize(&mut self.fonts[font], font_size, chars)?;
+            bitmaps.extend(rasterized.glyphs.into_iter().map(|(char, advance, bitmap)|BitmapGlyph{char, advance, ..bitmap.unwrap_or_default()}));
+            kerning.extend(rasterized.kerning);
+        }
+        //chars no font has all get the same replacement, which isnt kerned since it isnt the char it stands in for
+        let replacement = match self.replacement{
+            _ if missing.is_empty() => None,
+            Replacement::Char(replacement) => match self.font_for(replacement){
+                Some(font) => rasterize(&mut self.fonts[font], font_size, &[replacement])?.glyphs.pop().map(|(char, advance, bitmap)|BitmapGlyph{char, advance, ..bitmap.unwrap_or_default()}),
+                None => Some(tofu(font_size, ascent)),
+            },
+            Replacement::Tofu => Some(tofu(font_size, ascent)),
+            Replacement::Skip => None,
+        };
+        if let Some(replacement) = replacement{
+            bitmaps.extend(missing.into_iter().map(|char|BitmapGlyph{char, ..replacement.clone()}));
+        }
+        Ok(sized_from_bitmaps(font_size, ascent, descent, bitmaps).with_line_spacing(metrics.line_gap.round() as i32, metrics.line_spacing.round() as i32).with_kerning(kerning))
+    }
+}
+
+//rasterizes chars from loaded_font as bitmaps, chars with nothing to draw only get an advance
+fn rasterize(loaded_font:&mut LoadedFont, font_size:f32, chars:&[char]) -> Result<RasterizedChars<BitmapGlyph>, Error>{
+    rasterize_chars(loaded_font, font_size, chars, |loaded_font, char|{
+        let glyph = generate_glyph(loaded_font, char, font_size)?;
+        let size = Size::is(glyph.width as usize, glyph.height as usize);
+        Ok(BitmapGlyph{char, size, xoff:glyph.xoff, yoff:glyph.yoff, advance:0.0, coverage:glyph.as_slice().to_vec()})
+    })
+}
+
+///Outlined box standing on the baseline, half font_size wide and most of the way up to ascent, what chars no font has are drawn as
+pub fn tofu(font_size:f32, ascent:i32) -> BitmapGlyph{
+    let size = Size::is(((font_size*0.5).round() as usize).max(3), ((ascent as f32*0.8).round() as usize).max(3));
+    let mut coverage = vec![0u8;size.pixels()];
+    for y in 0..size.height{
+        for x in 0..size.width{
+            if x == 0 || y == 0 || x == size.width - 1 || y == size.height - 1{
+                coverage[y*size.width + x] = 255;
+            }
+        }
+    }
+    //a pixel of room on both sides so boxes next to each other dont merge
+    BitmapGlyph{char:char::REPLACEMENT_CHARACTER, size, xoff:1, yoff:-(size.height as i32), advance:(size.width + 2) as f32, coverage}
+}
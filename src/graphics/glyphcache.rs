@@ -0,0 +1,277 @@
+// ===============================
+//           GLYPHCACHE.RS
+// -------------------------------
+//  rasterizes glyphs the first time they are drawn instead of
+//  a whole charset up front. glyphs of every font and size
+//  share one atlas, the least recently used ones are dropped
+//  once it goes over its memory budget. sized fonts built from
+//  the atlas are kept while all their glyphs are, so the same
+//  text keeps getting the same one
+// -------------------------------
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    io::Error,
+    ops::Range,
+    sync::Arc,
+};
+
+use crate::{
+    graphics::{
+        bitmapfont::BitmapGlyph,
+        parse_ttf_bindings::{generate_glyph, LoadedFont},
+        texthandler::{rasterize_chars, sized_from_bitmaps, SizedFont},
+    },
+    shared::Size,
+};
+
+///FontId is a font added to a glyph cache
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FontId(usize);
+
+//what a glyph is cached under, the size is kept as its bits so it can be hashed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct GlyphKey{
+    font:FontId,
+    size:u32,
+    char:char,
+}
+
+///CachedGlyph is a rasterized glyph living in the atlas of a glyph cache
+#[derive(Clone, Debug)]
+pub struct CachedGlyph{
+    pub char:char,
+    pub size:Size,
+    ///offset from the pen position on the baseline to the top left of the bitmap
+    pub xoff:i32,
+    pub yoff:i32,
+    pub advance:f32,
+    //bytes of the atlas holding the coverage, one a pixel
+    span:Range<usize>,
+    //tick of the last time the glyph was asked for, also its key in the lru
+    last_used:u64,
+}
+
+///GlyphCache rasterizes glyphs on demand and keeps them under a memory budget
+pub struct GlyphCache{
+    fonts:Vec<LoadedFont>,
+    glyphs:HashMap<GlyphKey, CachedGlyph>,
+    //glyphs by when they were last used, oldest first
+    lru:BTreeMap<u64, GlyphKey>,
+    tick:u64,
+    //coverage of every cached glyph, glyphs that were evicted leave gaps that get reused
+    atlas:Vec<u8>,
+    //gaps in the atlas, sorted and never touching each other or the end of the atlas
+    free:Vec<Range<usize>>,
+    //bytes of the atlas cached glyphs are using
+    used:usize,
+    budget:usize,
+    //the last sized font handed out for each font and size with the chars it was asked for, dropped once any of its glyphs is evicted
+    sized_fonts:HashMap<(FontId, u32), (Vec<char>, Arc<SizedFont>)>,
+}
+
+impl GlyphCache{
+    ///Empty cache that evicts glyphs once their coverage takes up more than budget bytes
+    pub fn new(budget:usize) -> Self{
+        GlyphCache{
+            fonts:Vec::new(),
+            glyphs:HashMap::new(),
+            lru:BTreeMap::new(),
+            tick:0,
+            atlas:Vec::new(),
+            free:Vec::new(),
+            used:0,
+            budget,
+            sized_fonts:HashMap::new(),
+        }
+    }
+    ///Hands a font to the cache, glyphs are asked for with the id it gives back
+    pub fn add_font(&mut self, loaded_font:LoadedFont) -> FontId{
+        self.fonts.push(loaded_font);
+        FontId(self.fonts.len() - 1)
+    }
+    pub fn loaded_font(&mut self, font:FontId) -> Option<&mut LoadedFont>{
+        self.fonts.get_mut(font.0)
+    }
+    ///Glyphs in the cache
+    pub fn len(&self) -> usize{
+        self.glyphs.len()
+    }
+    pub fn is_empty(&self) -> bool{
+        self.glyphs.is_empty()
+    }
+    ///Bytes of coverage cached glyphs are using, never more than the budget unless a single glyph is bigger than it.
+    ///the sized fonts the cache keeps hold a copy of glyphs that are still cached, so at most as much again is alive
+    pub fn used_bytes(&self) -> usize{
+        self.used
+    }
+    ///Bytes the atlas takes up, gaps left by evicted glyphs included
+    pub fn atlas_bytes(&self) -> usize{
+        self.atlas.len()
+    }
+    pub fn budget(&self) -> usize{
+        self.budget
+    }
+    ///True if the glyph is cached, doesnt count as using it
+    pub fn contains(&self, font:FontId, font_size:f32, char:char) -> bool{
+        self.glyphs.contains_key(&GlyphKey{font, size:font_size.to_bits(), char})
+    }
+    ///Coverage of glyph, one byte a pixel in reading order
+    pub fn coverage(&self, glyph:&CachedGlyph) -> &[u8]{
+        &self.atlas[glyph.span.clone()]
+    }
+    ///Glyph for char at font_size, rasterized the first time it is asked for. none if the font doesnt have the char.
+    ///counts as using the glyph, so it is the last to be evicted
+    pub fn glyph(&mut self, font:FontId, font_size:f32, char:char) -> Result<Option<&CachedGlyph>, Error>{
+        let key = GlyphKey{font, size:font_size.to_bits(), char};
+        self.tick += 1;
+        match self.glyphs.get_mut(&key){
+            Some(glyph) =>{
+                self.lru.remove(&glyph.last_used);
+                glyph.last_used = self.tick;
+                self.lru.insert(self.tick, key);
+            }
+            None => self.rasterize(key)?,
+        }
+        Ok(self.glyphs.get(&key))
+    }
+    ///Sized font with the glyphs text needs at font_size, rasterizing only the ones that arent cached yet. it can be drawn
+    ///with Text like any other sized font. chars the font doesnt have are left out. asking again for chars it already has
+    ///gives back the same font, so a display can tell the text hasnt changed
+    pub fn sized(&mut self, font:FontId, font_size:f32, text:&str) -> Result<Arc<SizedFont>, Error>{
+        let mut chars:Vec<char> = text.chars().collect();
+        chars.sort_unstable();
+        chars.dedup();
+        let key = (font, font_size.to_bits());
+        if let Some((rasterized, sized_font)) = self.sized_fonts.get(&key){
+            if chars.iter().all(|char|rasterized.binary_search(char).is_ok()){
+                let (rasterized, sized_font) = (rasterized.clone(), sized_font.clone());
+                //counts as using every glyph of the font so it stays cached as long as it is drawn
+                for char in rasterized{
+                    self.glyph(font, font_size, char)?;
+                }
+                return Ok(sized_font);
+            }
+            chars.extend_from_slice(rasterized);
+            chars.sort_unstable();
+            chars.dedup();
+        }
+        //metrics and kerning only, the glyphs themselves come out of the atlas
+        let rasterized = rasterize_chars(self.font(font)?, font_size, &chars, |_, _|Ok(()))?;
+        let mut bitmaps = Vec::with_capacity(rasterized.glyphs.len());
+        for &(char, _, _) in rasterized.glyphs.iter(){
+            //coverage is copied out straight away, later glyphs are free to evict this one
+            let Some(glyph) = self.glyph(font, font_size, char)?.cloned() else{continue};
+            let coverage = self.coverage(&glyph).to_vec();
+            bitmaps.push(BitmapGlyph{char, size:glyph.size, xoff:glyph.xoff, yoff:glyph.yoff, advance:glyph.advance, coverage});
+        }
+        let metrics = rasterized.metrics;
+        //lines are as tall as the font says, so text with only short glyphs lines up with text that has tall ones
+        let (ascent, descent) = (metrics.ascent.round() as i32, metrics.descent.round() as i32);
+        let sized_font = Arc::new(sized_from_bitmaps(font_size, ascent, descent, bitmaps)
+            .with_line_spacing(metrics.line_gap.round() as i32, metrics.line_spacing.round() as i32)
+            .with_kerning(rasterized.kerning));
+        self.sized_fonts.insert(key, (chars, sized_font.clone()));
+        Ok(sized_font)
+    }
+    ///Drops every glyph of every size of font, for when it isnt going to be drawn again for a while
+    pub fn evict_font(&mut self, font:FontId){
+        let keys:Vec<GlyphKey> = self.glyphs.keys().filter(|key|key.font == font).copied().collect();
+        for key in keys{
+            self.evict(key);
+        }
+    }
+
+    //rasterizes the glyph for key into the atlas, chars the font doesnt have are left out of the cache
+    fn rasterize(&mut self, key:GlyphKey) -> Result<(), Error>{
+        let GlyphKey{font, char, ..} = key;
+        let font_size = f32::from_bits(key.size);
+        let rasterized = rasterize_chars(self.font(font)?, font_size, &[char], |loaded_font, char|generate_glyph(loaded_font, char, font_size))?;
+        let Some((_, advance, loaded_glyph)) = rasterized.glyphs.into_iter().next() else{
+            return Ok(());
+        };
+        let mut glyph = CachedGlyph{char, size:Size::default(), xoff:0, yoff:0, advance, span:0..0, last_used:self.tick};
+        //spaces and the like only move the pen, they take up no room in the atlas
+        if let Some(loaded_glyph) = loaded_glyph{
+            glyph.size = Size::is(loaded_glyph.width as usize, loaded_glyph.height as usize);
+            glyph.xoff = loaded_glyph.xoff;
+            glyph.yoff = loaded_glyph.yoff;
+            glyph.span = self.allocate(loaded_glyph.size);
+            self.atlas[glyph.span.clone()].copy_from_slice(loaded_glyph.as_slice());
+        }
+        self.lru.insert(self.tick, key);
+        self.glyphs.insert(key, glyph);
+        Ok(())
+    }
+    //font for id, an error for ids that didnt come from this cache
+    fn font(&mut self, font:FontId) -> Result<&mut LoadedFont, Error>{
+        self.fonts.get_mut(font.0).ok_or_else(||Error::other(format!("Glyph cache has no font {}", font.0)))
+    }
+    //finds room in the atlas for len bytes, evicting the least recently used glyphs until they fit in the budget
+    fn allocate(&mut self, len:usize) -> Range<usize>{
+        while self.used + len > self.budget{
+            let Some((_, key)) = self.lru.pop_first() else{break};
+            self.evict(key);
+        }
+        self.used += len;
+        //first gap big enough, whatever is left over stays a gap
+        if let Some(index) = self.free.iter().position(|gap|gap.len() >= len){
+            let start = self.free[index].start;
+            if self.free[index].len() == len{
+                self.free.remove(index);
+            }else{
+                self.free[index].start += len;
+            }
+            return start..start + len;
+        }
+        //the atlas only grows past the budget if the gaps are too scattered to fit the glyph, packing it fixes that
+        if self.atlas.len() + len > self.budget.max(self.used){
+            self.compact();
+        }
+        let start = self.atlas.len();
+        self.atlas.resize(start + len, 0);
+        start..start + len
+    }
+    //removes a glyph and gives its bytes back to the atlas
+    fn evict(&mut self, key:GlyphKey){
+        let Some(glyph) = self.glyphs.remove(&key) else{return};
+        self.lru.remove(&glyph.last_used);
+        //a sized font missing a glyph cant be handed out again, the coverage it copied isnt counted against the budget any more
+        let sized_key = (key.font, key.size);
+        if self.sized_fonts.get(&sized_key).is_some_and(|(chars, _)|chars.binary_search(&key.char).is_ok()){
+            self.sized_fonts.remove(&sized_key);
+        }
+        if glyph.span.is_empty(){return;}
+        self.used -= glyph.span.len();
+        //merges the gap with the ones it touches, a gap at the end just shrinks the atlas
+        let index = self.free.partition_point(|gap|gap.start < glyph.span.start);
+        let mut gap = glyph.span;
+        if index < self.free.len() && self.free[index].start == gap.end{
+            gap.end = self.free.remove(index).end;
+        }
+        if index > 0 && self.free[index - 1].end == gap.start{
+            gap.start = self.free.remove(index - 1).start;
+        }
+        if gap.end == self.atlas.len(){
+            self.atlas.truncate(gap.start);
+        }else{
+            let index = self.free.partition_point(|other|other.start < gap.start);
+            self.free.insert(index, gap);
+        }
+    }
+    //moves every glyph to the front of the atlas so the gaps between them become one at the end, which is then cut off
+    fn compact(&mut self){
+        let mut glyphs:Vec<&mut CachedGlyph> = self.glyphs.values_mut().filter(|glyph|!glyph.span.is_empty()).collect();
+        glyphs.sort_by_key(|glyph|glyph.span.start);
+        let mut end = 0;
+        for glyph in glyphs{
+            let len = glyph.span.len();
+            self.atlas.copy_within(glyph.span.clone(), end);
+            glyph.span = end..end + len;
+            end += len;
+        }
+        self.atlas.truncate(end);
+        self.atlas.shrink_to(self.budget);
+        self.free.clear();
+    }
+}
@@ -6,7 +6,18 @@
 //  itself are here
 // -------------------------------
 
+pub mod animation;
+pub mod bitmapfont;
+pub mod blend;
+pub mod fontstack;
+pub mod glyphcache;
 pub mod renderer;
+pub mod richtext;
+pub mod sdf;
+pub mod sfnt;
+pub mod render_target;
+pub mod transform;
 pub mod sprites;
 pub mod texthandler;
+pub mod textlayout;
 pub mod parse_ttf_bindings;
\ No newline at end of file
@@ -12,12 +12,14 @@
 //was not due to a tangible performance benefit (Though stbtt truetype is blazing fast by any metric)
 //but more about learning how to code c, how to bind c functions to rust, and how to work with unsafe code blocks and raw pointers.
 //It was not a practical choice but rather a learning experience that, had I decided to plop in a premade rust crate I would not have gotten.
-//I will implement the checksum verification when i feel ready to tackle that, at which point code will be entirely safe.
+//Font data now goes through the validator in sfnt.rs before stbtt sees it, which bounds checks the table directory, the offsets in cmap, loca and hmtx
+//and verifies the checksums. glyph outlines, kerning and the glyph ids cmap hands out are still trusted, so a crafted font can still misbehave.
 
 use std::{
-    env::current_dir, 
-    io::Error, 
-    ops::Range, 
+    env::{current_dir, current_exe}, 
+    fs,
+    io::{Error, ErrorKind}, 
+    path::{Path, PathBuf},
     slice::from_raw_parts
 };
 
@@ -33,16 +35,28 @@ use libc::{
     size_t
 };
 
-use smallvec::SmallVec;
+use crate::{graphics::{sfnt::{validate_font, FontError}, texthandler::{split_coverage, GlyphRange}}, shared::Rect};
 
 //declares external functions from parse_text.c
 unsafe extern "C"{
     //private function, returns raw pointer, public facing api wraps this in the LoadedFont Struct
-    unsafe fn c_load_font(font_path:*const c_char, font_name:*const c_char,) -> LoadedFont;
+    unsafe fn c_load_font_from_memory(font_ptr:*const c_uchar, font_size:size_t, font_name:*const c_char, copy:c_int) -> LoadedFont;
+    unsafe fn c_face_count(loaded_font:*mut LoadedFont) -> c_int;
+    unsafe fn c_select_face(loaded_font:*mut LoadedFont, face:c_int) -> c_int;
+    unsafe fn c_get_font_name_string(loaded_font:*mut LoadedFont, length:*mut c_int, platform_id:c_int, encoding_id:c_int, language_id:c_int, name_id:c_int) -> *const c_char;
     unsafe fn c_generate_glyph(loaded_font:*mut LoadedFont, codepoint:c_int, px:c_float) -> LoadedGlyph;
+    unsafe fn c_generate_sdf_glyph(loaded_font:*mut LoadedFont, codepoint:c_int, px:c_float, padding:c_int, onedge_value:c_uchar, pixel_dist_scale:c_float) -> LoadedGlyph;
     unsafe fn c_unload_font(loaded_font:*mut LoadedFont);
     unsafe fn c_unload_glyph(loaded_glyph:*mut LoadedGlyph);
     unsafe fn c_get_kerning(loaded_font:*mut LoadedFont,ch1:c_int,ch2:c_int) -> c_int;
+    unsafe fn c_scale_for_pixel_height(loaded_font:*mut LoadedFont, px:c_float) -> c_float;
+    unsafe fn c_get_codepoint_hmetrics(loaded_font:*mut LoadedFont, codepoint:c_int, advance_width:*mut c_int, left_side_bearing:*mut c_int);
+    unsafe fn c_is_glyph_empty(loaded_font:*mut LoadedFont, codepoint:c_int) -> c_int;
+    unsafe fn c_get_font_vmetrics(loaded_font:*mut LoadedFont, ascent:*mut c_int, descent:*mut c_int, line_gap:*mut c_int);
+    unsafe fn c_find_glyph_index(loaded_font:*mut LoadedFont, codepoint:c_int) -> c_int;
+    unsafe fn c_get_codepoint_box(loaded_font:*mut LoadedFont, codepoint:c_int, x0:*mut c_int, y0:*mut c_int, x1:*mut c_int, y1:*mut c_int) -> c_int;
+    unsafe fn c_get_codepoint_bitmap_box(loaded_font:*mut LoadedFont, codepoint:c_int, scale:c_float, x0:*mut c_int, y0:*mut c_int, x1:*mut c_int, y1:*mut c_int);
+    unsafe fn c_get_font_bounding_box(loaded_font:*mut LoadedFont, x0:*mut c_int, y0:*mut c_int, x1:*mut c_int, y1:*mut c_int);
 }
 //struct definitions for the structs from c structs
 #[repr(C)]
@@ -61,6 +75,10 @@ pub struct LoadedFont{
     pub font_size: size_t,
     //stbtt_fonntinfo handle
     font_info_ptr: *mut stbtt_font_info,
+    //nonzero if the c side allocated font_ptr and frees it on unload, zero for static data it only borrows
+    owned: c_int,
+    //face of a collection stbtt is reading, zero for plain fonts
+    face: c_int,
 }
 
 impl LoadedFont{
@@ -83,12 +101,19 @@ impl LoadedFont{
             // and the best I could do is get a version converted to standard ttf. anyways stbtt truetype supports it for some unknown
             // reason (probably because the library can be used on classic macs too) so i am keeping it here for the sake of extensiveness
             0x74727565 => "AppleTrueType",
-            0x77746366 =>"TrueTypeCollection",
+            0x74746366 =>"TrueTypeCollection",
             _ =>"Unknown",
         }
     }
 }
 
+impl LoadedFont{
+    ///Index of the face in use, only ever nonzero for collections
+    pub fn face(&self) -> usize{
+        self.face as usize
+    }
+}
+
 //deallocates the memory on LoadedFont drop
 impl Drop for LoadedFont{
     fn drop(&mut self){
@@ -114,9 +139,9 @@ impl LoadedGlyph{
             self.as_slice()
         );
     }
-    pub fn push_ranges(&self, translucent_ranges:&mut SmallVec<[Range<u8>;1024]>,solid_ranges:&mut SmallVec<[Range<u8>;1024]>){
-        let glyph_slice = self.as_slice();
-        todo!();
+    ///Splits every line of the glyph into runs of fully and partly covered pixels, uncovered runs are left out
+    pub fn push_ranges(&self, translucent_ranges:&mut Vec<GlyphRange>, solid_ranges:&mut Vec<GlyphRange>){
+        split_coverage(self.as_slice(), self.width as usize, translucent_ranges, solid_ranges);
     }
     //function only used internally, returns a slice based off of 
     pub(crate) fn as_slice(&self) -> &[u8]{
@@ -143,25 +168,56 @@ impl Drop for LoadedGlyph{
     }
 }
 
-///Loads a font into memory, and returns an optional LoadedFont struct
-///NOTE: Undefined behavior can be induced by trying to load a corrupted ttf file with a valid header,  (Header is verified so you cant feed it junk data)
-///as sstbb_fontinfo does not enforce checksums. This is an acceptable level of memory safety to me. implementing my own
-///checksum verification is on the todo list for eventually. Maybe once I get better at c.
-pub fn load_font(font_name:&str) -> Option<LoadedFont>{
-    //initializes new string font_path which contains the path to the font file spacified in function call
-    let c_font_path= CString::new(
-        //formats a new string that contains the directory 
-        format!("{}/fonts/{}",current_dir().expect("Invalid directory").display(), font_name)
-    ).expect("Working directory contains Null, cannot convert to cstring");
+///Loads a font from the fonts folder. looks in fonts/ under the working directory first, then next to the executable,
+///then in the crate the binary was built from, so it works wherever it is launched from.
+///the font is validated before stbtt sees it, so a corrupted file is turned away with an error instead of being read out of bounds
+pub fn load_font(font_name:&str) -> Result<LoadedFont, FontError>{
+    match font_search_paths(font_name).into_iter().find(|path|path.is_file()){
+        Some(path) => load_font_from_path(&path),
+        None => Err(FontError::Io(Error::new(ErrorKind::NotFound, format!("Could not find font {font_name} in fonts folder")))),
+    }
+}
+
+//every place load_font looks for font_name, in the order it looks
+fn font_search_paths(font_name:&str) -> Vec<PathBuf>{
+    let mut folders = Vec::new();
+    if let Ok(dir) = current_dir(){
+        folders.push(dir);
+    }
+    if let Some(dir) = current_exe().ok().as_deref().and_then(Path::parent){
+        folders.push(dir.to_path_buf());
+    }
+    folders.push(PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    folders.into_iter().map(|folder|folder.join("fonts").join(font_name)).collect()
+}
+
+///Loads the font file at path, same as load_font but from anywhere
+pub fn load_font_from_path(path:&Path) -> Result<LoadedFont, FontError>{
+    let font_data = fs::read(path)?;
+    let font_name = path.file_name().map_or(path.to_string_lossy(), |name|name.to_string_lossy());
+    //the c side copies the data so the buffer read here can be dropped
+    load_validated(&font_data, &font_name, true)
+}
+
+///Loads a font straight from font data, like a font shipped inside the binary with include_bytes!. the data is used in place and never copied.
+///font_name is only used in error messages
+pub fn load_font_from_bytes(font_data:&'static [u8], font_name:&str) -> Result<LoadedFont, FontError>{
+    //data is static so it outlives the font, c marks it as not owned and never writes to or frees it
+    load_validated(font_data, font_name, false)
+}
+
+//validates font_data and hands it to c, which copies it first if copy is set. without copy font_data has to outlive the font
+fn load_validated(font_data:&[u8], font_name:&str, copy:bool) -> Result<LoadedFont, FontError>{
+    validate_font(font_data)?;
     //generates c_string from font name, unsed entirely for error formatting in the c code.
-    let c_font_name = CString::new(font_name).expect("Font name contains Null, cannot convert to cstring");
-    //pointer to strings only live for duration of c function call and are then dropped.
-    let loaded_font = unsafe{c_load_font(c_font_path.as_ptr(),c_font_name.as_ptr())};
-    //checks to see if c function returned a null pointer, if so, returns None
+    let c_font_name = CString::new(font_name.replace('\0', "")).expect("Nulls were just removed from the font name");
+    //pointer to the name only lives for duration of c function call and is then dropped.
+    let loaded_font = unsafe{c_load_font_from_memory(font_data.as_ptr(), font_data.len(), c_font_name.as_ptr(), copy as c_int)};
+    //checks to see if c function returned a null pointer, if so, stbtt refused the font even though it validated
     if loaded_font.font_ptr.is_null(){
-        return None;
+        return Err(FontError::InitFailed);
     }
-    Some(loaded_font)
+    Ok(loaded_font)
 }
 ///Safe rust binding for c generate glyph function. handles null invariants and propogates error if so
 pub fn generate_glyph(loaded_font:&mut LoadedFont, char:char, font_height:f32) -> Result<LoadedGlyph, Error>{
@@ -177,13 +233,39 @@ pub fn generate_glyph(loaded_font:&mut LoadedFont, char:char, font_height:f32) -
             codepoint, px
         )
     };
-    //if c_generate_glyph returns a null pointer return error
+    //if c_generate_glyph returns a null pointer return error, saying why when it is something the caller can check for
     if glyph.ptr.is_null(){
+        if find_glyph_index(loaded_font, char).is_none(){
+            return Err(Error::new(ErrorKind::NotFound, format!("Font has no glyph for char {char:?}")));
+        }
+        if is_glyph_empty(loaded_font, char){
+            return Err(Error::other(format!("Char {char:?} has nothing to draw")));
+        }
         return Err(Error::other(format!("Failed to initialize codepoint for char {char}")))
     }
     Ok(glyph)
 }
 
+///Distance field of char at font_height pixels tall instead of its coverage. a pixel on the outline is onedge_value, every pixel further in
+///adds pixel_dist_scale and every pixel further out takes it away. the field reaches padding pixels past the outline on every side
+pub fn generate_sdf_glyph(loaded_font:&mut LoadedFont, char:char, font_height:f32, padding:i32, onedge_value:u8, pixel_dist_scale:f32) -> Result<LoadedGlyph, Error>{
+    //safe for the same reasons as generate_glyph, the field is malloced by stbtt and freed by the glyphs drop
+    let glyph = unsafe{
+        c_generate_sdf_glyph(
+            loaded_font as *mut LoadedFont,
+            char as c_int, font_height as c_float,
+            padding as c_int, onedge_value as c_uchar, pixel_dist_scale as c_float
+        )
+    };
+    if glyph.ptr.is_null(){
+        if find_glyph_index(loaded_font, char).is_none(){
+            return Err(Error::new(ErrorKind::NotFound, format!("Font has no glyph for char {char:?}")));
+        }
+        return Err(Error::other(format!("Char {char:?} has nothing to draw")));
+    }
+    Ok(glyph)
+}
+
 #[inline]
 pub fn get_kerning(loaded_font:&mut LoadedFont, char1:char, char2:char) -> i32{
     //safe because only invariant for operation returns a zero for no kerning table. No invariants can result in memory unsafety 
@@ -193,4 +275,176 @@ pub fn get_kerning(loaded_font:&mut LoadedFont, char1:char, char2:char) -> i32{
             char1 as c_int, char2 as c_int
         )
     }
-}
\ No newline at end of file
+}
+///Scale factor that turns unscaled font units, like the ones kerning and hmetrics return, into pixels for a font font_height pixels tall
+pub fn scale_for_pixel_height(loaded_font:&mut LoadedFont, font_height:f32) -> f32{
+    //safe for the same reason as get_kerning, stbtt only reads the font tables
+    unsafe{c_scale_for_pixel_height(loaded_font as *mut LoadedFont, font_height as c_float)}
+}
+
+///Advance width and left side bearing of char in unscaled font units. chars the font doesnt have get the metrics of its missing glyph
+pub fn get_codepoint_hmetrics(loaded_font:&mut LoadedFont, char:char) -> (i32, i32){
+    let (mut advance_width, mut left_side_bearing):(c_int, c_int) = (0, 0);
+    //pointers are to locals that outlive the call, stbtt writes both before returning
+    unsafe{
+        c_get_codepoint_hmetrics(
+            loaded_font as *mut LoadedFont,
+            char as c_int,
+            &mut advance_width, &mut left_side_bearing
+        );
+    }
+    (advance_width, left_side_bearing)
+}
+
+///True if char has no outline, like a space. generating a glyph for it would fail since the bitmap comes back empty
+pub fn is_glyph_empty(loaded_font:&mut LoadedFont, char:char) -> bool{
+    unsafe{c_is_glyph_empty(loaded_font as *mut LoadedFont, char as c_int) != 0}
+}
+
+///Ascent, descent and line gap of the font in unscaled font units. descent is negative, the next line starts ascent - descent + line_gap below the last
+pub fn get_font_vmetrics(loaded_font:&mut LoadedFont) -> (i32, i32, i32){
+    let (mut ascent, mut descent, mut line_gap):(c_int, c_int, c_int) = (0, 0, 0);
+    //same as get_codepoint_hmetrics, the pointers are to locals stbtt fills before returning
+    unsafe{
+        c_get_font_vmetrics(
+            loaded_font as *mut LoadedFont,
+            &mut ascent, &mut descent, &mut line_gap
+        );
+    }
+    (ascent, descent, line_gap)
+}
+
+///Index of the glyph the font uses for char, none if the font doesnt have one. chars without one get drawn as the fonts missing glyph
+pub fn find_glyph_index(loaded_font:&mut LoadedFont, char:char) -> Option<u32>{
+    //safe for the same reason as get_kerning, stbtt only reads the cmap table
+    let index = unsafe{c_find_glyph_index(loaded_font as *mut LoadedFont, char as c_int)};
+    (index > 0).then_some(index as u32)
+}
+
+///Bounding box of the outline of char in unscaled font units, y goes up from the baseline. none if it has no outline, like a space
+pub fn get_codepoint_box(loaded_font:&mut LoadedFont, char:char) -> Option<Rect>{
+    let mut rect = Rect::default();
+    //pointers are to the fields of a local rect that outlives the call, stbtt only writes them when it returns nonzero
+    let found = unsafe{
+        c_get_codepoint_box(
+            loaded_font as *mut LoadedFont,
+            char as c_int,
+            &mut rect.x0, &mut rect.y0, &mut rect.x1, &mut rect.y1
+        )
+    };
+    (found != 0).then_some(rect)
+}
+
+///Box the bitmap of char covers at scale in pixels relative to the pen on the baseline, y goes down. the same size and offset generate_glyph
+///would give, without rasterizing anything
+pub fn get_codepoint_bitmap_box(loaded_font:&mut LoadedFont, char:char, scale:f32) -> Rect{
+    let mut rect = Rect::default();
+    //same as get_codepoint_box, stbtt writes all four before returning, zeros for chars without an outline
+    unsafe{
+        c_get_codepoint_bitmap_box(
+            loaded_font as *mut LoadedFont,
+            char as c_int, scale as c_float,
+            &mut rect.x0, &mut rect.y0, &mut rect.x1, &mut rect.y1
+        );
+    }
+    rect
+}
+
+///Box every glyph of the font fits in, unscaled font units with y going up
+pub fn get_font_bounding_box(loaded_font:&mut LoadedFont) -> Rect{
+    let mut rect = Rect::default();
+    unsafe{
+        c_get_font_bounding_box(
+            loaded_font as *mut LoadedFont,
+            &mut rect.x0, &mut rect.y0, &mut rect.x1, &mut rect.y1
+        );
+    }
+    rect
+}
+
+///name table id of the family name, like "Times New Roman"
+pub const NAME_FAMILY:u16 = 1;
+///name table id of the style name, like "Bold Italic"
+pub const NAME_STYLE:u16 = 2;
+///name table id of the full name, usually family and style together
+pub const NAME_FULL:u16 = 4;
+
+///FontFace is one face of a font file along with its names, a plain font has one and a collection can have many
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FontFace{
+    pub index:usize,
+    pub family:String,
+    pub style:String,
+}
+
+///Number of faces in the font, more than one only for collections
+pub fn face_count(loaded_font:&mut LoadedFont) -> usize{
+    //safe, the c side bounds checks the collection header against the size of the data before reading any offsets
+    unsafe{c_face_count(loaded_font as *mut LoadedFont).max(0) as usize}
+}
+
+///Switches to another face of a collection, everything after reads from that face. fails and keeps the current face if there is no such face
+pub fn select_face(loaded_font:&mut LoadedFont, face:usize) -> Result<(), Error>{
+    let Ok(c_face) = c_int::try_from(face) else{
+        return Err(Error::other(format!("Font has no face {face}")));
+    };
+    if unsafe{c_select_face(loaded_font as *mut LoadedFont, c_face)} == 0{
+        return Err(Error::other(format!("Failed to select face {face}")));
+    }
+    Ok(())
+}
+
+///Entry name_id of the name table of the current face, none if the font doesnt have it in an encoding that can be read
+pub fn get_font_name(loaded_font:&mut LoadedFont, name_id:u16) -> Option<String>{
+    //windows unicode in english, unicode platform, then mac roman in english, the first one the font has wins
+    for (platform_id, encoding_id, language_id) in [(3, 1, 0x409), (0, 3, 0), (1, 0, 0)]{
+        let mut length:c_int = 0;
+        let name_ptr = unsafe{
+            c_get_font_name_string(loaded_font as *mut LoadedFont, &mut length, platform_id, encoding_id, language_id, name_id as c_int)
+        };
+        if name_ptr.is_null() || length <= 0{continue;}
+        //stbtt points into the font data, which lives as long as loaded_font. the length it gives was read from the name table
+        //so it is only as good as the font, it is checked against the end of the data first
+        let start = name_ptr as usize - loaded_font.font_ptr as usize;
+        if start + length as usize > loaded_font.font_size{continue;}
+        let bytes = unsafe{from_raw_parts(name_ptr as *const u8, length as usize)};
+        return Some(match platform_id{
+            //mac roman, everything past ascii is close enough to latin 1 for names
+            1 => bytes.iter().map(|&byte|byte as char).collect(),
+            //utf 16, big endian like everything else in the font
+            _ => char::decode_utf16(bytes.chunks_exact(2).map(|pair|u16::from_be_bytes([pair[0], pair[1]])))
+                .map(|char|char.unwrap_or(char::REPLACEMENT_CHARACTER)).collect(),
+        });
+    }
+    None
+}
+
+///Every face in the font with its family and style names. the face in use is left as it was
+pub fn list_faces(loaded_font:&mut LoadedFont) -> Vec<FontFace>{
+    let current = loaded_font.face();
+    let mut faces = Vec::new();
+    for index in 0..face_count(loaded_font){
+        if select_face(loaded_font, index).is_err(){continue;}
+        faces.push(FontFace{
+            index,
+            family:get_font_name(loaded_font, NAME_FAMILY).unwrap_or_default(),
+            style:get_font_name(loaded_font, NAME_STYLE).unwrap_or_default(),
+        });
+    }
+    //the face in use was loaded fine before so selecting it again cant fail
+    let _ = select_face(loaded_font, current);
+    faces
+}
+
+///Switches to the face whose full name, family and style, or family alone is name, ignoring case. the first match wins
+pub fn select_face_named(loaded_font:&mut LoadedFont, name:&str) -> Result<(), Error>{
+    let faces = list_faces(loaded_font);
+    let full = |face:&&FontFace|format!("{} {}", face.family, face.style).eq_ignore_ascii_case(name);
+    let family = |face:&&FontFace|face.family.eq_ignore_ascii_case(name);
+    //an exact family and style match beats a face that only shares the family
+    let face = faces.iter().find(full).or_else(||faces.iter().find(family));
+    match face{
+        Some(face) => select_face(loaded_font, face.index),
+        None => Err(Error::other(format!("Font has no face named {name}"))),
+    }
+}
@@ -0,0 +1,61 @@
+// ===============================
+//          RENDER_TARGET.RS
+// -------------------------------
+//  Backbuffers the renderhook can copy frames into.
+//  pixels is the windowed target, framebuffer is a
+//  plain cpu buffer for running without a window or gpu
+// -------------------------------
+
+use pixels::Pixels;
+
+use crate::shared::{Size, BPP};
+
+///Anything the renderhook can present frames to. all the renderer needs is a resizable rgba buffer to write into
+pub trait RenderTarget{
+    ///Resizes the backbuffer to size, contents are unspecified after a resize
+    fn resize(&mut self, size:Size);
+    ///Mutable access to the rgba bytes of the backbuffer
+    fn frame_mut(&mut self) -> &mut [u8];
+}
+
+//windowed target, just forwards to the pixels backbuffer
+impl RenderTarget for Pixels{
+    fn resize(&mut self, size:Size){
+        self.resize_buffer(size.width as u32, size.height as u32).unwrap();
+    }
+    fn frame_mut(&mut self) -> &mut [u8]{
+        Pixels::frame_mut(self)
+    }
+}
+
+///Framebuffer is an in memory backbuffer. used for headless runs where there is no display server to hand a surface to
+#[derive(Clone, Debug, Default)]
+pub struct Framebuffer{
+    pub size:Size,
+    pub frame:Vec<u8>,
+}
+
+impl Framebuffer{
+    ///Public constructor for framebuffer, zero filled
+    pub fn new(size:Size) -> Self{
+        Framebuffer{
+            size,
+            frame:vec![0;size.pixels()*BPP],
+        }
+    }
+    ///Returns the last frame copied into the framebuffer
+    pub fn frame(&self) -> &[u8]{
+        &self.frame
+    }
+}
+
+impl RenderTarget for Framebuffer{
+    fn resize(&mut self, size:Size){
+        self.size = size;
+        self.frame.clear();
+        self.frame.resize(size.pixels()*BPP, 0);
+    }
+    fn frame_mut(&mut self) -> &mut [u8]{
+        &mut self.frame
+    }
+}
@@ -6,10 +6,13 @@
 //  alpha transparency methods
 // -------------------------------
 
+use crate::graphics::blend::{Blend, BlendMode};
+use crate::graphics::render_target::RenderTarget;
+use crate::graphics::sprites::{palette::Palette, rle::RleSprite, PixelRange, Sprite};
+use crate::graphics::transform::{Affine, Sampling};
 use crate::object::Object;
 use crate::shared::*;
 use pixels::Pixels;
-use smallvec::SmallVec;
 ///Camera is a dumb box that contains a position and a size, referenced by the capture function.
 #[derive(Clone, Copy, Debug)]
 pub struct Camera{
@@ -32,7 +35,9 @@ impl Camera{
 pub struct Pixel{
     pub point:Point,
     pub color:[u8;4],
-    pub translucent:bool
+    pub translucent:bool,
+    ///how the pixel mixes with the display, pixels with different modes go on separate canvases
+    pub blend:BlendMode,
 }
 impl Pixel{
     pub fn with_color(point:Point, color:[u8;4]) -> Self{
@@ -44,91 +49,209 @@ impl Pixel{
             Pixel{
                 point,
                 color:[r,g,b,a],
-                translucent:true
+                translucent:true,
+                blend:BlendMode::Normal,
             }
         }else{
             Pixel{
                 point:point,
                 color,
-                translucent:false
+                translucent:false,
+                blend:BlendMode::Normal,
             }
         }
     }
+    ///Same pixel mixed into the display with blend
+    pub fn with_blend(mut self, blend:BlendMode) -> Self{
+        self.blend = blend;
+        self
+    }
+    ///The single cell of the display grid the pixel lands on
+    pub fn rect(&self) -> Rect{
+        Rect::at(self.point, Size::is(1, 1))
+    }
 }
 
 pub trait Renderable{
     fn render(&self, point:Point);
 }
-///held by engine and borrowed mutably during rendering renderhook owns the instance of pixels created at the initilaization of the program
-pub struct RenderHook{
+///held by engine and borrowed mutably during rendering renderhook owns the render target created at the initilaization of the program.
+///defaults to the pixels instance of the window, headless runs use a cpu framebuffer instead
+pub struct RenderHook<T:RenderTarget = Pixels>{
     pub render_size:Size,
-    pub pixels:Pixels,
+    pub target:T,
 }
 
-impl RenderHook{
+impl<T:RenderTarget> RenderHook<T>{
     ///Public constructor for RenderHook
-    pub fn create_render_hook(render_size:Size, pixels:Pixels)-> Self{
+    pub fn create_render_hook(render_size:Size, target:T)-> Self{
         RenderHook{
             render_size,
-            pixels,
+            target,
         }
         
     }
-    ///Changes size of pixels buffer and cameras internal resolution it uses for calculations
+    ///Changes size of the targets backbuffer and cameras internal resolution it uses for calculations
     pub fn change_size(&mut self, size:Size){
         self.render_size = size;
-        self.pixels.resize_buffer(size.width as u32, size.height as u32).unwrap();
+        self.target.resize(size);
     }
 
-    ///Draws pixel Draw command to display buffer
-    pub fn buffer_pixels(&mut self,display_buffer:&mut [u8], display_size:Size,pixel_buffer: &mut SmallVec<[Pixel;1024]>){
-        //drains pixel buffer
-        for pixel in pixel_buffer.drain(..){
-            //clamps x and y to the display size
-            let x = pixel.point.x.floor().clamp(0.0,display_size.width as f32) as usize;
-            let y = pixel.point.y.floor().clamp(0.0,display_size.height as f32) as usize;
+    ///Draws pixel Draw commands to a buffer covering area of the display, pixels outside area are skipped
+    pub fn buffer_pixels(&mut self, buffer:&mut [u8], area:&Rect, pixels:&[Pixel]){
+        let width = area.width() as usize;
+        //draws every pixel in the batch
+        for pixel in pixels{
+            let rect = pixel.rect();
+            if !area.intersects(&rect){continue;}
             //calculates the index for each pixel
-            let index = x*BPP + y*display_size.width*BPP;
+            let index = ((rect.x0 - area.x0) as usize + (rect.y0 - area.y0) as usize*width)*BPP;
             if pixel.translucent{
-                composite(&mut display_buffer[index..=index+3], &pixel.color);
+                composite(&mut buffer[index..=index+3], &pixel.color);
             }else{
                 //if they arent copy them directly
-                display_buffer[index..index+BPP].copy_from_slice(&pixel.color);
+                buffer[index..index+BPP].copy_from_slice(&pixel.color);
             }
         }
     }
-    ///Draws object to display buffer
-    pub fn buffer_object(&mut self, object:&Object,display_buffer:&mut [u8], render_size:&Size){
-        
-        //sets point to  x and y and converts to usize, sets width to sprites width
-        //clamps to 0.0 to avoid any underflow cases in the case of negative points
-        //upper bounds check unnecessary, in the final render call frame vector is sliced
-        //to exact size before being copied to pixels backbuffer.
-        let x = object.point.x.floor() as usize;
-        let y = object.point.y.floor() as usize;
-        //calculates the index to draw the sprite at in the screen vector
-        let y_bytes = render_size.width*BPP;
-        let index: usize= x*BPP + y*render_size.width*BPP;
+    ///Draws the part of object inside clip to the display buffer. the object can sit partly or fully off the display, anything outside is clipped
+    pub fn buffer_object(&mut self, object:&Object,display_buffer:&mut [u8], render_size:&Size, clip:&Rect){
+        let origin = object.rect();
+        let clip = clip.intersect(&Rect::of_size(*render_size));
+        if clip.is_empty(){return;}
+        //affine objects dont line up with the display grid at all so every pixel gets sampled
+        if let Some(affine) = object.affine{
+            self.buffer_affine(object, &affine, display_buffer, render_size, &clip);
+            return;
+        }
+        //flipped, rotated or scaled sprites cant be copied a line at a time
+        if !object.transform.is_identity(){
+            self.buffer_transformed(object, display_buffer, render_size, &clip);
+            return;
+        }
+        //indexed sprites look every pixel up in the palette and blended ones need every pixel mixed on its own
+        if object.sprite.is_indexed() || !object.blend.is_normal(){
+            self.buffer_per_pixel(object, display_buffer, render_size, &clip);
+            return;
+        }
         //FIRST PASS - SOLID BLOCKS
         //reads out all the ranges of static pixels in the sprites static ranges section and draws them on the screen
-        for range in object.sprite.solid_ranges{
-            //finds the position in the screen buffer to place the slice of the sprite based of parameters
-            display_buffer[index + y_bytes*range.line + range.x_begin..(index + y_bytes*range.line + range.x_end)]
+        for range in object.sprite.solid_ranges.iter(){
+            let Some((index, src_index, length)) = clip_range(range, &origin, &clip, render_size) else{continue;};
             //copys the corresponding slice of the sprite data.
-            .copy_from_slice(&object.sprite.data[range.src_index..range.src_end_index]);
+            display_buffer[index..index + length].copy_from_slice(&object.sprite.data[src_index..src_index + length]);
         }
         //SECOND PASS - TRANSLUCENT BLOCKS
-        for range in object.sprite.translucent_ranges{
-            let source = &object.sprite.data[range.src_index..range.src_end_index];
-            //finds the position in the screen buffer to place the slice of th sprite based of parameters
-            let destination = &mut display_buffer[index + y_bytes*range.line + range.x_begin..(index + y_bytes*range.line + range.x_end)];
+        for range in object.sprite.translucent_ranges.iter(){
+            let Some((index, src_index, length)) = clip_range(range, &origin, &clip, render_size) else{continue;};
+            let source = &object.sprite.data[src_index..src_index + length];
+            let destination = &mut display_buffer[index..index + length];
             //iterates through the pixel array and the destination and composites the source onto the destination
             for (dst, src) in destination.chunks_exact_mut(BPP).zip(source.chunks_exact(BPP)){
                 composite(dst, src);
             }
         }
     }
-    
+    //draws an object a range at a time like the straight path but one pixel at a time inside each range, resolving indexed colors
+    //through the objects palette and mixing through its blend. a swapped palette or an opacity can change any pixels alpha,
+    //so every pixel is checked instead of trusting which pass it is in
+    fn buffer_per_pixel(&mut self, object:&Object, display_buffer:&mut [u8], render_size:&Size, clip:&Rect){
+        let origin = object.rect();
+        let palette = object.palette.as_ref();
+        for range in object.sprite.solid_ranges.iter().chain(object.sprite.translucent_ranges.iter()){
+            let Some((index, src_index, length)) = clip_range(range, &origin, clip, render_size) else{continue;};
+            //clip_range moves src_index along a whole pixel of rgba for every pixel clipped off the left
+            let first = range.x_begin/BPP + (src_index - range.src_index)/BPP;
+            for (offset, dst) in display_buffer[index..index + length].chunks_exact_mut(BPP).enumerate(){
+                put_blended(dst, object.sprite.color_in(first + offset, range.line, palette), &object.blend);
+            }
+        }
+    }
+    //draws an object through its transform one sprite pixel at a time, every pixel becomes a scale by scale block
+    fn buffer_transformed(&mut self, object:&Object, display_buffer:&mut [u8], render_size:&Size, clip:&Rect){
+        let origin = object.rect();
+        let transform = object.transform;
+        let scale = transform.scale() as i32;
+        let sprite_size = object.sprite.size;
+        //same two passes as the straight path so solid pixels never cover translucent ones drawn in the same object
+        for range in object.sprite.solid_ranges.iter().chain(object.sprite.translucent_ranges.iter()){
+            for sprite_x in range.x_begin/BPP..range.x_end/BPP{
+                let src = object.sprite.color_in(sprite_x, range.line, object.palette.as_ref());
+                let (x, y) = transform.map(sprite_x, range.line, sprite_size);
+                let x0 = origin.x0 + x as i32*scale;
+                let y0 = origin.y0 + y as i32*scale;
+                let block = Rect::new(x0, y0, x0 + scale, y0 + scale).intersect(clip);
+                for dst_y in block.y0..block.y1{
+                    let start = (dst_y as usize*render_size.width + block.x0 as usize)*BPP;
+                    for dst in display_buffer[start..start + block.width() as usize*BPP].chunks_exact_mut(BPP){
+                        put_blended(dst, src, &object.blend);
+                    }
+                }
+            }
+        }
+    }
+    //draws an object by walking every display pixel it covers and sampling the sprite at where that pixel maps back to
+    fn buffer_affine(&mut self, object:&Object, affine:&Affine, display_buffer:&mut [u8], render_size:&Size, clip:&Rect){
+        let size = object.size();
+        let (center_x, center_y) = (object.point.x + size.width as f32/2.0, object.point.y + size.height as f32/2.0);
+        for y in clip.y0..clip.y1{
+            for x in clip.x0..clip.x1{
+                //samples at the center of the display pixel
+                let Some((offset_x, offset_y)) = affine.inverse(x as f32 + 0.5 - center_x, y as f32 + 0.5 - center_y) else{return;};
+                let (u, v) = object.transform.unmap(offset_x + size.width as f32/2.0, offset_y + size.height as f32/2.0, object.sprite.size);
+                let color = match affine.sampling{
+                    Sampling::Nearest => sample_nearest(&object.sprite, object.palette.as_ref(), u, v),
+                    Sampling::Bilinear => sample_bilinear(&object.sprite, object.palette.as_ref(), u, v),
+                };
+                let index = (y as usize*render_size.width + x as usize)*BPP;
+                put_blended(&mut display_buffer[index..index + BPP], &color, &object.blend);
+            }
+        }
+    }
+    ///Draws a run length encoded sprite with its top left corner at point straight from its runs, clipped the same way objects are.
+    ///only covers the untransformed case with a normal blend, anything else has to be decoded into an object first
+    pub fn buffer_rle(&mut self, sprite:&RleSprite, point:Point, display_buffer:&mut [u8], render_size:&Size, clip:&Rect){
+        let origin = Rect::at(point, sprite.size);
+        let clip = clip.intersect(&Rect::of_size(*render_size));
+        if clip.is_empty(){return;}
+        //solid runs first, same order as objects
+        for (ranges, translucent) in [(&*sprite.solid_ranges, false), (&*sprite.translucent_ranges, true)]{
+            for range in ranges{
+                let y = origin.y0 + range.line as i32;
+                if y < clip.y0 || y >= clip.y1{continue;}
+                let mut x = origin.x0 + (range.x_begin/BPP) as i32;
+                for (count, color) in sprite.runs(range){
+                    let (x0, x1) = (x.max(clip.x0), (x + count as i32).min(clip.x1));
+                    x += count as i32;
+                    if x0 >= x1{continue;}
+                    let start = (y as usize*render_size.width + x0 as usize)*BPP;
+                    for dst in display_buffer[start..start + (x1 - x0) as usize*BPP].chunks_exact_mut(BPP){
+                        if translucent{
+                            composite(dst, color);
+                        }else{
+                            dst.copy_from_slice(color);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    ///Composites the part of a pixel canvas inside clip onto the display buffer with blend, canvas covers bounds of the display
+    pub fn buffer_canvas(&mut self, canvas:&[u8], bounds:&Rect, display_buffer:&mut [u8], display_size:Size, clip:&Rect, blend:BlendMode){
+        let clip = clip.intersect(bounds);
+        if clip.is_empty(){return;}
+        let blend = Blend::NORMAL.with_mode(blend);
+        let length = clip.width() as usize*BPP;
+        for y in clip.y0..clip.y1{
+            let start = (y as usize*display_size.width + clip.x0 as usize)*BPP;
+            let src = ((y - bounds.y0) as usize*bounds.width() as usize + (clip.x0 - bounds.x0) as usize)*BPP;
+            for (dst, src) in display_buffer[start..start + length].chunks_exact_mut(BPP).zip(canvas[src..src + length].chunks_exact(BPP)){
+                //empty canvas pixels are the common case, put skips them and copies solid ones outright
+                put_blended(dst, src, &blend);
+            }
+        }
+    }
+
     pub fn capture(&mut self, display_buffer:&[u8], display_size:&Size, camera:&Camera){
         //cameras point to  x and y and converts to usize, sets width cameras width
         //clamps to 0.0 to avoid any underflow cases in the case of negative points
@@ -143,17 +266,62 @@ impl RenderHook{
         let lines = display_buffer.chunks_exact(width).skip(y).take(camera_height);
         for (row,line) in lines.enumerate(){
             //copys the data in the line to the indexed point in the framebuffer
-            self.pixels.frame_mut()[row*camera_width..row*camera_width + camera_width].copy_from_slice(&line[index..index + camera_width]);
+            self.target.frame_mut()[row*camera_width..row*camera_width + camera_width].copy_from_slice(&line[index..index + camera_width]);
         }
     }
 
-    ///copys buffer directly to pixel buffer, used for copying entire display buffer to pixels buffer
+    ///Like capture but only copies the parts of regions the camera can see
+    pub fn capture_regions(&mut self, display_buffer:&[u8], display_size:&Size, camera:&Camera, regions:&[Rect]){
+        let view = Rect::at(camera.point, camera.camera_size);
+        let camera_width = camera.camera_size.width;
+        let frame = self.target.frame_mut();
+        for region in regions{
+            let visible = region.intersect(&view);
+            if visible.is_empty(){continue;}
+            let length = visible.width() as usize*BPP;
+            for y in visible.y0..visible.y1{
+                let src = (y as usize*display_size.width + visible.x0 as usize)*BPP;
+                let dst = ((y - view.y0) as usize*camera_width + (visible.x0 - view.x0) as usize)*BPP;
+                frame[dst..dst + length].copy_from_slice(&display_buffer[src..src + length]);
+            }
+        }
+    }
+    ///Like copy_buffer but only copies regions, the render size has to match the display size
+    pub fn copy_regions(&mut self, frame:&[u8], display_size:&Size, regions:&[Rect]){
+        let display_rect = Rect::of_size(*display_size);
+        let target = self.target.frame_mut();
+        for region in regions{
+            let region = region.intersect(&display_rect);
+            if region.is_empty(){continue;}
+            let length = region.width() as usize*BPP;
+            for y in region.y0..region.y1{
+                let start = (y as usize*display_size.width + region.x0 as usize)*BPP;
+                target[start..start + length].copy_from_slice(&frame[start..start + length]);
+            }
+        }
+    }
+
+    ///copys buffer directly to the targets backbuffer, used for copying entire display buffer to pixels buffer
     pub fn copy_buffer(&mut self, frame:&[u8]){
-        //copies data in frame buffer into the pixels backbuffer
-        self.pixels.frame_mut().copy_from_slice(&frame[..self.render_size.width * self.render_size.height * BPP]);
+        //copies data in frame buffer into the targets backbuffer
+        self.target.frame_mut().copy_from_slice(&frame[..self.render_size.width * self.render_size.height * BPP]);
     }
 }
 
+//clips one line of a sprite placed at origin against clip.
+//returns the index in the display buffer, the index in the sprite data and the byte length of whatever is left
+fn clip_range(range:&PixelRange, origin:&Rect, clip:&Rect, render_size:&Size) -> Option<(usize, usize, usize)>{
+    let y = origin.y0 + range.line as i32;
+    if y < clip.y0 || y >= clip.y1{return None;}
+    let x0 = origin.x0 + (range.x_begin/BPP) as i32;
+    let x1 = origin.x0 + (range.x_end/BPP) as i32;
+    let (clipped_x0, clipped_x1) = (x0.max(clip.x0), x1.min(clip.x1));
+    if clipped_x0 >= clipped_x1{return None;}
+    let index = (y as usize*render_size.width + clipped_x0 as usize)*BPP;
+    let src_index = range.src_index + (clipped_x0 - x0) as usize*BPP;
+    Some((index, src_index, (clipped_x1 - clipped_x0) as usize*BPP))
+}
+
 fn composite(dst:&mut [u8],src:&[u8]){
     let composite_alpha = 255 - src[3] as u16;
     //r
@@ -166,6 +334,83 @@ fn composite(dst:&mut [u8],src:&[u8]){
     dst[3] = src[3] + (fast_divide(dst[3] as u16*composite_alpha)) as u8;
 }
 
+//draws one premultiplied pixel without knowing its alpha up front, transparent ones are skipped and solid ones copied
+fn put(dst:&mut [u8], src:&[u8]){
+    match src[3]{
+        0 =>{}
+        255 => dst.copy_from_slice(src),
+        _ => composite(dst, src),
+    }
+}
+
+//draws one premultiplied pixel through blend. tint and opacity scale the source first, then the mode mixes it in
+fn put_blended(dst:&mut [u8], src:&[u8], blend:&Blend){
+    if blend.is_normal(){
+        put(dst, src);
+        return;
+    }
+    let mut color = [src[0], src[1], src[2], src[3]];
+    for (channel, tint) in color.iter_mut().zip(blend.tint){
+        *channel = fast_divide(*channel as u16*tint as u16);
+    }
+    //opacity scales alpha along with the color so it stays premultiplied
+    for channel in color.iter_mut(){
+        *channel = fast_divide(*channel as u16*blend.opacity as u16);
+    }
+    if color[3] == 0{return;}
+    mix(dst, &color, blend.mode);
+}
+
+//the blend modes on premultiplied colors, normal is the same as composite. color channels follow the mode, alpha builds up the same as source over
+fn mix(dst:&mut [u8], src:&[u8;4], mode:BlendMode){
+    let (src_alpha, dst_alpha) = (src[3] as u16, dst[3] as u16);
+    for channel in 0..3{
+        let (s, d) = (src[channel] as u16, dst[channel] as u16);
+        dst[channel] = match mode{
+            BlendMode::Normal => s as u8 + fast_divide(d*(255 - src_alpha)),
+            BlendMode::Add => (s + d).min(255) as u8,
+            BlendMode::Subtract => d.saturating_sub(s) as u8,
+            //source times display where both are, plus each one where the other isnt
+            BlendMode::Multiply => (fast_divide(s*d) as u16 + fast_divide(s*(255 - dst_alpha)) as u16 + fast_divide(d*(255 - src_alpha)) as u16).min(255) as u8,
+            BlendMode::Screen => (s + d - fast_divide(s*d) as u16) as u8,
+        };
+    }
+    dst[3] = src[3] + fast_divide(dst_alpha*(255 - src_alpha));
+}
+
+//pixel of sprite at x, y, pixels outside the sprite are transparent. indexed sprites are looked up in palette if there is one
+fn texel(sprite:&Sprite, palette:Option<&Palette>, x:i32, y:i32) -> [u8;4]{
+    if x < 0 || y < 0 || x >= sprite.size.width as i32 || y >= sprite.size.height as i32{return [0;4];}
+    let pixel = sprite.color_in(x as usize, y as usize, palette);
+    [pixel[0], pixel[1], pixel[2], pixel[3]]
+}
+
+fn sample_nearest(sprite:&Sprite, palette:Option<&Palette>, u:f32, v:f32) -> [u8;4]{
+    texel(sprite, palette, u.floor() as i32, v.floor() as i32)
+}
+
+//blends the four pixels around u, v. sprite data is premultiplied so the blend is too, and since every channel is weighted
+//the same the color never ends up brighter than its alpha, which composite relies on
+fn sample_bilinear(sprite:&Sprite, palette:Option<&Palette>, u:f32, v:f32) -> [u8;4]{
+    //pixel centers sit on the halves
+    let (u, v) = (u - 0.5, v - 0.5);
+    let (x, y) = (u.floor(), v.floor());
+    let (fx, fy) = (u - x, v - y);
+    let (x, y) = (x as i32, y as i32);
+    let corners = [
+        (texel(sprite, palette, x, y), (1.0 - fx)*(1.0 - fy)),
+        (texel(sprite, palette, x + 1, y), fx*(1.0 - fy)),
+        (texel(sprite, palette, x, y + 1), (1.0 - fx)*fy),
+        (texel(sprite, palette, x + 1, y + 1), fx*fy),
+    ];
+    let mut color = [0;4];
+    for (channel, value) in color.iter_mut().enumerate(){
+        let blended:f32 = corners.iter().map(|(texel, weight)|texel[channel] as f32*weight).sum();
+        *value = blended.round().clamp(0.0, 255.0) as u8;
+    }
+    color
+}
+
 //fast divide helper function for alpha compositing, doesnt actually divide anything but gets the exact same number as true division by 255 would
 fn fast_divide(value:u16) -> u8{
     (((value + 128) + ((value + 128) >> 8)) >> 8) as u8
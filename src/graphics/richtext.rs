@@ -0,0 +1,511 @@
+// ===============================
+//           RICHTEXT.RS
+// -------------------------------
+//  text with markup in it, like [color=#ff0]gold[/color] or
+//  [sprite=apple]. markup is parsed into styled runs, the runs
+//  are laid out into lines of glyphs and inline sprites, and
+//  those are drawn with every glyph moved by its runs motion
+//  for the tick being drawn
+// -------------------------------
+
+use std::{
+    collections::HashMap,
+    error::Error,
+    f32::consts::TAU,
+    fmt::{self, Display, Formatter},
+    sync::Arc,
+};
+
+use crate::{
+    graphics::{
+        sprites::Sprite,
+        texthandler::SizedFont,
+        textlayout::{HorizontalAlign, TextBox, VerticalAlign},
+    },
+    shared::{Point, Rect, Size, BPP},
+};
+
+///font [b] switches to
+pub const BOLD:&str = "bold";
+///font [i] switches to
+pub const ITALIC:&str = "italic";
+//ticks a wave takes to pass by and glyphs from one crest to the next
+const WAVE_PERIOD:f32 = 60.0;
+const WAVE_LENGTH:f32 = 8.0;
+//pixels motions move glyphs by when their tag has no value
+const WAVE_AMPLITUDE:f32 = 2.0;
+const SHAKE_AMPLITUDE:f32 = 1.0;
+///most pixels a motion moves glyphs by, markup asking for more is turned away and motions made in code are held to it
+pub const MAX_AMOUNT:f32 = 64.0;
+
+///Everything wrong markup can have in it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MarkupError{
+    ///a [ at this byte with no ] after it
+    UnterminatedTag(usize),
+    ///a tag that isnt any of the ones markup knows
+    UnknownTag(String),
+    ///a tag missing its value, given one it doesnt take, or given one it cant read
+    BadValue{tag:String, value:String},
+    ///a closing tag that doesnt match the tag open innermost
+    UnexpectedClose(String),
+    ///a tag still open at the end of the text
+    Unclosed(String),
+    ///a font switched to that the rich text wasnt given
+    UnknownFont(String),
+    ///a sprite placed that the rich text wasnt given
+    UnknownSprite(String),
+}
+
+impl Display for MarkupError{
+    fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result{
+        match self{
+            MarkupError::UnterminatedTag(at) => write!(f, "Tag at byte {at} is never closed with ]"),
+            MarkupError::UnknownTag(tag) => write!(f, "Unknown tag [{tag}]"),
+            MarkupError::BadValue{tag, value} => write!(f, "Tag [{tag}] cant take the value {value:?}"),
+            MarkupError::UnexpectedClose(tag) => write!(f, "[/{tag}] doesnt close the tag open before it"),
+            MarkupError::Unclosed(tag) => write!(f, "[{tag}] is never closed"),
+            MarkupError::UnknownFont(font) => write!(f, "No font named {font:?}"),
+            MarkupError::UnknownSprite(sprite) => write!(f, "No sprite named {sprite:?}"),
+        }
+    }
+}
+
+impl Error for MarkupError{}
+
+///Motion is how the glyphs of a run move from one tick to the next
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Motion{
+    ///glyphs bob up and down this many pixels in a wave running along the text
+    Wave(f32),
+    ///glyphs jump around at random up to this many pixels every tick
+    Shake(f32),
+}
+
+impl Motion{
+    ///Pixels the glyph index glyphs into the text is moved by at tick
+    pub fn offset(&self, index:usize, tick:u32) -> (i32, i32){
+        match *self{
+            Motion::Wave(amplitude) =>{
+                let phase = tick as f32/WAVE_PERIOD + index as f32/WAVE_LENGTH;
+                let reach = self.reach();
+                (0, ((amplitude*(phase*TAU).sin()).round() as i32).clamp(-reach, reach))
+            }
+            Motion::Shake(_) =>{
+                //cheap integer hash, every glyph jumps on its own but the same tick always gives the same offsets
+                let mut hash = (index as u32).wrapping_mul(0x9E3779B1) ^ tick.wrapping_mul(0x85EBCA77);
+                hash ^= hash >> 15;
+                hash = hash.wrapping_mul(0x2C1B3C6D);
+                hash ^= hash >> 12;
+                let reach = self.reach();
+                let span = (reach as u32).saturating_mul(2).saturating_add(1);
+                (((hash%span) as i32).saturating_sub(reach), (((hash >> 16)%span) as i32).saturating_sub(reach))
+            }
+        }
+    }
+    ///Most pixels the motion ever moves a glyph by in any direction, never more than MAX_AMOUNT
+    pub fn reach(&self) -> i32{
+        match *self{
+            Motion::Wave(amplitude) | Motion::Shake(amplitude) => amplitude.abs().round().min(MAX_AMOUNT) as i32,
+        }
+    }
+}
+
+///RunStyle is how the text of a run is drawn
+#[derive(Clone, Debug, PartialEq)]
+pub struct RunStyle{
+    ///straight alpha
+    pub color:[u8;4],
+    ///name of the font the run is drawn with, none is the regular font
+    pub font:Option<String>,
+    pub motion:Option<Motion>,
+}
+
+///Run is a stretch of markup with one style, or a sprite placed inline
+#[derive(Clone, Debug, PartialEq)]
+pub enum Run{
+    Text{text:String, style:RunStyle},
+    ///named sprite standing on the baseline like a glyph would
+    Sprite(String),
+}
+
+///Parses markup into runs, text outside every tag gets color and the regular font. tags nest and close innermost first:
+///[color=#rgb] [color=#rrggbbaa] and the like, [b] [i] [font=name], [wave] [wave=pixels] [shake] [shake=pixels], all closed with
+///[/name]. [sprite=name] places a sprite and isnt closed. [[ is a [ that doesnt start a tag
+pub fn parse_markup(markup:&str, color:[u8;4]) -> Result<Vec<Run>, MarkupError>{
+    let mut runs = Vec::new();
+    let mut style = RunStyle{color, font:None, motion:None};
+    //open tags along with the style from before they were opened
+    let mut open:Vec<(&str, RunStyle)> = Vec::new();
+    let mut text = String::new();
+    let mut chars = markup.char_indices().peekable();
+    while let Some((index, char)) = chars.next(){
+        if char != '['{
+            text.push(char);
+            continue;
+        }
+        if chars.next_if(|&(_, next)|next == '[').is_some(){
+            text.push('[');
+            continue;
+        }
+        let end = index + markup[index..].find(']').ok_or(MarkupError::UnterminatedTag(index))?;
+        while chars.next_if(|&(at, _)|at <= end).is_some(){}
+        //text before the tag keeps the style it was written in
+        if !text.is_empty(){
+            runs.push(Run::Text{text:std::mem::take(&mut text), style:style.clone()});
+        }
+        let tag = &markup[index + 1..end];
+        if let Some(name) = tag.strip_prefix('/'){
+            match open.pop(){
+                Some((opened, previous)) if opened == name => style = previous,
+                _ => return Err(MarkupError::UnexpectedClose(name.to_string())),
+            }
+            continue;
+        }
+        let (name, value) = match tag.split_once('='){
+            Some((name, value)) => (name, Some(value)),
+            None => (tag, None),
+        };
+        let bad_value = ||MarkupError::BadValue{tag:name.to_string(), value:value.unwrap_or_default().to_string()};
+        let previous = style.clone();
+        match (name, value){
+            ("sprite", Some(sprite)) if !sprite.is_empty() =>{
+                runs.push(Run::Sprite(sprite.to_string()));
+                continue;
+            }
+            ("color", Some(value)) => style.color = parse_color(value).ok_or_else(bad_value)?,
+            ("b", None) => style.font = Some(BOLD.to_string()),
+            ("i", None) => style.font = Some(ITALIC.to_string()),
+            ("font", Some(font)) if !font.is_empty() => style.font = Some(font.to_string()),
+            ("wave", value) => style.motion = Some(Motion::Wave(parse_amount(value, WAVE_AMPLITUDE).ok_or_else(bad_value)?)),
+            ("shake", value) => style.motion = Some(Motion::Shake(parse_amount(value, SHAKE_AMPLITUDE).ok_or_else(bad_value)?)),
+            ("sprite" | "color" | "b" | "i" | "font", _) => return Err(bad_value()),
+            _ => return Err(MarkupError::UnknownTag(name.to_string())),
+        }
+        open.push((name, previous));
+    }
+    if let Some((opened, _)) = open.pop(){
+        return Err(MarkupError::Unclosed(opened.to_string()));
+    }
+    if !text.is_empty(){
+        runs.push(Run::Text{text, style});
+    }
+    Ok(runs)
+}
+
+//#rgb, #rgba, #rrggbb or #rrggbbaa as straight rgba, colors without alpha are opaque
+fn parse_color(value:&str) -> Option<[u8;4]>{
+    let hex = value.strip_prefix('#')?;
+    if !hex.chars().all(|char|char.is_ascii_hexdigit()){
+        return None;
+    }
+    let digits:Vec<u8> = hex.chars().map(|char|char.to_digit(16).unwrap() as u8).collect();
+    let mut color = [255u8;4];
+    match digits.len(){
+        3 | 4 => for (channel, digit) in color.iter_mut().zip(digits){
+            *channel = digit*17;
+        },
+        6 | 8 => for (channel, pair) in color.iter_mut().zip(digits.chunks_exact(2)){
+            *channel = pair[0]*16 + pair[1];
+        },
+        _ => return None,
+    }
+    Some(color)
+}
+
+//pixels a motion tag moves glyphs by, default if the tag has no value. none past MAX_AMOUNT either way
+fn parse_amount(value:Option<&str>, default:f32) -> Option<f32>{
+    match value{
+        None => Some(default),
+        Some(value) => value.parse().ok().filter(|amount:&f32|amount.is_finite() && amount.abs() <= MAX_AMOUNT),
+    }
+}
+
+///PlacedGlyph is one glyph of a rich layout and where it goes
+#[derive(Clone, Debug)]
+pub struct PlacedGlyph{
+    pub font:Arc<SizedFont>,
+    pub char:char,
+    ///pen position on the baseline, relative to the top left of the box
+    pub x:i32,
+    pub baseline:i32,
+    ///straight alpha
+    pub color:[u8;4],
+    pub motion:Option<Motion>,
+    ///glyphs before this one in the text, lets motions tell glyphs apart
+    pub index:usize,
+}
+
+///PlacedSprite is an inline sprite of a rich layout, top left relative to the top left of the box
+#[derive(Clone, Debug)]
+pub struct PlacedSprite{
+    pub sprite:Sprite,
+    pub x:i32,
+    pub y:i32,
+}
+
+///RichLayout is runs broken into lines and placed inside a box
+#[derive(Clone, Debug)]
+pub struct RichLayout{
+    pub glyphs:Vec<PlacedGlyph>,
+    pub sprites:Vec<PlacedSprite>,
+    ///everything the glyphs and sprites cover when they arent moving, relative to the top left of the box
+    pub bounds:Rect,
+    ///true if lines were dropped to fit the box
+    pub truncated:bool,
+}
+
+//one thing laid out along a line, along with how far it moves the pen
+struct Piece{
+    kind:PieceKind,
+    advance:f32,
+}
+
+enum PieceKind{
+    Glyph{font:Arc<SizedFont>, char:char, color:[u8;4], motion:Option<Motion>, index:usize},
+    Sprite(Sprite),
+    Space,
+    Newline,
+}
+
+///RichText is the fonts and sprites markup can refer to by name
+#[derive(Clone, Debug)]
+pub struct RichText{
+    ///font of text outside any font tag
+    pub regular:Arc<SizedFont>,
+    pub fonts:HashMap<String, Arc<SizedFont>>,
+    pub sprites:HashMap<String, Sprite>,
+}
+
+impl RichText{
+    pub fn new(regular:Arc<SizedFont>) -> Self{
+        RichText{regular, fonts:HashMap::new(), sprites:HashMap::new()}
+    }
+    ///Font [font=name] switches to, use BOLD and ITALIC as the name for the fonts of [b] and [i]
+    pub fn with_font(mut self, name:impl Into<String>, font:Arc<SizedFont>) -> Self{
+        self.fonts.insert(name.into(), font);
+        self
+    }
+    ///Sprite [sprite=name] places
+    pub fn with_sprite(mut self, name:impl Into<String>, sprite:Sprite) -> Self{
+        self.sprites.insert(name.into(), sprite);
+        self
+    }
+    fn font(&self, name:&Option<String>) -> Result<&Arc<SizedFont>, MarkupError>{
+        match name{
+            None => Ok(&self.regular),
+            Some(name) => self.fonts.get(name).ok_or_else(||MarkupError::UnknownFont(name.clone())),
+        }
+    }
+    ///Breaks runs into lines that fit text_box and aligns them inside it. lines break at spaces, words wider than the box run past it.
+    ///every line is tall enough for the tallest font or sprite on it and sprites sit on the baseline
+    pub fn layout(&self, runs:&[Run], text_box:&TextBox) -> Result<RichLayout, MarkupError>{
+        let pieces = self.pieces(runs)?;
+        let lines = break_lines(&pieces, text_box);
+        //places every line under the one before, leaving at least the line spacing of the regular font between them
+        let spacing = (self.regular.line_spacing as f32*text_box.line_spacing).round() as i32;
+        let mut placed = Vec::with_capacity(lines.len());
+        let mut y = 0;
+        let mut truncated = false;
+        for (index, line) in lines.iter().enumerate(){
+            let (mut ascent, mut descent) = (0, 0);
+            let mut pen = 0.0f32;
+            for &piece in line.iter(){
+                match &pieces[piece].kind{
+                    PieceKind::Glyph{font, ..} => (ascent, descent) = (ascent.max(font.ascent), descent.max(font.descent)),
+                    PieceKind::Sprite(sprite) => ascent = ascent.max(sprite.size.height as i32),
+                    PieceKind::Space | PieceKind::Newline =>{}
+                }
+                pen += pieces[piece].advance;
+            }
+            if ascent == 0 && descent == 0{
+                (ascent, descent) = (self.regular.ascent, self.regular.descent);
+            }
+            if index > 0{
+                y += spacing.max(ascent + descent).max(0);
+            }
+            if text_box.height.is_some_and(|height|index > 0 && y + ascent + descent > height as i32){
+                truncated = true;
+                break;
+            }
+            placed.push((line, y, ascent, descent, pen.ceil() as i32));
+        }
+        let widest = placed.iter().map(|line|line.4).max().unwrap_or(0);
+        let box_width = text_box.width.map_or(widest, |width|width as i32);
+        let content_height = placed.last().map_or(0, |&(_, y, ascent, descent, _)|y + ascent + descent);
+        let top = match (text_box.vertical_align, text_box.height){
+            (VerticalAlign::Top, _) | (_, None) => 0,
+            (VerticalAlign::Middle, Some(height)) => (height as i32 - content_height)/2,
+            (VerticalAlign::Bottom, Some(height)) => height as i32 - content_height,
+        };
+        let mut layout = RichLayout{glyphs:Vec::new(), sprites:Vec::new(), bounds:Rect::default(), truncated};
+        for (line, y, ascent, descent, width) in placed{
+            let left = match text_box.align{
+                HorizontalAlign::Left => 0,
+                HorizontalAlign::Center => (box_width - width)/2,
+                HorizontalAlign::Right => box_width - width,
+            };
+            let (line_top, baseline) = (top + y, top + y + ascent);
+            layout.bounds = layout.bounds.union(&Rect::new(left, line_top, left + width, baseline + descent));
+            let mut pen = 0.0f32;
+            for &piece in line.iter(){
+                let x = left + pen.round() as i32;
+                match &pieces[piece].kind{
+                    PieceKind::Glyph{font, char, color, motion, index} =>{
+                        let glyph = font.glyph(*char).expect("pieces only hold glyphs their font has");
+                        let covered = Rect::new(x + glyph.xoff, baseline + glyph.yoff, x + glyph.xoff + glyph.size.width as i32, baseline + glyph.yoff + glyph.size.height as i32);
+                        layout.bounds = layout.bounds.union(&covered);
+                        layout.glyphs.push(PlacedGlyph{font:font.clone(), char:*char, x, baseline, color:*color, motion:*motion, index:*index});
+                    }
+                    PieceKind::Sprite(sprite) =>{
+                        let y = baseline - sprite.size.height as i32;
+                        layout.bounds = layout.bounds.union(&Rect::new(x, y, x + sprite.size.width as i32, baseline));
+                        layout.sprites.push(PlacedSprite{sprite:sprite.clone(), x, y});
+                    }
+                    PieceKind::Space | PieceKind::Newline =>{}
+                }
+                pen += pieces[piece].advance;
+            }
+        }
+        Ok(layout)
+    }
+    ///Draws layout as it looks at tick into one sprite. returns the sprite and where its top left sits relative to the top left of the box
+    pub fn render(&self, layout:&RichLayout, tick:u32) -> (Sprite, Point){
+        //room for glyphs to move into without being cut off
+        let reach = layout.glyphs.iter().filter_map(|glyph|glyph.motion).map(|motion|motion.reach()).max().unwrap_or(0);
+        let bounds = layout.bounds;
+        let area = Rect::new(bounds.x0.saturating_sub(reach), bounds.y0.saturating_sub(reach), bounds.x1.saturating_add(reach), bounds.y1.saturating_add(reach));
+        let size = Size::is(area.width().max(0) as usize, area.height().max(0) as usize);
+        let mut data = vec![0u8;size.pixels()*BPP];
+        let mut draw = |x:i32, y:i32, color:&[u8]|{
+            let (x, y) = (x - area.x0, y - area.y0);
+            if x < 0 || y < 0 || x >= size.width as i32 || y >= size.height as i32{return;}
+            let index = (y as usize*size.width + x as usize)*BPP;
+            over(&mut data[index..index + BPP], color);
+        };
+        for placed in layout.sprites.iter(){
+            let sprite = &placed.sprite;
+            for y in 0..sprite.size.height{
+                for x in 0..sprite.size.width{
+                    draw(placed.x + x as i32, placed.y + y as i32, sprite.color_in(x, y, None));
+                }
+            }
+        }
+        for placed in layout.glyphs.iter(){
+            let Some(glyph) = placed.font.glyph(placed.char) else{continue};
+            let (dx, dy) = placed.motion.map_or((0, 0), |motion|motion.offset(placed.index, tick));
+            let (x, y) = (placed.x + glyph.xoff + dx, placed.baseline + glyph.yoff + dy);
+            let width = glyph.size.width.max(1);
+            for (index, &covered) in placed.font.coverage(glyph).iter().enumerate(){
+                if covered == 0{continue;}
+                let alpha = mul(placed.color[3], covered);
+                let color = [mul(placed.color[0], alpha), mul(placed.color[1], alpha), mul(placed.color[2], alpha), alpha];
+                draw(x + (index%width) as i32, y + (index/width) as i32, &color);
+            }
+        }
+        (Sprite::from_rgba(data, size), Point::at(area.x0 as f32, area.y0 as f32))
+    }
+    ///Checks that every font and sprite runs name is one rich has
+    pub fn check(&self, runs:&[Run]) -> Result<(), MarkupError>{
+        self.pieces(runs).map(|_|())
+    }
+    ///Parses, lays out and draws markup in one go
+    pub fn render_markup(&self, markup:&str, color:[u8;4], text_box:&TextBox, tick:u32) -> Result<(Sprite, Point), MarkupError>{
+        let runs = parse_markup(markup, color)?;
+        Ok(self.render(&self.layout(&runs, text_box)?, tick))
+    }
+
+    //turns runs into pieces with their advances, kerning between glyphs of the same font is added to the first of them
+    fn pieces(&self, runs:&[Run]) -> Result<Vec<Piece>, MarkupError>{
+        let mut pieces:Vec<Piece> = Vec::new();
+        let mut index = 0;
+        for run in runs{
+            let (text, style) = match run{
+                Run::Text{text, style} => (text, style),
+                Run::Sprite(name) =>{
+                    let sprite = self.sprites.get(name).ok_or_else(||MarkupError::UnknownSprite(name.clone()))?;
+                    pieces.push(Piece{advance:sprite.size.width as f32, kind:PieceKind::Sprite(sprite.clone())});
+                    continue;
+                }
+            };
+            let font = self.font(&style.font)?;
+            for char in text.chars(){
+                if char == '\n'{
+                    pieces.push(Piece{kind:PieceKind::Newline, advance:0.0});
+                    continue;
+                }
+                //chars missing from the font are skipped the same way a sized font skips them
+                let Some(glyph) = font.glyph(char) else{continue};
+                if char == ' '{
+                    pieces.push(Piece{kind:PieceKind::Space, advance:glyph.advance});
+                    continue;
+                }
+                if let Some(Piece{kind:PieceKind::Glyph{font:previous_font, char:previous, ..}, advance}) = pieces.last_mut()
+                    && Arc::ptr_eq(previous_font, font){
+                    *advance += font.kerning(*previous, char);
+                }
+                pieces.push(Piece{kind:PieceKind::Glyph{font:font.clone(), char, color:style.color, motion:style.motion, index}, advance:glyph.advance});
+                index += 1;
+            }
+        }
+        Ok(pieces)
+    }
+}
+
+//greedily fits the words of pieces onto lines, returning the pieces of every line. spaces a line is broken at are left out
+fn break_lines(pieces:&[Piece], text_box:&TextBox) -> Vec<Vec<usize>>{
+    let max_width = text_box.width.filter(|_|text_box.wrap).map(|width|width as f32);
+    let mut lines = Vec::new();
+    let mut line:Vec<usize> = Vec::new();
+    let mut pen = 0.0;
+    //spaces since the last word and the word being read, only added to the line once it is clear whether the word fits
+    let (mut spaces, mut spaces_width) = (Vec::new(), 0.0);
+    let (mut word, mut word_width) = (Vec::new(), 0.0);
+    for index in 0..=pieces.len(){
+        let piece = pieces.get(index);
+        if let Some(Piece{kind:PieceKind::Glyph{..} | PieceKind::Sprite(_), advance}) = piece{
+            word.push(index);
+            word_width += advance;
+            continue;
+        }
+        if !word.is_empty(){
+            if max_width.is_some_and(|max_width|!line.is_empty() && pen + spaces_width + word_width > max_width){
+                lines.push(std::mem::take(&mut line));
+                pen = 0.0;
+            }else{
+                line.append(&mut spaces);
+                pen += spaces_width;
+            }
+            line.append(&mut word);
+            pen += word_width;
+            (spaces_width, word_width) = (0.0, 0.0);
+            spaces.clear();
+        }
+        match piece{
+            Some(Piece{kind:PieceKind::Space, advance}) =>{
+                spaces.push(index);
+                spaces_width += advance;
+            }
+            Some(Piece{kind:PieceKind::Newline, ..}) | None =>{
+                lines.push(std::mem::take(&mut line));
+                spaces.clear();
+                (pen, spaces_width) = (0.0, 0.0);
+            }
+            _ =>{}
+        }
+    }
+    lines
+}
+
+//channel times alpha, both from 0 to 255
+fn mul(channel:u8, alpha:u8) -> u8{
+    ((channel as u16*alpha as u16 + 127)/255) as u8
+}
+
+//puts premultiplied source over premultiplied destination
+fn over(destination:&mut [u8], source:&[u8]){
+    let inverse = 255 - source[3];
+    for (dst, src) in destination.iter_mut().zip(source){
+        *dst = src.saturating_add(mul(*dst, inverse));
+    }
+}
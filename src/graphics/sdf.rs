@@ -0,0 +1,340 @@
+// ===============================
+//              SDF.RS
+// -------------------------------
+//  text drawn from signed distance fields. every glyph is
+//  rasterized once as a field at a base size and resampled to
+//  whatever size it is drawn at, and knowing how far every
+//  pixel is from the outline lets outlines, shadows and glows
+//  be drawn around text without another trip through stbtt
+// -------------------------------
+
+use std::{collections::HashMap, io::Error, sync::{Arc, Mutex}};
+
+use crate::{
+    graphics::{
+        bitmapfont::BitmapGlyph,
+        parse_ttf_bindings::{generate_sdf_glyph, LoadedFont},
+        sprites::Sprite,
+        texthandler::{rasterize_chars, sized_from_bitmaps, SizedFont},
+        textlayout::TextLayout,
+    },
+    shared::{Point, Rect, Size, BPP},
+};
+
+///value a field has right on the outline, higher is inside
+const ONEDGE:u8 = 128;
+///how many resampled sizes an sdf font keeps, animating text through sizes only resamples the ones it hasnt drawn lately
+const SIZED_FONTS:usize = 8;
+
+///Outline is a border of color drawn width pixels out from the edge of the glyphs
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Outline{
+    pub width:f32,
+    ///straight alpha
+    pub color:[u8;4],
+}
+
+///Shadow is a copy of the text, outline included, drawn under it offset pixels away and blurred over softness pixels
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Shadow{
+    pub offset:Point,
+    pub softness:f32,
+    ///straight alpha
+    pub color:[u8;4],
+}
+
+///Glow is color fading out over radius pixels around the text, outline included
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Glow{
+    pub radius:f32,
+    ///straight alpha, what the glow starts at right against the text
+    pub color:[u8;4],
+}
+
+///TextEffects is everything drawn around text besides the text itself, from the bottom up shadow, glow, outline and then the text.
+///nothing reaches further from the glyphs than the padding of the sdf font they are drawn with
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TextEffects{
+    pub outline:Option<Outline>,
+    pub shadow:Option<Shadow>,
+    pub glow:Option<Glow>,
+}
+
+impl TextEffects{
+    pub fn with_outline(mut self, width:f32, color:[u8;4]) -> Self{
+        self.outline = Some(Outline{width, color});
+        self
+    }
+    pub fn with_shadow(mut self, offset:Point, softness:f32, color:[u8;4]) -> Self{
+        self.shadow = Some(Shadow{offset, softness, color});
+        self
+    }
+    pub fn with_glow(mut self, radius:f32, color:[u8;4]) -> Self{
+        self.glow = Some(Glow{radius, color});
+        self
+    }
+    //pixels the effects reach past the glyphs on every side, besides where the shadow is moved to
+    fn reach(&self) -> f32{
+        let outline = self.outline.map_or(0.0, |outline|outline.width.max(0.0));
+        let glow = self.glow.map_or(0.0, |glow|glow.radius.max(0.0));
+        let shadow = self.shadow.map_or(0.0, |shadow|shadow.softness.max(0.0));
+        outline + glow.max(shadow)
+    }
+}
+
+///SdfGlyph is the distance field of one char at the base size of the sdf font it belongs to
+#[derive(Clone, Debug, Default)]
+pub struct SdfGlyph{
+    pub char:char,
+    ///size of the field, padding included. zero for chars with nothing to draw like spaces
+    pub size:Size,
+    ///offset from the pen position on the baseline to the top left of the field
+    pub xoff:i32,
+    pub yoff:i32,
+    pub advance:f32,
+    //where the field starts in the atlas, one byte per pixel
+    atlas_index:usize,
+}
+
+///SdfFont is every char of a charset rasterized once as a distance field, drawn at any size by resampling them
+#[derive(Debug)]
+pub struct SdfFont{
+    ///size the fields were rasterized at, sizes near it look best and much smaller ones lose detail
+    pub font_size:f32,
+    ///pixels the fields reach past the outline at the base size
+    pub padding:i32,
+    //field value gained for every base pixel further inside the outline
+    pixel_dist_scale:f32,
+    //line metrics at the base size, kept unrounded so they scale cleanly
+    ascent:f32,
+    descent:f32,
+    line_gap:f32,
+    line_spacing:f32,
+    atlas:Vec<u8>,
+    //sorted by char so lookups can binary search
+    glyphs:Vec<SdfGlyph>,
+    //kerning at the base size
+    kerning:HashMap<(char, char), f32>,
+    //the sizes resampled most recently, last used at the end
+    sized_fonts:Mutex<Vec<Arc<SizedFont>>>,
+}
+
+impl Clone for SdfFont{
+    fn clone(&self) -> Self{
+        SdfFont{
+            atlas:self.atlas.clone(),
+            glyphs:self.glyphs.clone(),
+            kerning:self.kerning.clone(),
+            sized_fonts:Mutex::new(self.sized_fonts.lock().unwrap_or_else(|poisoned|poisoned.into_inner()).clone()),
+            ..*self
+        }
+    }
+}
+
+impl SdfFont{
+    ///Glyph for char, none if it wasnt in the charset the font was made with
+    pub fn glyph(&self, char:char) -> Option<&SdfGlyph>{
+        self.glyphs.binary_search_by_key(&char, |glyph|glyph.char).ok().map(|index|&self.glyphs[index])
+    }
+    ///Distance field of glyph, one byte a pixel in reading order
+    pub fn field(&self, glyph:&SdfGlyph) -> &[u8]{
+        &self.atlas[glyph.atlas_index..glyph.atlas_index + glyph.size.pixels()]
+    }
+    ///Pixels effects can reach past the glyphs when drawn at font_size, anything further out is cut off
+    pub fn reach(&self, font_size:f32) -> f32{
+        self.padding as f32*font_size/self.font_size
+    }
+    ///Sized font with every glyph resampled from its field at font_size. it draws plain text through Text like any other sized font
+    ///and gives the metrics render lays text out with, nothing goes through stbtt again. sizes drawn lately are kept, asking for one
+    ///again gives back the same font
+    pub fn sized(&self, font_size:f32) -> Arc<SizedFont>{
+        let mut sized_fonts = self.sized_fonts.lock().unwrap_or_else(|poisoned|poisoned.into_inner());
+        let sized_font = match sized_fonts.iter().position(|sized_font|sized_font.font_size == font_size){
+            Some(index) => sized_fonts.remove(index),
+            None => Arc::new(self.resample(font_size)),
+        };
+        if sized_fonts.len() >= SIZED_FONTS{
+            sized_fonts.remove(0);
+        }
+        sized_fonts.push(sized_font.clone());
+        sized_font
+    }
+    //resamples every glyph from its field at font_size
+    fn resample(&self, font_size:f32) -> SizedFont{
+        let scale = font_size/self.font_size;
+        let bitmaps = self.glyphs.iter().map(|glyph|{
+            let mut bitmap = BitmapGlyph{char:glyph.char, advance:glyph.advance*scale, ..Default::default()};
+            if glyph.size.pixels() == 0{
+                return bitmap;
+            }
+            //every pixel the scaled field covers, cut down afterwards to the ones the glyph covers
+            let x0 = (glyph.xoff as f32*scale).floor() as i32;
+            let y0 = (glyph.yoff as f32*scale).floor() as i32;
+            let x1 = ((glyph.xoff + glyph.size.width as i32) as f32*scale).ceil() as i32;
+            let y1 = ((glyph.yoff + glyph.size.height as i32) as f32*scale).ceil() as i32;
+            let mut covered = Rect::new(x1, y1, x0, y0);
+            let mut coverage = Vec::with_capacity(((x1 - x0)*(y1 - y0)) as usize);
+            for y in y0..y1{
+                for x in x0..x1{
+                    let alpha = (edge(self.distance(glyph, scale, x as f32, y as f32), 0.0)*255.0).round() as u8;
+                    if alpha != 0{
+                        covered = Rect::new(covered.x0.min(x), covered.y0.min(y), covered.x1.max(x + 1), covered.y1.max(y + 1));
+                    }
+                    coverage.push(alpha);
+                }
+            }
+            if covered.is_empty(){
+                return bitmap;
+            }
+            let width = (x1 - x0) as usize;
+            for y in covered.y0..covered.y1{
+                let start = (y - y0) as usize*width + (covered.x0 - x0) as usize;
+                bitmap.coverage.extend_from_slice(&coverage[start..start + covered.width() as usize]);
+            }
+            bitmap.size = Size::is(covered.width() as usize, covered.height() as usize);
+            (bitmap.xoff, bitmap.yoff) = (covered.x0, covered.y0);
+            bitmap
+        }).collect();
+        let kerning = self.kerning.iter().map(|(&pair, &kern)|(pair, kern*scale)).collect();
+        //rounded up like the pixel extents of glyphs rasterized straight at the size, so lines come out as tall as theirs
+        let (ascent, descent) = ((self.ascent*scale).ceil() as i32, (self.descent*scale).ceil() as i32);
+        sized_from_bitmaps(font_size, ascent, descent, bitmaps).with_line_spacing((self.line_gap*scale).round() as i32, (self.line_spacing*scale).round() as i32).with_kerning(kerning)
+    }
+    ///Draws text on one line with its effects into a sprite. metrics is the sized font of this font at the size to draw at, the glyphs
+    ///are placed where it would place them. returns the sprite and where its top left sits relative to the top of the line at the pen start
+    pub fn render(&self, metrics:&SizedFont, text:&str, color:[u8;4], effects:&TextEffects) -> (Sprite, Point){
+        let (left, right) = metrics.horizontal_bounds(text);
+        let bounds = Rect::new(left, 0, right, metrics.line_height() as i32);
+        self.render_lines(metrics, &[(text, 0, 0)], bounds, color, effects)
+    }
+    ///Draws every line of layout with its effects into one sprite, laid out with metrics like render. returns the sprite and where its top
+    ///left sits relative to the top left of the box the text was laid out in
+    pub fn render_layout(&self, metrics:&SizedFont, layout:&TextLayout, color:[u8;4], effects:&TextEffects) -> (Sprite, Point){
+        let lines:Vec<(&str, i32, i32)> = layout.lines.iter().map(|line|(line.text.as_str(), line.x, line.y)).collect();
+        self.render_lines(metrics, &lines, layout.bounds, color, effects)
+    }
+
+    //distance from the outline of glyph to the pixel at x, y relative to the pen on the baseline, in pixels at scale. positive is inside
+    fn distance(&self, glyph:&SdfGlyph, scale:f32, x:f32, y:f32) -> f32{
+        let field = self.field(glyph);
+        let (width, height) = (glyph.size.width as i32, glyph.size.height as i32);
+        //where the center of the pixel lands in the field, as a position between the centers of field pixels
+        let fx = (x + 0.5)/scale - glyph.xoff as f32 - 0.5;
+        let fy = (y + 0.5)/scale - glyph.yoff as f32 - 0.5;
+        //edge pixels of the field are padding away from the outline, so clamping to them is as far out as the field can tell
+        let value = |x:i32, y:i32|field[(y.clamp(0, height - 1)*width + x.clamp(0, width - 1)) as usize] as f32;
+        let (x0, y0) = (fx.floor() as i32, fy.floor() as i32);
+        let (tx, ty) = (fx - x0 as f32, fy - y0 as f32);
+        let top = value(x0, y0) + (value(x0 + 1, y0) - value(x0, y0))*tx;
+        let bottom = value(x0, y0 + 1) + (value(x0 + 1, y0 + 1) - value(x0, y0 + 1))*tx;
+        (top + (bottom - top)*ty - ONEDGE as f32)/self.pixel_dist_scale*scale
+    }
+    //draws lines, each text with its pen start and line top, into a sprite covering bounds grown by however far the effects reach
+    fn render_lines(&self, metrics:&SizedFont, lines:&[(&str, i32, i32)], bounds:Rect, color:[u8;4], effects:&TextEffects) -> (Sprite, Point){
+        let scale = metrics.font_size/self.font_size;
+        let reach = self.reach(metrics.font_size);
+        let grow = effects.reach().min(reach).ceil() as i32;
+        let mut area = Rect::new(bounds.x0 - grow, bounds.y0 - grow, bounds.x1 + grow, bounds.y1 + grow);
+        if let Some(shadow) = effects.shadow{
+            let (dx, dy) = (shadow.offset.x.round() as i32, shadow.offset.y.round() as i32);
+            area = area.union(&Rect::new(area.x0 + dx, area.y0 + dy, area.x1 + dx, area.y1 + dy));
+        }
+        let size = Size::is(area.width() as usize, area.height() as usize);
+        //distance of every pixel to the nearest outline, the glyphs of a line overlapping keep whichever is further inside
+        let mut distances = vec![-reach;size.pixels()];
+        for &(text, x, y) in lines{
+            let baseline = y + metrics.ascent;
+            metrics.layout(text, |sized_glyph, pen|{
+                let Some(glyph) = self.glyph(sized_glyph.char).filter(|glyph|glyph.size.pixels() != 0) else{return};
+                let pen = x + pen;
+                let field = Rect::new(
+                    pen + (glyph.xoff as f32*scale).floor() as i32,
+                    baseline + (glyph.yoff as f32*scale).floor() as i32,
+                    pen + ((glyph.xoff + glyph.size.width as i32) as f32*scale).ceil() as i32,
+                    baseline + ((glyph.yoff + glyph.size.height as i32) as f32*scale).ceil() as i32,
+                ).intersect(&area);
+                for py in field.y0..field.y1{
+                    for px in field.x0..field.x1{
+                        let distance = self.distance(glyph, scale, (px - pen) as f32, (py - baseline) as f32);
+                        let index = (py - area.y0) as usize*size.width + (px - area.x0) as usize;
+                        distances[index] = distances[index].max(distance);
+                    }
+                }
+            });
+        }
+        let outline = effects.outline.map_or(0.0, |outline|outline.width.max(0.0));
+        let shadow_offset = effects.shadow.map(|shadow|(shadow.offset.x.round() as i32, shadow.offset.y.round() as i32));
+        let mut data = Vec::with_capacity(size.pixels()*BPP);
+        for y in 0..size.height as i32{
+            for x in 0..size.width as i32{
+                let distance = distances[y as usize*size.width + x as usize];
+                let mut pixel = [0.0f32;4];
+                if let (Some(shadow), Some((dx, dy))) = (effects.shadow, shadow_offset){
+                    let (sx, sy) = (x - dx, y - dy);
+                    let cast = if sx < 0 || sy < 0 || sx >= size.width as i32 || sy >= size.height as i32 {-reach} else {distances[sy as usize*size.width + sx as usize]};
+                    //softness spreads the edge of the shadow over that many pixels instead of one
+                    let alpha = ((cast + outline)/shadow.softness.max(1.0) + 0.5).clamp(0.0, 1.0);
+                    blend(&mut pixel, shadow.color, alpha);
+                }
+                if let Some(glow) = effects.glow{
+                    let outside = -(distance + outline);
+                    //squeezed into however far the field reaches so it fades all the way out instead of being cut off
+                    let radius = glow.radius.min(reach - outline).max(f32::EPSILON);
+                    let alpha = if outside <= 0.0 {1.0} else {(1.0 - outside/radius).clamp(0.0, 1.0).powi(2)};
+                    blend(&mut pixel, glow.color, alpha);
+                }
+                if let Some(effect) = effects.outline{
+                    blend(&mut pixel, effect.color, edge(distance, outline));
+                }
+                blend(&mut pixel, color, edge(distance, 0.0));
+                data.extend(pixel.map(|channel|(channel*255.0).round() as u8));
+            }
+        }
+        (Sprite::from_rgba(data, size), Point::at(area.x0 as f32, area.y0 as f32))
+    }
+}
+
+//how much of a pixel distance from the outline is covered by a shape grown grow pixels past it, antialiased over one pixel
+fn edge(distance:f32, grow:f32) -> f32{
+    (distance + grow + 0.5).clamp(0.0, 1.0)
+}
+
+//puts color at alpha over a premultiplied pixel with channels from 0 to 1
+fn blend(pixel:&mut [f32;4], color:[u8;4], alpha:f32){
+    let alpha = color[3] as f32/255.0*alpha;
+    if alpha <= 0.0{return;}
+    for (channel, value) in pixel[..3].iter_mut().zip(color){
+        *channel = value as f32/255.0*alpha + *channel*(1.0 - alpha);
+    }
+    pixel[3] = alpha + pixel[3]*(1.0 - alpha);
+}
+
+///Rasterizes the distance field of every char in charset at font_size pixels tall, reaching padding pixels past the outlines. the more
+///padding the wider outlines, shadows and glows can be, at the cost of bigger fields
+pub fn create_sdf_font(loaded_font:&mut LoadedFont, font_size:f32, padding:i32, charset:&[char]) -> Result<SdfFont, Error>{
+    let padding = padding.max(1);
+    //the field bottoms out right at the padding so none of its range is wasted on pixels it doesnt have
+    let pixel_dist_scale = (ONEDGE - 1) as f32/padding as f32;
+    let mut atlas = Vec::new();
+    let rasterized = rasterize_chars(loaded_font, font_size, charset, |loaded_font, char|{
+        let field = generate_sdf_glyph(loaded_font, char, font_size, padding, ONEDGE, pixel_dist_scale)?;
+        let glyph = SdfGlyph{char, size:Size::is(field.width as usize, field.height as usize), xoff:field.xoff, yoff:field.yoff, atlas_index:atlas.len(), ..Default::default()};
+        field.push_data(&mut atlas);
+        Ok(glyph)
+    })?;
+    atlas.shrink_to_fit();
+    let glyphs = rasterized.glyphs.into_iter().map(|(char, advance, glyph)|SdfGlyph{char, advance, ..glyph.unwrap_or_default()}).collect();
+    Ok(SdfFont{
+        font_size,
+        padding,
+        pixel_dist_scale,
+        ascent:rasterized.metrics.ascent,
+        descent:rasterized.metrics.descent,
+        line_gap:rasterized.metrics.line_gap,
+        line_spacing:rasterized.metrics.line_spacing,
+        atlas,
+        glyphs,
+        kerning:rasterized.kerning,
+        sized_fonts:Mutex::new(Vec::new()),
+    })
+}
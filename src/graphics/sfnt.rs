@@ -0,0 +1,356 @@
+// ===============================
+//              SFNT.RS
+// -------------------------------
+//  checks font files are well formed before stbtt ever
+//  sees them. stbtt trusts every offset in the file, so a
+//  table directory pointing outside the data is undefined
+//  behavior on the c side. the table directory, checksums,
+//  fixed fields and the offsets stbtt follows out of cmap,
+//  loca and hmtx are bounds checked here first. what those
+//  lead to isnt: glyph ids cmap hands out, the outlines in
+//  glyf and CFF and the kern and GPOS tables are read by
+//  stbtt as they are, and checksums can be worked out by
+//  anyone, so a font made to get past this can still make
+//  stbtt read out of bounds
+// -------------------------------
+
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    io,
+};
+
+///magic numbers a font file can start with, the same ones the c side accepts
+pub const TRUETYPE:u32 = 0x00010000;
+pub const OPENTYPE_CFF:u32 = 0x4F54544F;
+pub const APPLE_TRUETYPE:u32 = 0x74727565;
+pub const TRUETYPE_COLLECTION:u32 = 0x74746366;
+
+///checksum of a whole font plus head.checkSumAdjustment always adds up to this
+const CHECKSUM_MAGIC:u32 = 0xB1B0AFBA;
+///head.magicNumber, anything else means head isnt really a head table
+const HEAD_MAGIC:u32 = 0x5F0F3CF5;
+//where checkSumAdjustment, magicNumber and indexToLocFormat sit inside head
+const HEAD_ADJUSTMENT:usize = 8;
+const HEAD_MAGIC_OFFSET:usize = 12;
+const HEAD_LOCA_FORMAT:usize = 50;
+//where numGlyphs sits inside maxp and numberOfHMetrics inside hhea
+const MAXP_GLYPHS:usize = 4;
+const HHEA_METRICS:usize = 34;
+
+///tables stbtt reads for every font along with the least bytes it reads from each, cmap is only looked into through its own offsets
+const REQUIRED_TABLES:[(Tag, usize);5] = [
+    (Tag(*b"cmap"), 4),
+    (Tag(*b"head"), 54),
+    (Tag(*b"hhea"), 36),
+    (Tag(*b"hmtx"), 0),
+    (Tag(*b"maxp"), 6),
+];
+
+///Tag is the four letter name of an sfnt table
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Tag(pub [u8;4]);
+
+impl Display for Tag{
+    fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result{
+        //tags are meant to be printable ascii, anything else is shown as a question mark so junk data prints cleanly
+        for byte in self.0{
+            let char = if byte.is_ascii_graphic() || byte == b' ' {byte as char} else {'?'};
+            write!(f, "{char}")?;
+        }
+        Ok(())
+    }
+}
+
+///Everything that can make a font unsafe to hand to stbtt, or fail to load at all
+#[derive(Debug)]
+pub enum FontError{
+    ///the file couldnt be found or read
+    Io(io::Error),
+    ///the data ends before something it says is there
+    TooShort{needed:usize, len:usize},
+    ///the first four bytes arent any kind of font
+    UnknownFormat(u32),
+    ///a collection with no faces, or asked for a face it doesnt have
+    NoSuchFace(usize),
+    ///a table directory entry points outside the data
+    TableOutOfBounds(Tag),
+    ///the same table is listed twice
+    DuplicateTable(Tag),
+    ///a table stbtt needs isnt there
+    MissingTable(Tag),
+    ///a table is too small for what stbtt reads out of it, or has contents that cant be right
+    BadTable(Tag, &'static str),
+    ///the data of a table doesnt add up to the checksum in the directory
+    ChecksumMismatch{tag:Tag, stored:u32, computed:u32},
+    ///the whole file doesnt add up to head.checkSumAdjustment
+    FileChecksumMismatch{stored:u32, computed:u32},
+    ///everything checked out but stbtt still refused it
+    InitFailed,
+}
+
+impl Display for FontError{
+    fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result{
+        match self{
+            FontError::Io(error) => write!(f, "Couldnt read font: {error}"),
+            FontError::TooShort{needed, len} => write!(f, "Font data is {len} bytes but needs at least {needed}"),
+            FontError::UnknownFormat(header) => write!(f, "Font has invalid header {header:#010x}"),
+            FontError::NoSuchFace(face) => write!(f, "Font has no face {face}"),
+            FontError::TableOutOfBounds(tag) => write!(f, "Font table {tag} lies outside the file"),
+            FontError::DuplicateTable(tag) => write!(f, "Font table {tag} is listed more than once"),
+            FontError::MissingTable(tag) => write!(f, "Font is missing required table {tag}"),
+            FontError::BadTable(tag, reason) => write!(f, "Font table {tag} is malformed: {reason}"),
+            FontError::ChecksumMismatch{tag, stored, computed} => write!(f, "Font table {tag} checksum is {computed:#010x} but the directory says {stored:#010x}"),
+            FontError::FileChecksumMismatch{stored, computed} => write!(f, "Font checksum adjustment is {computed:#010x} but head says {stored:#010x}"),
+            FontError::InitFailed => write!(f, "stbtt failed to initialize the font"),
+        }
+    }
+}
+
+impl Error for FontError{
+    fn source(&self) -> Option<&(dyn Error + 'static)>{
+        match self{
+            FontError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for FontError{
+    fn from(error:io::Error) -> Self{
+        FontError::Io(error)
+    }
+}
+
+///TableRecord is one entry of a table directory, already checked to lie inside the data
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TableRecord{
+    pub tag:Tag,
+    pub checksum:u32,
+    pub offset:usize,
+    pub length:usize,
+}
+
+//big endian reads that fail instead of running off the end
+fn read_u16(data:&[u8], at:usize) -> Result<u16, FontError>{
+    data.get(at..at + 2).map(|bytes|u16::from_be_bytes([bytes[0], bytes[1]])).ok_or(FontError::TooShort{needed:at + 2, len:data.len()})
+}
+fn read_u32(data:&[u8], at:usize) -> Result<u32, FontError>{
+    data.get(at..at + 4).map(|bytes|u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])).ok_or(FontError::TooShort{needed:at + 4, len:data.len()})
+}
+
+///Sum of data as big endian u32s, the last one padded with zeros. how every sfnt checksum is worked out
+pub fn checksum(data:&[u8]) -> u32{
+    let mut chunks = data.chunks_exact(4);
+    let mut sum = chunks.by_ref().fold(0u32, |sum, word|sum.wrapping_add(u32::from_be_bytes([word[0], word[1], word[2], word[3]])));
+    let rest = chunks.remainder();
+    if !rest.is_empty(){
+        let mut word = [0u8;4];
+        word[..rest.len()].copy_from_slice(rest);
+        sum = sum.wrapping_add(u32::from_be_bytes(word));
+    }
+    sum
+}
+
+///Offsets of the table directory of every face in data, one for a plain font
+pub fn face_offsets(data:&[u8]) -> Result<Vec<usize>, FontError>{
+    match read_u32(data, 0)?{
+        TRUETYPE | OPENTYPE_CFF | APPLE_TRUETYPE => Ok(vec![0]),
+        TRUETYPE_COLLECTION =>{
+            let count = read_u32(data, 8)? as usize;
+            if count == 0{
+                return Err(FontError::NoSuchFace(0));
+            }
+            (0..count).map(|face|read_u32(data, 12 + face*4).map(|offset|offset as usize)).collect()
+        }
+        header => Err(FontError::UnknownFormat(header)),
+    }
+}
+
+///Reads the table directory at offset, checking every table lies inside data and none are listed twice
+pub fn table_directory(data:&[u8], offset:usize) -> Result<Vec<TableRecord>, FontError>{
+    let header = read_u32(data, offset)?;
+    if !matches!(header, TRUETYPE | OPENTYPE_CFF | APPLE_TRUETYPE){
+        return Err(FontError::UnknownFormat(header));
+    }
+    let count = read_u16(data, offset + 4)? as usize;
+    let mut records:Vec<TableRecord> = Vec::with_capacity(count);
+    for index in 0..count{
+        let at = offset + 12 + index*16;
+        let tag = Tag(read_u32(data, at)?.to_be_bytes());
+        let record = TableRecord{
+            tag,
+            checksum:read_u32(data, at + 4)?,
+            offset:read_u32(data, at + 8)? as usize,
+            length:read_u32(data, at + 12)? as usize,
+        };
+        if record.offset.checked_add(record.length).is_none_or(|end|end > data.len()){
+            return Err(FontError::TableOutOfBounds(tag));
+        }
+        if records.iter().any(|other|other.tag == tag){
+            return Err(FontError::DuplicateTable(tag));
+        }
+        records.push(record);
+    }
+    Ok(records)
+}
+
+///Checks everything stbtt reads on its own is there and inside data. every face of a collection is checked, the whole file checksum only
+///for plain fonts since collection faces share tables and the adjustment in each head cant cover all of them
+pub fn validate_font(data:&[u8]) -> Result<(), FontError>{
+    let faces = face_offsets(data)?;
+    for &offset in faces.iter(){
+        validate_face(data, offset)?;
+    }
+    if faces.len() == 1 && faces[0] == 0{
+        let head = table_directory(data, 0)?.into_iter().find(|record|record.tag == Tag(*b"head")).ok_or(FontError::MissingTable(Tag(*b"head")))?;
+        let stored = read_u32(data, head.offset + HEAD_ADJUSTMENT)?;
+        //the adjustment itself counts as zero when the file is summed
+        let computed = CHECKSUM_MAGIC.wrapping_sub(checksum(data).wrapping_sub(stored));
+        if computed != stored{
+            return Err(FontError::FileChecksumMismatch{stored, computed});
+        }
+    }
+    Ok(())
+}
+
+//checks the directory, checksums and required tables of the face whose directory is at offset
+fn validate_face(data:&[u8], offset:usize) -> Result<(), FontError>{
+    let records = table_directory(data, offset)?;
+    for record in records.iter(){
+        let table = &data[record.offset..record.offset + record.length];
+        let mut computed = checksum(table);
+        if record.tag == Tag(*b"head"){
+            //heads checksum is worked out as if checkSumAdjustment was zero, the adjustment is summed over the whole file later
+            computed = computed.wrapping_sub(read_u32(table, HEAD_ADJUSTMENT).unwrap_or(0));
+        }
+        if computed != record.checksum{
+            return Err(FontError::ChecksumMismatch{tag:record.tag, stored:record.checksum, computed});
+        }
+    }
+    let table = |tag:Tag|records.iter().find(|record|record.tag == tag);
+    for (tag, min_length) in REQUIRED_TABLES{
+        let record = table(tag).ok_or(FontError::MissingTable(tag))?;
+        if record.length < min_length{
+            return Err(FontError::BadTable(tag, "shorter than its fixed fields"));
+        }
+    }
+    let table_data = |tag:Tag|table(tag).map(|record|&data[record.offset..record.offset + record.length]).unwrap_or_default();
+    let head = table_data(Tag(*b"head"));
+    if read_u32(head, HEAD_MAGIC_OFFSET)? != HEAD_MAGIC{
+        return Err(FontError::BadTable(Tag(*b"head"), "wrong magic number"));
+    }
+    //every fixed field read here was covered by the minimum lengths above
+    let glyph_count = read_u16(table_data(Tag(*b"maxp")), MAXP_GLYPHS)? as usize;
+    validate_hmtx(table_data(Tag(*b"hhea")), table_data(Tag(*b"hmtx")), glyph_count)?;
+    validate_cmap(table_data(Tag(*b"cmap")))?;
+    //outlines come either as glyf with loca to index it, or as cff
+    match (table(Tag(*b"glyf")), table(Tag(*b"loca")), table(Tag(*b"CFF "))){
+        (Some(glyf), Some(_), _) => validate_loca(table_data(Tag(*b"loca")), glyf.length, glyph_count, read_u16(head, HEAD_LOCA_FORMAT)?),
+        (None, None, Some(_)) => Ok(()),
+        (Some(_), None, _) => Err(FontError::MissingTable(Tag(*b"loca"))),
+        (None, Some(_), _) => Err(FontError::MissingTable(Tag(*b"glyf"))),
+        (None, None, None) => Err(FontError::MissingTable(Tag(*b"glyf"))),
+    }
+}
+
+//hmtx holds an advance and side bearing for each of the first numberOfHMetrics glyphs and just a side bearing for every glyph after,
+//which stbtt gives the last advance. so there has to be at least one full metric and room for every glyph
+fn validate_hmtx(hhea:&[u8], hmtx:&[u8], glyph_count:usize) -> Result<(), FontError>{
+    let long_metrics = read_u16(hhea, HHEA_METRICS)? as usize;
+    if long_metrics == 0{
+        return Err(FontError::BadTable(Tag(*b"hhea"), "no horizontal metrics"));
+    }
+    if hmtx.len() < long_metrics*4 + glyph_count.saturating_sub(long_metrics)*2{
+        return Err(FontError::BadTable(Tag(*b"hmtx"), "shorter than numberOfHMetrics and numGlyphs need"));
+    }
+    Ok(())
+}
+
+//loca has an offset into glyf for every glyph plus one for the end of the last, u16 halved offsets for format 0 and u32 ones otherwise.
+//stbtt reads a glyph from one offset to the next so they have to stay inside glyf and never go backwards
+fn validate_loca(loca:&[u8], glyf_length:usize, glyph_count:usize, format:u16) -> Result<(), FontError>{
+    let bad = |reason|FontError::BadTable(Tag(*b"loca"), reason);
+    let entry = if format == 0 {2} else {4};
+    if loca.len() < (glyph_count + 1)*entry{
+        return Err(bad("shorter than numGlyphs needs"));
+    }
+    let mut previous = 0;
+    for glyph in 0..=glyph_count{
+        let offset = match format{
+            0 => read_u16(loca, glyph*2)? as usize*2,
+            _ => read_u32(loca, glyph*4)? as usize,
+        };
+        if offset < previous{
+            return Err(bad("offsets go backwards"));
+        }
+        if offset > glyf_length{
+            return Err(bad("offset points past the end of glyf"));
+        }
+        previous = offset;
+    }
+    Ok(())
+}
+
+//cmap is a list of subtables that map chars to glyph ids, stbtt picks one and follows its offsets to look chars up. every subtable
+//has to fit inside cmap along with the arrays stbtt reads for its format
+fn validate_cmap(cmap:&[u8]) -> Result<(), FontError>{
+    let bad = |reason|FontError::BadTable(Tag(*b"cmap"), reason);
+    //reads inside a subtable, running off its end is the subtables fault rather than the files
+    let field16 = |bytes:&[u8], at:usize|read_u16(bytes, at).map_err(|_|bad("subtable cut short"));
+    let field32 = |bytes:&[u8], at:usize|read_u32(bytes, at).map_err(|_|bad("subtable cut short"));
+    let count = read_u16(cmap, 2)? as usize;
+    if cmap.len() < 4 + count*8{
+        return Err(bad("encoding records run past the end"));
+    }
+    for record in 0..count{
+        let offset = read_u32(cmap, 4 + record*8 + 4)? as usize;
+        let subtable = cmap.get(offset..).ok_or(bad("subtable outside the table"))?;
+        let format = field16(subtable, 0)?;
+        //formats up to 6 have a u16 length, the later ones a u32 after a reserved field, besides 14 which has no reserved field
+        let length = match format{
+            0 | 2 | 4 | 6 => field16(subtable, 2)? as usize,
+            14 => field32(subtable, 2)? as usize,
+            _ => field32(subtable, 4)? as usize,
+        };
+        let subtable = subtable.get(..length).ok_or(bad("subtable runs past the end"))?;
+        let needed = match format{
+            //a byte for each of the first 256 chars
+            0 => 6 + 256,
+            4 =>{
+                let segments = field16(subtable, 6)? as usize/2;
+                validate_segments(subtable, segments)?;
+                //end codes, a reserved pad, start codes, deltas and range offsets, a u16 of each per segment
+                16 + segments*8
+            }
+            6 => 10 + field16(subtable, 8)? as usize*2,
+            12 | 13 => 16 + field32(subtable, 12)? as usize*12,
+            _ => 0,
+        };
+        if subtable.len() < needed{
+            return Err(bad("subtable shorter than its arrays"));
+        }
+    }
+    Ok(())
+}
+
+//segments of a format 4 subtable with a range offset read their glyph ids that many bytes on from where the offset itself sits, so the
+//ids for every char of the segment have to be inside the subtable. the 0xFFFF segment that ends the list is left out, it is there
+//only so searches stop and fonts often point it nowhere
+fn validate_segments(subtable:&[u8], segments:usize) -> Result<(), FontError>{
+    let bad = FontError::BadTable(Tag(*b"cmap"), "format 4 glyph ids run past the end");
+    if subtable.len() < 16 + segments*8{
+        return Err(FontError::BadTable(Tag(*b"cmap"), "subtable shorter than its arrays"));
+    }
+    for segment in 0..segments{
+        let end = read_u16(subtable, 14 + segment*2)? as usize;
+        let start = read_u16(subtable, 16 + segments*2 + segment*2)? as usize;
+        let range_at = 16 + segments*6 + segment*2;
+        let range_offset = read_u16(subtable, range_at)? as usize;
+        if range_offset == 0 || start == 0xFFFF{continue;}
+        if range_at + range_offset + end.saturating_sub(start)*2 + 2 > subtable.len(){
+            return Err(bad);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,101 @@
+// ===============================
+//            CONVERT.RS
+// -------------------------------
+//  Turns decoded png pixels into sprite data.
+//  only depends on std and png because build.rs
+//  includes this file too, so sprites generated at
+//  build time and loaded at runtime come out the same
+// -------------------------------
+
+use png::ColorType;
+
+const BPP:usize = 4;
+
+///One line of a run of solid or translucent pixels, same fields as pixel range in the same order
+pub type RangeFields = [usize;5];
+
+///Expands 8 bit png pixels of color_type into straight rgba. none for indexed color, palettes have to be expanded by the decoder first
+pub fn expand_to_rgba(buffer:&[u8], color_type:ColorType) -> Option<Vec<u8>>{
+    let mut data = Vec::with_capacity(buffer.len()/color_type.samples()*BPP);
+    match color_type{
+        ColorType::Rgba => data.extend_from_slice(buffer),
+        ColorType::Rgb => buffer.chunks_exact(3).for_each(|rgb|data.extend_from_slice(&[rgb[0], rgb[1], rgb[2], 255])),
+        ColorType::GrayscaleAlpha => buffer.chunks_exact(2).for_each(|gray|data.extend_from_slice(&[gray[0], gray[0], gray[0], gray[1]])),
+        ColorType::Grayscale => buffer.iter().for_each(|&gray|data.extend_from_slice(&[gray, gray, gray, 255])),
+        ColorType::Indexed => return None,
+    }
+    Some(data)
+}
+
+///Premultiplies straight rgba in place, rounding the same way the sprite converter does. fully transparent pixels become all zeros
+pub fn premultiply(data:&mut [u8]){
+    for pixel in data.chunks_exact_mut(BPP){
+        let alpha = pixel[3] as u16;
+        match alpha{
+            0 => pixel.fill(0),
+            255 =>{}
+            _ => for channel in &mut pixel[..3]{
+                *channel = ((*channel as u16*alpha + 127)/255) as u8;
+            },
+        }
+    }
+}
+
+///Splits every line of an rgba image into runs of solid and translucent pixels, transparent runs are left out.
+///returns the translucent ranges then the solid ranges as line, src_index, src_end_index, x_begin, x_end
+pub fn split_ranges(data:&[u8], width:usize) -> (Vec<RangeFields>, Vec<RangeFields>){
+    let alphas:Vec<u8> = data.chunks_exact(BPP).map(|pixel|pixel[3]).collect();
+    split_alpha_ranges(&alphas, width, BPP)
+}
+
+///Same as split_ranges but only needs the alpha of every pixel. src_index and src_end_index count stride bytes per pixel,
+///x_begin and x_end are always in rgba bytes so ranges line up on the display whatever the sprite stores
+pub fn split_alpha_ranges(alphas:&[u8], width:usize, stride:usize) -> (Vec<RangeFields>, Vec<RangeFields>){
+    let mut translucent = Vec::new();
+    let mut solid = Vec::new();
+    if width == 0{return (translucent, solid);}
+    for (line, pixels) in alphas.chunks_exact(width).enumerate(){
+        let line_start = line*width*stride;
+        //0 transparent, 1 translucent, 2 solid
+        let kind = |x:usize|match pixels[x]{0 => 0, 255 => 2, _ => 1};
+        let mut x = 0;
+        while x < width{
+            //finds the end of the run of pixels with the same kind of alpha as the one at x
+            let start = x;
+            while x < width && kind(x) == kind(start){
+                x += 1;
+            }
+            let range = [line, line_start + start*stride, line_start + x*stride, start*BPP, x*BPP];
+            match kind(start){
+                1 => translucent.push(range),
+                2 => solid.push(range),
+                _ =>{}
+            }
+        }
+    }
+    (translucent, solid)
+}
+
+///Run length encodes the pixels each range covers, solid runs first then translucent ones. returns the runs back to back and
+///the translucent then solid ranges again with src_index and src_end_index pointing into the runs instead of the rgba data
+pub fn encode_runs(data:&[u8], translucent:&[RangeFields], solid:&[RangeFields]) -> (Vec<u8>, Vec<RangeFields>, Vec<RangeFields>){
+    let mut runs = Vec::new();
+    let solid = solid.iter().map(|&range|encode_range(data, range, &mut runs)).collect();
+    let translucent = translucent.iter().map(|&range|encode_range(data, range, &mut runs)).collect();
+    (runs, translucent, solid)
+}
+
+fn encode_range(data:&[u8], [line, src_index, src_end_index, x_begin, x_end]:RangeFields, runs:&mut Vec<u8>) -> RangeFields{
+    let start = runs.len();
+    let mut pixels = data[src_index..src_end_index].chunks_exact(BPP).peekable();
+    while let Some(color) = pixels.next(){
+        //each run is a count byte then the rgba its pixels share, the byte caps runs at 255 pixels, longer ones carry on in the next run
+        let mut count = 1;
+        while count < u8::MAX && pixels.next_if(|next|*next == color).is_some(){
+            count += 1;
+        }
+        runs.push(count);
+        runs.extend_from_slice(color);
+    }
+    [line, start, runs.len(), x_begin, x_end]
+}
@@ -1,140 +0,0 @@
-// ===============================
-//  DEFAULT_SPRITE.RS
-// -------------------------------
-//  generated from default.png
-//  dimensions: 41x40
-//  place in sprites folder and declare in sprites mod.rs to use
-//  DO NOT EDIT MANUALLY
-// -------------------------------
-
-use crate::{graphics::sprites::{Sprite, PixelRange}, shared::Size};
-
-pub const DEFAULT_SPRITE:Sprite = Sprite{data:&DEFAULT_SPRITE_DATA,size: Size{width:41,height:40},
-translucent_ranges:&DEFAULT_TRANSLUCENT, solid_ranges:&DEFAULT_SOLID};
-
-const DEFAULT_SPRITE_DATA:[u8;6560]= [0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,111,0,96,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,111,0,96,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,111,0,96,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,111,0,96,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,111,0,96,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,111,0,96,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,111,0,96,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,111,0,96,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,111,0,96,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,111,0,96,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,111,0,96,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,111,0,96,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,111,0,96,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,111,0,96,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,111,0,96,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,111,0,96,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,111,0,96,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,111,0,96,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,111,0,96,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,41,0,36,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,0,220,255,0,0,0,0,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,0,220,255,0,0,0,0,
-95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,95,0,82,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,0,220,255,0,0,0,0,
-255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,0,220,255,0,0,0,0,
-255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,0,220,255,0,0,0,0,
-255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,0,220,255,0,0,0,0,
-255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,0,220,255,0,0,0,0,
-255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,0,220,255,0,0,0,0,
-255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,0,220,255,0,0,0,0,
-255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,0,220,255,0,0,0,0,
-255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,0,220,255,0,0,0,0,
-255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,0,220,255,0,0,0,0,
-255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,0,220,255,0,0,0,0,
-255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,0,220,255,0,0,0,0,
-255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,0,220,255,0,0,0,0,
-255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,0,220,255,0,0,0,0,
-255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,255,0,220,255,0,0,0,0];
-
-const DEFAULT_TRANSLUCENT:[PixelRange;0]= [
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-];
-
-const DEFAULT_SOLID:[PixelRange;40]= [
-PixelRange{line:0,src_index:0,src_end_index:160,x_begin:0,x_end:160},
-PixelRange{line:1,src_index:164,src_end_index:324,x_begin:0,x_end:160},
-PixelRange{line:2,src_index:328,src_end_index:488,x_begin:0,x_end:160},
-PixelRange{line:3,src_index:492,src_end_index:652,x_begin:0,x_end:160},
-PixelRange{line:4,src_index:656,src_end_index:816,x_begin:0,x_end:160},
-PixelRange{line:5,src_index:820,src_end_index:980,x_begin:0,x_end:160},
-PixelRange{line:6,src_index:984,src_end_index:1144,x_begin:0,x_end:160},
-PixelRange{line:7,src_index:1148,src_end_index:1308,x_begin:0,x_end:160},
-PixelRange{line:8,src_index:1312,src_end_index:1472,x_begin:0,x_end:160},
-PixelRange{line:9,src_index:1476,src_end_index:1636,x_begin:0,x_end:160},
-PixelRange{line:10,src_index:1640,src_end_index:1800,x_begin:0,x_end:160},
-PixelRange{line:11,src_index:1804,src_end_index:1964,x_begin:0,x_end:160},
-PixelRange{line:12,src_index:1968,src_end_index:2128,x_begin:0,x_end:160},
-PixelRange{line:13,src_index:2132,src_end_index:2292,x_begin:0,x_end:160},
-PixelRange{line:14,src_index:2296,src_end_index:2456,x_begin:0,x_end:160},
-PixelRange{line:15,src_index:2460,src_end_index:2620,x_begin:0,x_end:160},
-PixelRange{line:16,src_index:2624,src_end_index:2784,x_begin:0,x_end:160},
-PixelRange{line:17,src_index:2788,src_end_index:2948,x_begin:0,x_end:160},
-PixelRange{line:18,src_index:2952,src_end_index:3112,x_begin:0,x_end:160},
-PixelRange{line:19,src_index:3116,src_end_index:3276,x_begin:0,x_end:160},
-PixelRange{line:20,src_index:3280,src_end_index:3440,x_begin:0,x_end:160},
-PixelRange{line:21,src_index:3444,src_end_index:3604,x_begin:0,x_end:160},
-PixelRange{line:22,src_index:3608,src_end_index:3768,x_begin:0,x_end:160},
-PixelRange{line:23,src_index:3772,src_end_index:3932,x_begin:0,x_end:160},
-PixelRange{line:24,src_index:3936,src_end_index:4096,x_begin:0,x_end:160},
-PixelRange{line:25,src_index:4100,src_end_index:4260,x_begin:0,x_end:160},
-PixelRange{line:26,src_index:4264,src_end_index:4424,x_begin:0,x_end:160},
-PixelRange{line:27,src_index:4428,src_end_index:4588,x_begin:0,x_end:160},
-PixelRange{line:28,src_index:4592,src_end_index:4752,x_begin:0,x_end:160},
-PixelRange{line:29,src_index:4756,src_end_index:4916,x_begin:0,x_end:160},
-PixelRange{line:30,src_index:4920,src_end_index:5080,x_begin:0,x_end:160},
-PixelRange{line:31,src_index:5084,src_end_index:5244,x_begin:0,x_end:160},
-PixelRange{line:32,src_index:5248,src_end_index:5408,x_begin:0,x_end:160},
-PixelRange{line:33,src_index:5412,src_end_index:5572,x_begin:0,x_end:160},
-PixelRange{line:34,src_index:5576,src_end_index:5736,x_begin:0,x_end:160},
-PixelRange{line:35,src_index:5740,src_end_index:5900,x_begin:0,x_end:160},
-PixelRange{line:36,src_index:5904,src_end_index:6064,x_begin:0,x_end:160},
-PixelRange{line:37,src_index:6068,src_end_index:6228,x_begin:0,x_end:160},
-PixelRange{line:38,src_index:6232,src_end_index:6392,x_begin:0,x_end:160},
-PixelRange{line:39,src_index:6396,src_end_index:6556,x_begin:0,x_end:160},
-];
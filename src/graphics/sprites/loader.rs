@@ -0,0 +1,89 @@
+// ===============================
+//            LOADER.RS
+// -------------------------------
+//  Decodes png files into sprites at runtime,
+//  same output as the sprite converter script
+//  without having to regenerate and recompile
+// -------------------------------
+
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    fs::File,
+    io::{self, BufReader, Read},
+    path::Path,
+};
+
+use png::{Decoder, DecodingError, Transformations};
+
+use crate::{graphics::sprites::{convert::{expand_to_rgba, premultiply}, Sprite}, shared::Size};
+
+///Everything that can go wrong turning a png into a sprite
+#[derive(Debug)]
+pub enum SpriteError{
+    ///the file couldnt be opened or read
+    Io(io::Error),
+    ///the bytes arent a png the decoder understands
+    Decode(DecodingError),
+    ///the png decoded but into something a sprite cant hold
+    Unsupported(String),
+}
+
+impl Display for SpriteError{
+    fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result{
+        match self{
+            SpriteError::Io(error) => write!(f, "Couldnt read sprite: {error}"),
+            SpriteError::Decode(error) => write!(f, "Couldnt decode sprite png: {error}"),
+            SpriteError::Unsupported(reason) => write!(f, "Unsupported sprite png: {reason}"),
+        }
+    }
+}
+
+impl Error for SpriteError{
+    fn source(&self) -> Option<&(dyn Error + 'static)>{
+        match self{
+            SpriteError::Io(error) => Some(error),
+            SpriteError::Decode(error) => Some(error),
+            SpriteError::Unsupported(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for SpriteError{
+    fn from(error:io::Error) -> Self{
+        SpriteError::Io(error)
+    }
+}
+
+impl From<DecodingError> for SpriteError{
+    fn from(error:DecodingError) -> Self{
+        SpriteError::Decode(error)
+    }
+}
+
+impl Sprite{
+    ///Loads the png at path into an owned sprite. read fresh every call so edited art shows up without recompiling
+    pub fn load_png(path:impl AsRef<Path>) -> Result<Sprite, SpriteError>{
+        decode_png(BufReader::new(File::open(path)?))
+    }
+    ///Decodes png bytes into an owned sprite, for art embedded with include_bytes
+    pub fn from_png_bytes(bytes:&[u8]) -> Result<Sprite, SpriteError>{
+        decode_png(bytes)
+    }
+}
+
+fn decode_png(reader:impl Read) -> Result<Sprite, SpriteError>{
+    let mut decoder = Decoder::new(reader);
+    //palettes and low bit depths are expanded and 16 bit channels cut down, so everything comes out as 8 bit gray, gray alpha, rgb or rgba
+    decoder.set_transformations(Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info()?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer)?;
+    buffer.truncate(info.buffer_size());
+    let size = Size::is(info.width as usize, info.height as usize);
+    let Some(mut data) = expand_to_rgba(&buffer, info.color_type) else{
+        return Err(SpriteError::Unsupported("palette was not expanded".to_string()));
+    };
+    premultiply(&mut data);
+    Ok(Sprite::from_rgba(data, size))
+}
@@ -1,68 +0,0 @@
-// ===============================
-//  SPRITE: MARIO
-// -------------------------------
-//  Auto-generated from mario.png
-//  Dimensions: 12x16
-//  Place in sprites folder and declare in mod.rs to use
-//  DO NOT EDIT MANUALLY
-// ===============================
-
-use crate::{graphics::sprites::Sprite, shared::Size};
-
-pub const MARIO_SPRITE:Sprite = Sprite{data:&MARIO_SPRITE_DATA,size: Size{width:12,height:16},
-translucent_ranges:&MARIO_TRANSLUCENT, solid_ranges:&MARIO_SOLID};
-
-const MARIO_SPRITE_DATA:[u8;768]= [0,0,0,0,0,0,0,0,0,0,0,0,216,40,0,255,216,40,0,255,216,40,0,255,216,40,0,255,216,40,0,255,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
-0,0,0,0,0,0,0,0,216,40,0,255,216,40,0,255,216,40,0,255,216,40,0,255,216,40,0,255,216,40,0,255,216,40,0,255,216,40,0,255,216,40,0,255,0,0,0,0,
-0,0,0,0,0,0,0,0,136,112,0,255,136,112,0,255,136,112,0,255,252,152,56,255,252,152,56,255,136,112,0,255,252,152,56,255,0,0,0,0,0,0,0,0,0,0,0,0,
-0,0,0,0,136,112,0,255,252,152,56,255,136,112,0,255,252,152,56,255,252,152,56,255,252,152,56,255,136,112,0,255,252,152,56,255,252,152,56,255,252,152,56,255,0,0,0,0,
-0,0,0,0,136,112,0,255,252,152,56,255,136,112,0,255,136,112,0,255,252,152,56,255,252,152,56,255,252,152,56,255,136,112,0,255,252,152,56,255,252,152,56,255,252,152,56,255,
-0,0,0,0,136,112,0,255,136,112,0,255,252,152,56,255,252,152,56,255,252,152,56,255,252,152,56,255,136,112,0,255,136,112,0,255,136,112,0,255,136,112,0,255,0,0,0,0,
-0,0,0,0,0,0,0,0,0,0,0,0,252,152,56,255,252,152,56,255,252,152,56,255,252,152,56,255,252,152,56,255,252,152,56,255,252,152,56,255,0,0,0,0,0,0,0,0,
-0,0,0,0,0,0,0,0,136,112,0,255,136,112,0,255,216,40,0,255,136,112,0,255,136,112,0,255,136,112,0,255,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
-0,0,0,0,136,112,0,255,136,112,0,255,136,112,0,255,216,40,0,255,136,112,0,255,136,112,0,255,216,40,0,255,136,112,0,255,136,112,0,255,136,112,0,255,0,0,0,0,
-136,112,0,255,136,112,0,255,136,112,0,255,136,112,0,255,216,40,0,255,216,40,0,255,216,40,0,255,216,40,0,255,136,112,0,255,136,112,0,255,136,112,0,255,136,112,0,255,
-252,152,56,255,252,152,56,255,136,112,0,255,216,40,0,255,252,152,56,255,216,40,0,255,216,40,0,255,252,152,56,255,216,40,0,255,136,112,0,255,252,152,56,255,252,152,56,255,
-252,152,56,255,252,152,56,255,252,152,56,255,216,40,0,255,216,40,0,255,216,40,0,255,216,40,0,255,216,40,0,255,216,40,0,255,252,152,56,255,252,152,56,255,252,152,56,255,
-252,152,56,255,252,152,56,255,216,40,0,255,216,40,0,255,216,40,0,255,216,40,0,255,216,40,0,255,216,40,0,255,216,40,0,255,216,40,0,255,252,152,56,255,252,152,56,255,
-0,0,0,0,0,0,0,0,216,40,0,255,216,40,0,255,216,40,0,255,0,0,0,0,0,0,0,0,216,40,0,255,216,40,0,255,216,40,0,255,0,0,0,0,0,0,0,0,
-0,0,0,0,136,112,0,255,136,112,0,255,136,112,0,255,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,136,112,0,255,136,112,0,255,136,112,0,255,0,0,0,0,
-136,112,0,255,136,112,0,255,136,112,0,255,136,112,0,255,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,136,112,0,255,136,112,0,255,136,112,0,255,136,112,0,255];
-
-const MARIO_TRANSLUCENT:[[usize;5];0]= [
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-];
-
-const MARIO_SOLID:[[usize;5];19]= [
-[0,12, 32, 12,32],
-[1,56, 92, 8,44],
-[2,104, 132, 8,36],
-[3,148, 188, 4,44],
-[4,196, 240, 4,48],
-[5,244, 284, 4,44],
-[6,300, 328, 12,40],
-[7,344, 368, 8,32],
-[8,388, 428, 4,44],
-[9,432, 480, 0,48],
-[10,480, 528, 0,48],
-[11,528, 576, 0,48],
-[12,576, 624, 0,48],
-[13,632, 644, 8,20],[13,652, 664, 28,40],
-[14,676, 688, 4,16],[14,704, 716, 32,44],
-[15,720, 736, 0,16],[15,752, 768, 32,48],
-];
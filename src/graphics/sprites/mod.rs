@@ -1,27 +1,96 @@
 // ===============================
 //  SPRITES MODULE
 // -------------------------------
-//  Whenever you import a new sprite you need to declare it 
-//  here as a pub mod in order to use it in code
+//  Sprites are generated by build.rs from every png
+//  in assets/sprites. drop a png in there and it shows
+//  up here as <name>_sprite::<NAME>_RLE, with
+//  <NAME>_SPRITE decoded from it on first use
 // -------------------------------
 
-use crate::shared::Size;
+use std::{ops::Deref, sync::Arc};
+
+use crate::shared::{Size, BPP};
+use convert::{split_ranges, RangeFields};
+use palette::Palette;
+
+//pixel conversion shared with build.rs, runtime png loading and run length encoding
+pub mod convert;
+pub mod loader;
+pub mod palette;
+pub mod rle;
+
+//one pub mod per png in assets/sprites, written to OUT_DIR by build.rs
+include!(concat!(env!("OUT_DIR"), "/sprites.rs"));
+
+///SpriteSlice is the storage behind a sprite. generated sprites borrow their constants, sprites built at runtime share
+///an allocation so cloning them into objects stays cheap
+#[derive(Clone,Debug)]
+pub enum SpriteSlice<T:'static>{
+    Static(&'static [T]),
+    Shared(Arc<[T]>),
+}
+
+impl<T> Deref for SpriteSlice<T>{
+    type Target = [T];
+    fn deref(&self) -> &[T]{
+        match self{
+            SpriteSlice::Static(slice) => slice,
+            SpriteSlice::Shared(slice) => slice,
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for SpriteSlice<T>{
+    fn from(vec:Vec<T>) -> Self{
+        SpriteSlice::Shared(vec.into())
+    }
+}
 
-//DECLARE THE SPRITE YOU CREATES AS A PUB MOD HERE
-//VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVV
-//pub mod mario_sprite;
-pub mod default_sprite;
-pub mod blackbuck_sprite;
-pub mod transparency2_sprite;
-//------------------------------------------------
 #[derive(Clone,Debug)]
 
 pub struct Sprite{
-    pub data:&'static [u8],
+    pub data:SpriteSlice<u8>,
     pub size:Size,
-    pub translucent_ranges:&'static [PixelRange],
-    pub solid_ranges:&'static [PixelRange]
-
+    pub translucent_ranges:SpriteSlice<PixelRange>,
+    pub solid_ranges:SpriteSlice<PixelRange>,
+    ///set for indexed sprites, data then holds one index into this palette per pixel instead of rgba
+    pub palette:Option<Arc<Palette>>,
+}
+impl Sprite{
+    ///Builds a sprite from premultiplied rgba data laid out as a full image, splitting every line into solid and translucent ranges
+    ///the same way generated sprites are
+    pub fn from_rgba(data:Vec<u8>, size:Size) -> Self{
+        assert_eq!(data.len(), size.pixels()*BPP, "sprite data doesnt match its size");
+        let (translucent_ranges, solid_ranges) = split_ranges(&data, size.width);
+        let to_ranges = |ranges:Vec<RangeFields>|ranges.into_iter()
+            .map(|[line, src_index, src_end_index, x_begin, x_end]|PixelRange{line, src_index, src_end_index, x_begin, x_end})
+            .collect::<Vec<_>>();
+        Sprite{
+            data:data.into(),
+            size,
+            translucent_ranges:to_ranges(translucent_ranges).into(),
+            solid_ranges:to_ranges(solid_ranges).into(),
+            palette:None,
+        }
+    }
+    ///Copies the size sized area with its top left corner at x, y into a new sprite, has to fit inside the sprite
+    ///indexed sprites stay indexed and share their palette
+    pub fn crop(&self, x:usize, y:usize, size:Size) -> Self{
+        let stride = self.stride();
+        let mut data = Vec::with_capacity(size.pixels()*stride);
+        for line in y..y + size.height{
+            let start = (line*self.size.width + x)*stride;
+            data.extend_from_slice(&self.data[start..start + size.width*stride]);
+        }
+        match &self.palette{
+            Some(palette) => Sprite::from_shared_indices(data, size, palette.clone()),
+            None => Sprite::from_rgba(data, size),
+        }
+    }
+    ///Premultiplied rgba of the pixel at x, y. sprite data is stored as a full image so this works for every pixel, transparent ones are all zeros
+    pub fn pixel(&self, x:usize, y:usize) -> &[u8]{
+        self.color_in(x, y, None)
+    }
 }
 #[derive(Debug,Clone)]
 ///Contains all of the 
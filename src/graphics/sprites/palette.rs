@@ -0,0 +1,122 @@
+// ===============================
+//            PALETTE.RS
+// -------------------------------
+//  Indexed color sprites. the sprite stores one
+//  palette index per pixel and the renderer looks
+//  the color up while drawing, so recolors, flashes
+//  and color cycling only ever touch the palette
+// -------------------------------
+
+use std::{ops::Range, sync::Arc};
+
+use crate::{graphics::sprites::{convert::{premultiply, split_alpha_ranges, RangeFields}, PixelRange, Sprite}, shared::{Size, BPP}};
+
+///Palette is a table of premultiplied rgba colors that indexed sprites look their pixels up in
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Palette{
+    colors:Vec<[u8;4]>,
+}
+
+impl Palette{
+    ///Palette from straight alpha colors, premultiplied the same way sprite pngs are
+    pub fn new(colors:&[[u8;4]]) -> Self{
+        let mut colors = colors.to_vec();
+        premultiply(colors.as_flattened_mut());
+        Palette{colors}
+    }
+    ///Palette from colors that are already premultiplied
+    pub fn from_premultiplied(colors:Vec<[u8;4]>) -> Self{
+        Palette{colors}
+    }
+    pub fn len(&self) -> usize{
+        self.colors.len()
+    }
+    pub fn is_empty(&self) -> bool{
+        self.colors.is_empty()
+    }
+    pub fn colors(&self) -> &[[u8;4]]{
+        &self.colors
+    }
+    ///Premultiplied color at index, indices past the end of the palette are transparent
+    pub fn color(&self, index:u8) -> &[u8;4]{
+        self.colors.get(index as usize).unwrap_or(&[0;4])
+    }
+    ///Replaces the color at index with a straight alpha color, does nothing past the end of the palette
+    pub fn set(&mut self, index:u8, color:[u8;4]){
+        if let Some(entry) = self.colors.get_mut(index as usize){
+            *entry = color;
+            premultiply(entry);
+        }
+    }
+    ///Cycles the colors in range by amount, positive amounts move every color up an index and wrap the last ones round to the start.
+    ///the range is cut down to the palette
+    pub fn rotate(&mut self, range:Range<usize>, amount:isize){
+        let range = range.start.min(self.colors.len())..range.end.min(self.colors.len());
+        let colors = &mut self.colors[range];
+        if colors.is_empty(){return;}
+        let amount = amount.rem_euclid(colors.len() as isize) as usize;
+        colors.rotate_right(amount);
+    }
+    ///Copy of the palette with every color replaced by a straight color, keeping each entrys own alpha so the sprite keeps its shape.
+    ///handy for hit flashes and silhouettes
+    pub fn filled(&self, color:[u8;3]) -> Self{
+        let mut filled = self.clone();
+        for entry in &mut filled.colors{
+            let [r, g, b] = color;
+            *entry = [r, g, b, entry[3]];
+            premultiply(entry);
+        }
+        filled
+    }
+}
+
+impl Sprite{
+    ///Builds an indexed sprite, one palette index per pixel in reading order. pixels count as solid, translucent or transparent
+    ///by their color in palette, so a palette swapped in later should keep transparent entries transparent
+    pub fn from_indices(indices:Vec<u8>, size:Size, palette:Palette) -> Self{
+        Sprite::from_shared_indices(indices, size, Arc::new(palette))
+    }
+    ///Same as from_indices but shares a palette that is already behind an arc, so sprites cut from one sheet keep one palette
+    pub fn from_shared_indices(indices:Vec<u8>, size:Size, palette:Arc<Palette>) -> Self{
+        assert_eq!(indices.len(), size.pixels(), "sprite indices dont match its size");
+        let alphas:Vec<u8> = indices.iter().map(|&index|palette.color(index)[3]).collect();
+        let (translucent_ranges, solid_ranges) = split_alpha_ranges(&alphas, size.width, 1);
+        let to_ranges = |ranges:Vec<RangeFields>|ranges.into_iter()
+            .map(|[line, src_index, src_end_index, x_begin, x_end]|PixelRange{line, src_index, src_end_index, x_begin, x_end})
+            .collect::<Vec<_>>();
+        Sprite{
+            data:indices.into(),
+            size,
+            translucent_ranges:to_ranges(translucent_ranges).into(),
+            solid_ranges:to_ranges(solid_ranges).into(),
+            palette:Some(palette),
+        }
+    }
+    ///True if data holds palette indices instead of rgba
+    pub fn is_indexed(&self) -> bool{
+        self.palette.is_some()
+    }
+    ///Bytes each pixel takes up in data
+    pub fn stride(&self) -> usize{
+        if self.is_indexed(){1}else{BPP}
+    }
+    ///Premultiplied rgba of the pixel at x, y. indexed sprites look it up in palette, or in their own palette if that is none
+    pub fn color_in<'a>(&'a self, x:usize, y:usize, palette:Option<&'a Palette>) -> &'a [u8]{
+        let index = (y*self.size.width + x)*self.stride();
+        match &self.palette{
+            Some(own) => palette.unwrap_or(own).color(self.data[index]),
+            None => &self.data[index..index + BPP],
+        }
+    }
+    ///Rgba copy of the sprite with every index looked up in palette, or in its own palette if that is none. rgba sprites just get cloned
+    pub fn resolved(&self, palette:Option<&Palette>) -> Sprite{
+        if !self.is_indexed(){return self.clone();}
+        let mut data = Vec::with_capacity(self.size.pixels()*BPP);
+        for y in 0..self.size.height{
+            for x in 0..self.size.width{
+                data.extend_from_slice(self.color_in(x, y, palette));
+            }
+        }
+        Sprite::from_rgba(data, self.size)
+    }
+}
@@ -1,320 +0,0 @@
-// ===============================
-//  PARTIAL_TRANSPARENCY_TEST_SPRITE.RS
-// -------------------------------
-//  generated from partial_transparency_test.png
-//  dimensions: 100x100
-//  place in sprites folder and declare in sprites mod.rs to use
-//  DO NOT EDIT MANUALLY
-// -------------------------------
-
-use crate::{graphics::sprites::Sprite, shared::Size};
-
-pub const PARTIAL_TRANSPARENCY_TEST_SPRITE:Sprite = Sprite{data:&PARTIAL_TRANSPARENCY_TEST_SPRITE_DATA,size: Size{width:100,height:100},
-translucent_ranges:&PARTIAL_TRANSPARENCY_TEST_TRANSLUCENT, solid_ranges:&PARTIAL_TRANSPARENCY_TEST_SOLID};
-
-const PARTIAL_TRANSPARENCY_TEST_SPRITE_DATA:[u8;40000]= [95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,94,95,94,95,94,95,94,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,93,95,93,95,89,94,89,95,87,94,87,95,86,94,86,95,87,94,87,95,89,94,89,95,91,95,91,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,94,95,94,95,93,95,93,95,93,94,93,95,93,94,93,95,92,93,92,95,91,92,91,95,90,92,90,95,91,92,91,95,92,93,92,95,93,94,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,89,94,89,95,80,93,80,95,71,92,70,95,65,92,64,95,62,91,61,95,65,92,64,95,70,92,69,95,76,93,76,95,82,94,82,95,88,94,88,95,92,95,92,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,93,95,93,95,90,94,90,95,86,94,86,95,83,93,83,95,81,92,81,95,78,91,77,95,75,88,75,95,70,85,70,95,68,83,67,95,65,83,65,95,67,83,67,95,71,84,71,95,78,86,77,95,85,89,85,95,91,92,90,95,94,94,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,91,95,91,95,83,94,82,95,68,92,67,95,49,91,47,95,38,90,34,95,30,89,25,95,28,89,23,95,29,89,23,95,33,89,29,95,41,90,38,95,50,91,48,95,61,91,60,95,74,92,73,95,85,94,85,95,92,95,92,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,91,94,91,95,83,94,83,95,73,92,73,95,65,91,64,95,56,90,54,95,50,89,48,95,42,87,39,95,36,86,33,95,30,86,26,95,28,85,23,95,26,85,20,95,27,85,22,95,31,83,27,95,39,81,37,95,52,80,51,95,67,82,66,95,81,88,81,95,89,92,89,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,91,95,91,95,85,94,84,95,73,92,73,95,53,91,52,95,33,89,28,95,22,89,13,95,19,89,6,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,19,89,6,95,20,89,10,95,25,89,18,95,36,90,32,95,55,91,53,95,75,92,74,95,88,94,88,95,94,95,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,90,94,89,95,79,93,79,95,62,91,61,95,47,90,44,95,33,89,28,95,25,89,19,95,21,89,12,95,19,89,7,95,18,89,5,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,88,6,95,20,86,12,95,29,82,25,95,47,80,46,95,67,84,66,95,83,91,83,95,91,94,91,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,88,94,88,95,78,93,78,95,63,91,61,95,42,90,39,95,24,89,17,95,19,89,6,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,5,95,22,89,13,95,38,90,35,95,64,92,63,95,83,94,83,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,91,95,91,95,80,93,79,95,58,91,56,95,37,89,33,95,22,89,14,95,19,89,6,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,88,4,95,20,86,10,95,33,81,29,95,56,83,54,95,77,89,77,95,90,94,90,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,88,94,88,95,75,92,74,95,55,91,53,95,35,90,31,95,20,89,11,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,19,89,6,95,29,89,24,95,56,91,54,95,80,93,80,95,91,95,91,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,94,95,94,95,85,94,84,95,64,91,63,95,38,89,35,95,20,89,11,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,88,5,95,25,83,20,95,50,83,48,95,75,89,75,95,90,94,90,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,88,94,88,95,73,92,73,95,54,91,52,95,32,89,28,95,20,89,9,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,25,89,18,95,52,91,50,95,79,93,79,95,91,95,91,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,90,94,90,95,75,92,74,95,48,90,45,95,23,89,16,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,88,4,95,25,84,20,95,50,84,49,95,77,90,76,95,91,94,90,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,89,94,89,95,76,93,75,95,54,91,53,95,34,90,30,95,20,89,10,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,24,89,17,95,52,91,50,95,80,93,80,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,94,95,94,95,86,94,85,95,63,90,61,95,31,89,27,95,19,89,6,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,88,5,95,29,85,25,95,56,87,55,95,80,92,79,95,91,95,91,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,92,95,92,95,81,93,80,95,60,91,59,95,36,90,32,95,21,89,12,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,26,89,19,95,56,91,54,95,83,94,83,95,94,95,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,92,95,92,95,79,92,79,95,50,88,47,95,23,88,15,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,19,88,9,95,37,87,33,95,60,90,58,95,81,93,80,95,91,95,91,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,88,94,88,95,72,92,71,95,47,90,45,95,24,89,17,95,18,89,5,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,30,89,25,95,64,92,62,95,88,94,88,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,91,94,91,95,72,90,71,95,39,87,36,95,19,89,7,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,23,89,15,95,41,89,38,95,64,91,63,95,83,94,83,95,93,95,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,85,94,84,95,64,92,63,95,36,90,33,95,20,89,9,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,19,89,6,95,39,90,36,95,74,92,73,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,89,93,89,95,65,87,64,95,31,86,27,95,18,89,5,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,5,95,26,89,20,95,46,90,44,95,71,92,70,95,88,94,88,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,93,95,93,95,82,94,82,95,59,91,58,95,31,89,26,95,19,89,6,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,22,89,14,95,54,91,53,95,85,94,85,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,88,92,88,95,61,85,60,95,27,86,22,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,20,89,9,95,33,89,28,95,58,91,56,95,80,93,80,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,93,95,92,95,81,93,81,95,57,91,56,95,28,89,22,95,18,89,5,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,19,89,6,95,35,90,31,95,72,92,71,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,87,91,87,95,58,82,57,95,26,85,20,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,25,89,18,95,46,90,44,95,72,92,71,95,89,94,89,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,93,95,93,95,82,94,82,95,58,91,56,95,28,89,22,95,18,89,5,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,24,89,17,95,56,91,54,95,86,94,85,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,86,89,86,95,58,79,58,95,25,83,20,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,21,89,11,95,37,90,34,95,63,91,62,95,84,94,84,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,93,95,93,95,84,94,83,95,61,91,59,95,31,89,26,95,19,89,6,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,19,89,8,95,42,90,39,95,76,93,76,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-94,94,94,95,86,88,86,95,60,76,59,95,26,81,21,95,18,88,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,19,89,7,95,30,89,25,95,55,91,53,95,78,93,78,95,92,95,91,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,87,94,86,95,66,92,65,95,36,90,32,95,19,89,8,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,5,95,31,89,26,95,65,92,64,95,89,94,89,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-94,94,94,95,87,88,87,95,62,75,62,95,27,79,23,95,18,88,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,5,95,25,89,18,95,47,90,44,95,72,92,71,95,89,94,88,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,90,94,90,95,73,92,72,95,44,90,42,95,22,89,13,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,25,89,18,95,56,91,54,95,84,94,84,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-94,94,94,95,88,88,88,95,64,75,64,95,30,77,26,95,18,88,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,24,89,5,96,72,94,12,121,72,94,12,121,72,94,12,121,73,94,12,121,66,94,11,117,47,92,7,106,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,57,93,15,111,87,96,40,127,76,93,66,103,85,94,85,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,96,95,95,96,118,101,101,121,118,101,101,121,118,101,101,121,119,101,101,121,115,100,100,117,107,98,98,108,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,114,100,101,116,118,101,101,121,118,101,101,121,118,101,101,121,118,101,101,121,117,100,99,121,107,98,86,121,87,96,56,121,74,94,26,121,72,94,12,121,69,94,12,119,34,90,5,100,72,94,12,121,72,94,12,121,72,94,12,121,72,94,12,121,71,94,12,120,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,56,93,9,111,80,95,14,127,42,91,7,103,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,21,89,12,95,48,90,46,95,80,93,79,95,93,95,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,88,90,88,95,67,79,66,95,34,78,31,95,18,87,6,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,36,91,6,101,159,88,29,188,159,88,29,188,159,88,29,188,159,88,29,188,159,88,29,188,159,88,29,188,143,91,25,175,82,95,15,128,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,131,94,24,165,161,88,40,188,119,98,67,144,83,94,83,95,93,95,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,170,96,100,178,130,102,102,134,95,95,95,95,95,95,95,95,161,99,101,169,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,178,94,97,188,175,93,90,188,168,91,70,188,161,88,43,188,159,88,30,188,149,90,26,180,54,93,9,110,150,90,27,181,159,88,29,188,159,88,29,188,159,88,29,188,141,92,25,173,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,131,94,23,165,159,88,29,188,104,95,18,144,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,19,89,8,95,42,90,39,95,76,93,75,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,89,92,89,95,70,83,69,95,38,80,36,95,19,87,8,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,36,91,6,101,159,88,29,188,137,93,25,170,71,94,12,120,71,94,12,120,79,95,13,126,106,95,18,146,154,89,27,184,159,88,29,188,82,95,15,128,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,51,92,8,108,159,88,29,188,153,90,37,182,156,93,65,179,84,94,82,96,93,95,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,162,99,101,170,118,101,101,121,118,101,101,121,123,101,102,126,138,102,103,142,171,97,100,180,179,94,97,188,131,102,102,135,95,95,95,95,115,100,100,117,120,101,102,123,120,101,102,123,120,101,102,123,140,102,103,145,179,94,97,188,144,101,99,152,106,99,82,123,88,96,53,123,76,95,23,123,71,94,13,120,18,89,3,95,20,89,3,95,108,95,19,147,159,88,29,188,80,95,14,126,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,51,92,8,108,159,88,29,188,151,89,27,182,147,91,27,179,23,89,5,96,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,19,89,7,95,39,90,35,95,73,92,73,95,92,95,91,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,91,94,91,95,74,88,73,95,44,83,42,95,20,88,12,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,36,91,6,101,159,88,29,188,128,93,22,163,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,69,94,12,119,159,88,29,188,142,91,26,174,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,104,95,18,144,159,88,29,188,98,96,36,136,167,90,65,188,111,99,89,123,94,95,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,107,98,98,108,177,94,98,186,170,97,100,178,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,124,101,102,127,179,94,97,188,132,102,101,137,85,94,85,95,66,92,64,95,38,90,34,95,20,89,10,95,18,89,4,95,18,89,3,95,103,95,18,143,159,88,29,188,72,94,12,121,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,104,95,18,144,159,88,29,188,94,95,17,136,159,88,29,188,75,95,13,123,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,19,89,6,95,38,90,35,95,73,92,72,95,91,95,91,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,92,94,92,95,79,91,78,95,52,86,50,95,25,88,18,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,36,91,6,101,159,88,29,188,128,93,22,163,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,145,92,26,177,159,88,29,188,30,90,5,99,18,89,3,95,18,89,3,95,24,89,5,96,147,91,27,179,134,94,27,168,49,91,39,99,164,92,71,184,148,99,94,159,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,164,98,101,172,179,94,97,188,99,96,96,99,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,124,101,102,127,179,94,97,188,133,102,102,137,91,94,91,95,77,93,77,95,55,91,53,95,29,89,24,95,19,89,6,95,18,89,3,95,103,95,18,143,159,88,29,188,72,94,12,121,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,24,89,5,96,147,91,27,179,134,93,24,168,32,90,5,99,154,89,27,184,123,94,22,159,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,19,89,7,95,40,90,37,95,75,92,75,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,94,95,94,95,85,93,85,95,62,89,61,95,34,88,30,95,19,89,8,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,36,91,6,101,159,88,29,188,128,93,22,163,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,141,92,25,173,159,88,29,188,29,89,4,97,18,89,3,95,18,89,3,95,76,95,14,124,159,88,30,188,94,96,32,134,55,91,54,95,137,98,82,155,174,93,93,186,102,97,97,104,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,170,97,100,178,177,95,98,187,96,95,95,96,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,124,101,102,127,179,94,97,188,133,102,103,137,94,95,94,95,87,94,87,95,70,92,69,95,45,90,43,95,23,89,16,95,18,89,4,95,103,95,18,143,159,88,29,188,72,94,12,121,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,76,95,14,124,159,88,29,188,90,95,15,134,18,89,3,95,119,95,21,155,156,88,28,186,42,91,7,104,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,20,89,10,95,46,90,44,95,79,93,79,95,93,95,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,90,94,90,95,74,92,73,95,50,89,48,95,27,88,22,95,19,89,7,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,36,91,6,101,159,88,29,188,128,93,22,163,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,56,93,10,111,158,88,29,187,135,93,25,169,18,89,3,95,18,89,4,95,19,89,6,95,125,95,26,160,158,89,38,186,59,92,48,101,70,92,69,95,112,100,92,121,178,94,97,188,134,102,103,138,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,121,101,102,124,179,94,97,188,155,100,102,161,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,124,101,102,127,179,94,97,188,133,102,103,137,95,95,95,95,93,95,93,95,83,94,83,95,60,91,59,95,34,90,30,95,19,89,8,95,103,95,18,143,159,88,29,188,72,94,12,121,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,124,94,23,160,156,88,28,186,38,90,6,101,18,89,3,95,73,94,12,121,159,88,29,188,96,95,17,138,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,24,89,17,95,54,91,53,95,85,94,85,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,94,95,94,95,86,94,85,95,68,91,67,95,45,89,43,95,29,89,23,95,20,89,10,95,18,89,6,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,36,91,6,101,159,88,29,188,131,94,23,166,42,91,7,104,45,91,8,105,60,93,10,113,93,95,15,136,149,90,26,180,159,88,29,188,73,94,13,122,18,89,4,95,20,89,9,95,47,92,22,104,159,89,44,186,132,97,63,158,72,92,71,95,85,94,85,95,93,95,93,96,170,96,99,178,166,98,100,174,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,166,98,101,174,130,101,102,134,130,101,102,134,135,102,103,140,151,101,103,158,177,94,98,187,167,97,100,175,105,98,98,106,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,124,101,102,127,179,94,97,188,133,102,103,137,95,95,95,95,95,95,95,95,91,94,90,95,74,92,73,95,45,90,42,95,23,89,15,95,103,95,19,143,159,88,29,188,72,94,12,121,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,43,91,7,104,156,88,28,186,121,95,22,158,18,89,3,95,18,89,3,95,21,90,3,96,147,90,27,178,142,91,26,174,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,19,89,6,95,32,89,28,95,65,92,64,95,89,94,89,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,92,95,92,95,85,94,84,95,69,92,68,95,53,90,52,95,38,89,35,95,29,88,23,95,23,89,15,95,20,89,10,95,19,89,7,95,19,89,6,95,18,89,5,95,36,91,7,101,159,88,29,188,159,88,29,188,159,88,29,188,159,88,29,188,159,88,29,188,159,88,29,188,145,92,26,177,77,94,13,124,18,89,4,95,19,89,8,95,29,89,23,95,105,96,49,139,167,91,68,188,108,99,84,124,89,94,88,96,93,94,92,96,96,95,94,96,141,102,103,146,179,94,97,188,115,100,100,117,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,143,102,103,148,98,96,96,98,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,124,101,102,127,179,94,97,188,133,102,103,137,95,95,95,95,95,95,95,95,93,95,93,95,80,93,80,95,55,91,54,95,28,89,22,95,103,95,19,143,159,88,29,188,72,94,12,121,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,97,95,17,139,159,88,29,188,77,94,13,124,24,89,5,96,24,89,5,96,24,89,5,96,106,95,18,146,159,88,29,188,67,93,11,117,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,20,89,10,95,43,90,41,95,73,92,72,95,91,94,91,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,92,95,92,95,86,94,86,95,77,92,76,95,67,91,66,95,57,90,56,95,50,89,48,95,45,89,43,95,41,88,38,95,36,89,32,95,32,89,28,95,42,91,24,101,160,88,34,188,141,92,29,174,93,95,19,136,92,95,18,135,84,95,15,129,62,93,11,114,23,89,6,96,18,89,5,95,20,89,10,95,28,89,23,95,47,90,44,95,154,95,71,175,174,92,86,188,178,94,94,188,178,94,97,188,178,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,148,101,103,154,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,159,100,102,166,103,97,98,104,103,97,98,104,115,100,100,117,177,94,98,187,159,100,102,166,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,124,101,102,127,179,94,97,188,133,102,103,137,95,95,95,95,95,95,95,95,93,95,93,95,83,94,83,95,61,91,60,95,33,89,29,95,103,95,20,143,159,88,29,188,72,94,12,121,18,89,4,95,19,89,6,95,19,89,8,95,21,89,8,95,143,92,27,175,159,88,29,188,159,88,29,188,159,88,29,188,159,88,29,188,159,88,30,188,159,88,30,188,159,88,29,188,117,95,21,154,18,89,4,95,18,89,3,95,18,89,3,95,18,89,5,95,25,89,18,95,52,91,50,95,77,93,77,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,91,94,91,95,87,94,87,95,82,93,81,95,78,92,77,95,75,92,74,95,72,92,72,95,69,91,68,95,64,91,64,95,67,93,59,101,164,89,55,188,134,95,49,163,38,90,35,95,32,89,28,95,28,89,22,95,25,89,18,95,25,89,18,95,27,89,22,95,35,90,32,95,49,91,47,95,93,96,70,118,175,92,87,188,158,100,100,166,143,102,103,149,144,102,103,149,144,102,103,149,144,102,103,149,144,102,103,149,170,97,100,178,175,95,98,184,100,96,96,100,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,145,102,103,151,179,94,97,188,127,102,103,131,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,124,101,102,127,179,94,97,188,133,102,103,137,95,95,95,95,95,95,95,95,94,95,94,95,85,94,85,95,65,92,64,95,38,90,35,95,103,95,21,143,159,88,29,188,72,94,12,121,18,89,5,95,20,89,10,95,28,89,22,95,75,94,36,118,162,88,45,188,135,94,45,166,116,96,44,149,116,96,46,149,117,96,48,149,117,96,50,149,116,96,46,149,148,91,36,178,154,89,30,184,35,90,7,100,18,89,4,95,18,89,4,95,19,89,8,95,31,89,26,95,58,91,57,95,81,93,81,95,93,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,93,95,93,95,92,95,92,95,91,95,91,95,91,94,91,95,90,94,90,95,88,94,88,95,90,95,86,101,173,92,81,188,144,97,77,163,66,92,65,95,59,91,58,95,54,91,53,95,51,91,49,95,51,91,49,95,54,91,52,95,62,91,60,95,73,92,72,95,143,100,92,155,178,94,94,188,108,99,99,110,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,134,102,103,138,179,94,97,188,129,102,103,133,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,102,97,97,102,173,96,99,182,172,96,99,181,102,97,97,102,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,124,101,102,127,179,94,97,188,133,102,103,137,95,95,95,95,95,95,95,95,94,95,94,95,88,94,87,95,69,92,68,95,44,90,41,95,104,96,24,143,159,88,29,188,72,94,12,121,18,89,4,95,21,89,11,95,32,89,28,95,126,97,55,155,168,91,70,188,91,97,78,110,79,93,78,95,80,93,80,95,81,93,81,95,75,93,75,95,59,91,58,95,101,96,41,138,160,88,32,188,89,95,16,133,18,89,4,95,18,89,4,95,21,89,12,95,38,90,35,95,63,92,62,95,85,94,84,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,99,97,96,101,178,94,94,188,152,99,95,163,86,94,85,95,82,94,82,95,78,93,78,95,77,93,76,95,76,93,75,95,79,93,78,95,83,94,83,95,95,96,91,101,176,95,96,185,160,100,101,167,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,103,97,97,103,177,95,98,186,161,99,101,169,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,130,101,102,134,179,94,97,188,145,101,102,151,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,124,101,102,127,179,94,97,188,133,102,103,137,95,95,95,95,95,95,95,95,95,95,95,95,90,94,90,95,75,92,74,95,50,91,48,95,104,96,27,143,159,88,29,188,72,94,12,121,18,89,4,95,19,89,7,95,41,91,21,101,159,90,49,185,147,96,73,167,84,94,84,95,91,95,91,95,93,95,93,95,91,94,91,95,79,93,79,95,57,91,55,95,51,92,32,103,157,88,30,186,135,93,24,169,18,89,4,95,18,89,5,95,24,89,16,95,45,90,42,95,70,92,69,95,88,94,88,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,155,100,101,163,94,95,94,95,93,95,93,95,92,95,92,95,92,95,91,95,91,95,91,95,92,95,92,95,93,95,93,95,130,102,102,134,179,94,97,188,127,102,102,131,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,151,100,102,158,179,94,97,188,110,99,100,112,95,95,95,95,101,97,97,101,179,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,96,95,95,96,163,99,101,171,178,94,98,188,114,100,100,116,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,124,101,102,127,179,94,97,188,133,102,103,137,95,95,95,95,95,95,95,95,95,95,95,95,92,95,92,95,81,93,80,96,80,95,58,113,125,94,35,159,159,88,29,188,104,95,18,144,52,92,8,108,18,89,4,95,91,95,19,134,161,88,39,188,98,97,53,131,69,92,68,95,81,93,80,95,84,94,84,95,79,93,79,95,63,91,62,95,42,90,39,95,26,89,19,95,122,94,22,158,159,88,29,188,58,93,10,112,19,89,7,95,29,89,23,95,53,91,51,95,77,93,76,95,91,95,91,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,162,99,101,170,175,95,98,184,99,96,96,99,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,119,101,101,122,179,94,97,188,143,102,103,148,95,95,95,95,101,97,97,101,179,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,116,100,100,118,179,94,97,188,163,99,101,171,96,95,95,96,95,95,95,95,95,95,95,95,95,95,95,95,124,101,102,127,179,94,97,188,133,102,103,137,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,104,98,90,114,168,91,69,188,161,88,43,188,159,88,31,188,159,88,29,188,154,89,27,184,18,89,3,95,137,93,25,170,154,89,30,184,42,90,30,99,46,90,43,95,57,91,55,95,60,91,59,95,54,91,52,95,40,90,37,95,27,89,22,95,19,89,8,95,73,94,13,122,159,88,29,188,110,95,19,148,21,89,11,95,36,90,32,95,61,91,60,95,83,94,83,95,93,95,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,90,94,90,95,74,92,73,95,52,91,50,95,28,89,22,95,19,89,7,95,18,89,4,95,18,89,3,95,18,89,4,95,19,89,6,95,21,89,12,95,27,89,21,95,32,89,28,95,34,90,30,95,30,89,25,95,24,89,16,95,19,89,8,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,5,95,25,89,18,95,45,90,43,95,71,92,70,95,88,94,88,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,85,94,85,95,64,92,63,95,42,90,39,95,25,89,19,95,19,89,7,95,18,89,4,95,18,89,3,95,18,89,4,95,18,89,5,95,19,89,7,95,20,89,10,95,20,89,10,95,20,89,9,95,19,89,6,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,4,95,20,89,10,95,33,89,29,95,58,91,57,95,79,93,79,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,92,95,91,95,81,93,80,95,60,91,59,95,43,90,40,95,27,89,21,95,20,89,10,95,18,89,5,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,4,95,19,89,7,95,28,89,22,95,47,90,45,95,71,92,70,95,88,94,88,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,91,94,91,95,80,93,80,95,62,91,61,95,47,90,44,95,31,89,27,95,23,89,15,95,19,89,8,95,18,89,5,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,19,89,7,95,26,89,19,95,43,90,40,95,63,91,62,95,83,94,83,95,93,95,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,91,95,91,95,83,94,82,95,68,92,67,95,54,91,52,95,39,90,37,95,29,89,24,95,23,89,16,95,20,89,11,95,19,89,8,95,19,89,6,95,19,89,6,95,19,89,7,95,20,89,9,95,22,89,13,95,28,89,23,95,43,90,40,95,61,91,60,95,81,93,81,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,92,95,92,95,86,94,86,95,75,92,75,95,63,91,62,95,52,91,50,95,42,90,40,95,35,90,31,95,30,89,25,95,28,89,22,95,27,89,21,95,29,89,23,95,34,90,29,95,41,90,38,95,53,91,51,95,66,92,65,95,82,93,81,95,91,95,91,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,89,94,89,95,83,94,82,95,75,93,75,95,68,92,67,95,61,91,60,95,57,91,55,95,54,91,53,95,54,91,52,95,56,91,54,95,60,91,58,95,68,92,67,95,76,93,75,95,85,94,85,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,93,95,93,95,90,94,90,95,86,94,86,95,83,94,82,95,80,93,79,95,78,93,77,95,78,93,77,95,80,93,79,95,83,94,82,95,87,94,86,95,91,94,91,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,93,95,93,95,92,95,92,95,92,95,91,95,92,95,92,95,92,95,92,95,93,95,93,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,94,95,94,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,92,95,91,95,89,94,89,95,89,94,89,95,89,94,89,95,91,95,91,95,93,95,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,93,95,93,95,90,94,90,95,85,94,85,95,79,93,78,95,75,92,75,95,73,92,72,95,75,92,75,95,79,93,79,95,85,94,85,95,91,94,91,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,90,94,90,95,84,94,83,95,75,92,74,95,65,92,64,95,56,91,54,95,50,91,48,95,48,90,45,95,50,91,48,95,57,91,56,95,66,92,65,95,76,93,76,95,85,94,85,95,91,94,91,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,114,100,101,116,118,101,101,121,118,101,101,121,118,101,101,121,118,101,101,121,118,101,101,121,118,101,101,121,118,101,101,121,118,101,101,121,118,101,101,121,117,100,101,119,95,95,95,95,96,95,95,96,118,101,100,121,115,100,97,121,110,99,90,121,102,98,79,121,89,96,66,117,69,94,52,108,40,90,37,95,31,89,26,95,26,89,19,95,24,89,16,95,26,89,19,95,32,89,27,95,41,90,38,95,52,91,50,95,64,92,63,95,92,97,78,111,115,100,90,127,97,96,90,103,91,95,91,95,93,95,93,95,94,95,94,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,102,97,97,102,124,102,102,127,133,102,103,137,129,102,103,133,120,100,101,123,103,97,97,103,95,95,95,95,95,95,95,95,95,95,95,95,96,95,95,96,118,101,101,121,118,101,101,121,118,101,101,121,119,101,101,121,115,100,100,117,105,98,98,106,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,109,99,99,111,124,101,102,127,102,97,97,103,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,161,99,101,169,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,171,97,100,180,95,95,95,95,99,96,95,101,175,93,91,188,172,91,81,188,167,91,66,188,164,89,54,188,161,88,43,188,160,88,36,188,148,91,30,178,91,95,17,134,18,89,5,95,18,89,4,95,18,89,6,95,20,89,9,95,23,89,15,95,30,89,25,95,39,90,36,95,137,95,54,165,167,90,64,188,123,98,76,144,77,93,76,95,82,93,81,95,85,94,85,95,88,94,88,95,89,94,89,95,91,94,91,95,92,95,91,95,132,101,100,138,176,94,96,185,178,94,97,188,178,94,97,188,178,94,97,188,179,94,97,188,177,94,98,187,134,102,103,139,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,167,97,100,175,124,101,102,128,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,158,100,102,165,179,94,97,188,139,102,103,144,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,115,100,100,117,120,101,102,123,120,101,102,123,120,101,102,123,140,102,103,145,179,94,97,188,146,101,103,152,120,101,102,123,120,101,102,123,120,101,102,123,117,101,100,120,91,95,91,95,90,95,86,101,170,91,73,188,144,94,57,170,80,95,40,121,75,94,27,121,80,95,19,126,102,95,19,142,149,90,27,180,159,88,29,188,92,95,16,135,18,89,4,95,18,89,4,95,18,89,4,95,18,89,5,95,19,89,7,95,53,92,15,108,159,88,35,188,153,91,40,182,152,92,49,179,55,91,51,96,59,91,57,95,64,92,63,95,69,92,67,95,71,92,70,95,75,92,74,95,109,99,82,127,173,92,83,188,158,97,88,172,110,99,91,121,99,97,91,106,108,98,95,113,122,101,100,127,148,101,102,155,115,100,100,118,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,162,99,101,170,118,101,101,120,118,100,101,120,123,101,102,126,141,102,103,146,175,96,99,184,179,94,97,188,124,101,102,128,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,107,98,98,108,178,94,98,188,173,96,98,182,170,97,100,179,96,95,95,96,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,124,101,102,127,179,94,97,188,133,102,103,137,95,95,95,95,95,95,95,95,92,95,92,95,87,94,87,95,78,93,77,95,71,93,63,101,163,89,50,188,130,94,35,163,22,89,14,95,19,89,7,95,18,89,4,95,18,89,4,95,52,92,8,108,156,88,28,186,147,91,26,178,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,104,95,18,144,159,88,29,188,94,95,20,136,160,88,32,188,78,95,27,123,33,89,28,95,38,90,34,95,42,90,39,95,46,90,44,95,49,91,47,95,133,96,57,161,165,90,60,188,79,95,62,110,66,92,65,95,71,92,70,95,76,93,76,95,82,94,82,95,87,94,87,95,91,95,91,95,94,95,94,95,95,95,95,95,101,97,97,101,179,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,117,100,101,119,179,94,97,188,166,98,100,174,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,139,102,103,144,178,94,98,188,132,101,102,136,179,94,97,188,120,101,101,123,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,124,101,102,127,179,94,97,188,133,102,102,137,93,95,93,95,88,94,88,95,80,93,79,95,67,92,66,95,53,91,51,95,50,91,37,101,160,88,35,188,128,93,24,163,18,89,4,95,18,89,4,95,18,89,3,95,18,89,4,95,18,89,4,95,139,92,25,172,159,88,29,188,30,90,8,99,18,89,5,95,18,89,4,95,18,89,3,95,24,89,5,96,147,91,27,179,134,93,24,168,32,90,6,99,154,89,27,184,123,94,23,159,20,89,9,95,21,89,12,95,22,89,14,95,24,89,16,95,25,89,18,95,131,94,32,164,161,88,37,188,45,91,31,100,39,90,37,95,46,90,43,95,53,91,51,95,61,91,59,95,69,92,68,95,77,93,77,95,85,94,84,95,89,94,89,95,98,96,94,101,178,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,169,98,101,177,179,94,97,188,99,96,96,99,95,95,95,95,95,95,95,95,96,95,95,96,170,97,100,179,160,99,101,168,99,96,96,99,175,95,98,184,152,100,102,159,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,123,101,101,127,178,94,95,188,128,101,96,137,82,93,81,95,70,92,69,95,55,91,53,95,42,90,39,95,31,89,26,95,39,91,16,101,159,88,29,188,128,93,23,163,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,19,89,8,95,147,91,28,178,158,88,29,187,24,89,9,96,19,89,6,95,18,89,5,95,18,89,4,95,76,95,14,124,159,88,29,188,90,95,15,134,18,89,4,95,119,95,21,155,156,88,28,186,42,91,7,104,18,89,4,95,18,89,5,95,18,89,5,95,18,89,5,95,110,95,20,148,159,88,29,188,96,95,19,137,22,89,13,95,24,89,17,95,29,89,23,95,35,90,31,95,44,90,42,95,54,91,53,95,65,92,64,95,74,92,73,95,88,95,83,101,176,93,91,188,154,100,99,163,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,166,98,100,173,178,94,98,188,97,95,95,97,95,95,95,95,95,95,95,95,121,101,102,124,179,94,97,188,130,102,102,134,95,95,95,95,149,101,103,155,177,94,98,186,103,97,98,104,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,93,95,93,95,118,100,94,127,173,92,83,188,116,98,76,137,59,91,57,95,44,90,41,95,31,89,26,95,23,89,15,95,19,89,7,95,36,91,7,101,159,88,29,188,128,93,22,163,18,89,3,95,18,89,4,95,18,89,5,95,20,89,10,95,78,95,22,124,160,88,32,188,126,94,23,161,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,124,94,23,160,156,88,28,186,38,90,6,101,18,89,4,95,73,94,12,121,159,88,29,188,96,95,17,138,18,89,4,95,18,89,4,95,18,89,4,95,18,89,3,95,44,92,8,105,151,89,27,182,159,88,29,188,119,94,21,156,60,93,11,113,19,89,7,95,20,89,11,95,25,89,18,95,31,89,26,95,40,90,37,95,50,91,48,95,69,93,61,101,170,91,74,188,148,98,87,163,89,94,89,95,93,95,93,95,95,95,95,95,95,95,95,95,109,99,99,111,177,94,98,187,161,99,102,169,95,95,95,95,95,95,95,95,95,95,95,95,153,101,103,160,177,95,98,186,101,96,96,101,95,95,95,95,119,101,101,121,179,94,97,188,134,102,103,138,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,91,94,91,95,83,94,83,95,104,98,74,127,166,90,60,188,102,96,47,137,34,90,30,95,25,89,18,95,19,89,9,95,18,89,4,95,18,89,4,95,36,91,6,101,159,88,29,188,141,92,25,174,91,95,16,134,91,95,18,134,99,96,23,140,123,95,31,158,158,88,35,187,143,91,27,175,47,91,8,106,18,89,3,95,18,89,3,95,18,89,3,95,43,91,7,104,156,88,28,186,121,95,22,158,18,89,3,95,18,89,4,95,21,90,4,96,147,90,27,178,142,91,26,174,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,51,92,8,108,132,93,24,166,159,88,29,188,159,88,29,188,135,93,25,169,82,95,15,128,22,90,6,96,20,89,9,95,23,89,15,95,28,89,23,95,48,91,34,101,163,89,52,188,142,96,65,166,82,95,75,104,94,96,86,105,108,99,95,113,131,102,102,136,171,97,100,180,178,94,98,188,119,101,101,122,95,95,95,95,95,95,95,95,103,97,97,104,177,94,98,186,151,101,103,158,95,95,95,95,95,95,95,95,96,96,96,96,170,96,100,178,166,98,100,174,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,92,95,92,95,82,93,81,95,63,91,62,95,90,96,48,127,161,88,38,188,96,96,24,137,20,89,10,95,19,89,6,95,18,89,4,95,18,89,3,95,18,89,3,95,36,91,6,101,159,88,29,188,159,88,29,188,159,88,31,188,160,88,35,188,161,88,39,188,161,88,38,188,111,95,27,148,28,90,7,98,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,97,95,17,139,159,88,29,188,77,94,13,124,24,89,5,96,24,89,5,96,24,89,5,96,106,95,18,146,159,88,29,188,67,93,11,117,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,18,89,4,95,18,89,4,95,66,94,11,117,119,94,21,155,155,89,28,185,159,88,29,188,131,93,23,165,37,90,7,101,18,89,5,95,19,89,7,95,38,91,14,101,160,88,35,188,161,88,43,188,164,89,55,188,167,91,67,188,172,91,80,188,175,93,89,188,167,97,98,177,121,101,101,124,95,95,95,95,95,95,95,95,95,95,95,95,134,102,103,139,179,94,97,188,121,101,101,124,96,95,95,96,96,95,95,96,96,95,95,96,141,102,103,146,179,94,97,188,115,100,100,117,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,86,94,86,95,67,92,66,95,43,90,40,95,82,95,24,127,159,88,29,188,95,95,17,137,18,89,4,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,36,91,7,101,159,88,29,188,132,94,28,166,47,92,24,104,49,92,29,104,71,94,31,117,158,88,34,187,133,93,25,166,20,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,20,89,3,95,143,92,26,175,159,88,29,188,159,88,29,188,159,88,29,188,159,88,29,188,159,88,29,188,159,88,29,188,159,88,29,188,117,95,21,154,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,18,89,4,95,45,91,8,105,126,94,22,161,159,88,29,188,119,95,21,155,18,89,4,95,18,89,4,95,36,91,7,101,159,88,29,188,141,92,28,174,95,96,29,136,97,96,42,135,96,97,56,129,89,96,69,114,81,93,80,96,89,94,89,95,94,95,94,95,95,95,95,95,95,95,95,95,167,98,101,175,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,148,101,103,154,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,92,95,92,95,79,93,78,95,58,91,56,95,29,89,24,95,81,95,15,127,159,88,29,188,95,95,16,137,18,89,3,95,18,89,3,95,18,89,4,95,18,89,4,95,19,89,6,95,38,91,12,101,160,88,32,188,129,94,31,163,28,89,22,95,26,89,20,95,23,89,15,95,113,95,21,151,159,88,29,188,86,95,15,131,18,89,3,95,18,89,3,95,18,89,3,95,68,93,12,118,159,88,29,188,131,94,23,166,110,95,20,149,110,95,20,149,110,95,20,149,110,95,20,149,110,95,20,149,147,91,26,178,154,89,27,184,34,90,5,100,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,29,89,8,97,148,90,28,180,155,89,28,185,18,89,5,95,18,89,4,95,36,91,6,101,159,88,29,188,128,93,23,163,19,89,8,95,23,89,16,95,31,89,26,95,42,90,40,95,57,91,55,95,73,92,73,95,86,94,85,95,92,95,92,95,115,100,99,118,179,94,97,188,159,100,102,166,144,102,103,149,144,102,103,149,144,102,103,149,144,102,103,149,144,102,103,149,170,97,100,178,175,95,98,184,100,96,96,100,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,91,95,91,95,75,92,75,95,47,90,45,95,23,89,15,95,80,95,14,127,159,88,29,188,95,95,16,137,18,89,3,95,18,89,4,95,19,89,6,95,20,89,10,95,23,89,15,95,40,91,18,101,160,88,32,188,128,94,26,163,20,89,10,95,19,89,7,95,18,89,5,95,40,91,6,102,151,90,27,182,150,90,27,181,39,91,6,102,18,89,3,95,18,89,3,95,119,95,21,155,159,88,29,188,54,93,9,110,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,96,96,16,138,159,88,29,188,89,95,16,133,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,19,89,6,95,137,93,26,170,158,88,29,187,24,89,10,96,19,89,6,95,36,91,7,101,159,88,29,188,128,93,22,163,18,89,4,95,18,89,5,95,19,89,8,95,23,89,15,95,33,89,29,95,49,91,47,95,67,92,66,95,81,93,81,95,145,101,96,155,178,94,95,188,107,99,98,110,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,134,102,103,138,179,94,97,188,129,102,103,133,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,88,94,88,95,69,92,68,95,44,90,41,95,22,89,13,95,80,95,14,127,159,88,29,188,95,95,16,137,18,89,3,95,18,89,4,95,19,89,6,95,20,89,10,95,21,89,12,95,37,91,11,101,159,88,29,188,128,93,23,163,18,89,4,95,18,89,4,95,18,89,3,95,18,89,4,95,91,95,16,134,159,88,29,188,113,95,20,151,18,89,3,95,36,91,6,101,155,89,28,185,134,93,24,167,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,42,91,7,103,156,88,28,186,135,93,24,169,18,89,3,95,18,89,3,95,33,91,5,100,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,54,92,9,110,156,88,28,186,136,93,25,169,20,89,10,95,21,89,12,95,37,91,11,101,159,88,29,188,128,93,23,163,18,89,3,95,18,89,3,95,18,89,4,95,18,89,5,95,21,89,11,95,31,89,26,95,44,90,41,95,67,93,59,101,167,93,75,185,153,98,87,167,89,94,89,95,93,95,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,103,97,97,103,177,95,98,186,161,99,101,169,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,93,95,93,95,86,94,86,95,66,92,64,95,37,90,34,95,20,89,10,95,80,95,14,127,159,88,29,188,95,95,16,137,18,89,3,95,18,89,3,95,18,89,4,95,18,89,4,95,18,89,4,95,36,91,6,101,159,88,29,188,128,93,22,163,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,23,89,6,96,138,92,24,171,159,88,29,188,64,93,11,116,90,95,15,134,159,88,29,188,87,95,15,131,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,122,94,22,158,159,88,29,188,58,93,10,112,18,89,3,95,152,90,28,183,124,94,23,160,104,95,18,144,90,96,15,134,87,95,15,131,109,95,19,148,152,90,28,183,156,88,28,186,71,94,13,120,18,89,5,95,20,89,10,95,38,91,15,101,159,88,31,188,128,93,24,163,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,20,89,9,95,26,89,20,95,95,96,37,134,164,89,52,188,103,98,65,131,73,92,72,95,83,94,82,95,90,94,90,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,151,100,102,158,179,94,97,188,110,99,100,112,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,91,95,91,95,83,94,83,95,63,92,61,95,34,89,30,95,19,89,8,95,80,95,14,127,159,88,29,188,95,95,16,137,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,36,91,6,101,159,88,29,188,128,93,22,163,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,68,93,12,118,159,88,29,188,138,93,24,171,138,92,24,171,154,89,27,184,32,90,5,99,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,73,94,13,122,159,88,29,188,110,95,19,148,18,89,3,95,121,94,22,158,154,89,27,184,159,88,29,188,159,88,29,188,159,88,29,188,158,88,28,187,129,93,23,164,64,94,11,116,18,89,3,95,18,89,3,95,18,89,5,95,38,91,14,101,160,88,33,188,129,94,27,163,19,89,8,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,4,95,18,89,4,95,19,89,6,95,137,93,27,170,154,89,33,184,44,90,33,99,48,91,46,95,63,91,61,95,76,93,75,95,88,94,87,95,93,95,93,95,95,95,95,95,95,95,95,95,119,101,101,122,179,94,97,188,143,102,103,148,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,91,95,91,95,82,94,82,95,61,91,60,95,33,89,28,95,19,89,6,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,21,90,3,96,47,92,7,106,58,93,10,112,53,92,9,109,33,91,5,100,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,19,89,7,95,23,89,16,95,25,89,18,95,22,89,13,95,19,89,8,95,18,89,5,95,18,89,4,95,18,89,4,95,18,89,3,95,18,89,4,95,18,89,4,95,19,89,6,95,21,89,11,95,26,89,20,95,38,90,35,95,54,91,53,95,72,92,72,95,87,94,86,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,91,95,91,95,83,94,83,95,67,92,66,95,42,90,39,95,22,89,13,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,19,89,8,95,21,89,11,95,20,89,11,95,20,89,9,95,19,89,7,95,18,89,5,95,18,89,4,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,19,89,6,95,22,89,13,95,34,89,29,95,54,91,51,95,75,92,75,95,89,94,89,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,93,95,93,95,87,94,87,95,75,92,74,95,56,91,54,95,35,89,31,95,22,89,13,95,18,89,5,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,18,89,4,95,18,89,5,95,18,89,5,95,18,89,5,95,18,89,5,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,22,89,12,95,38,90,35,95,65,92,64,95,84,94,83,95,93,95,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,92,95,92,95,85,94,85,95,75,92,74,95,59,91,58,95,42,90,40,95,28,89,22,95,22,89,13,95,19,89,7,95,18,89,5,95,18,89,4,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,19,89,6,95,29,89,24,95,57,91,56,95,80,93,79,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,92,95,92,95,87,94,87,95,80,93,79,95,70,92,69,95,57,91,56,95,45,90,43,95,38,90,34,95,31,89,26,95,26,89,20,95,22,89,13,95,19,89,7,95,18,89,5,95,18,89,4,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,18,89,4,95,19,89,7,95,20,89,10,95,22,89,13,95,23,89,15,95,23,89,15,95,22,89,14,95,22,89,13,95,21,89,11,95,20,89,10,95,19,89,8,95,19,89,6,95,18,89,5,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,5,95,18,89,5,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,5,95,29,89,23,95,57,91,56,95,79,93,79,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,92,95,92,95,88,94,88,95,84,94,83,95,77,93,77,95,71,92,70,95,64,92,63,95,57,91,56,95,48,91,46,95,39,90,36,95,32,89,27,95,26,89,20,95,23,89,16,95,22,89,12,95,20,89,10,95,19,89,9,95,19,89,7,95,19,89,6,95,18,89,5,95,18,89,5,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,5,95,18,89,5,95,19,89,8,95,22,89,14,95,28,89,22,95,37,90,33,95,45,90,42,95,50,91,48,95,54,91,52,95,54,91,52,95,53,91,51,95,51,91,48,95,47,90,45,95,44,90,41,95,39,90,36,95,34,90,31,95,31,89,26,95,27,89,21,95,25,89,19,95,24,89,17,95,24,89,17,95,24,89,16,95,24,89,16,95,23,89,16,95,22,89,14,95,21,89,12,95,20,89,11,95,20,89,10,95,20,89,9,95,19,89,7,95,19,89,6,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,20,89,10,95,34,90,31,95,61,91,60,95,82,94,82,95,93,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,94,95,94,95,93,95,93,95,91,94,91,95,89,94,89,95,86,94,85,95,81,93,80,95,73,92,72,95,65,92,64,95,58,91,56,95,52,91,50,95,48,90,46,95,44,90,41,95,41,90,38,95,37,90,34,95,35,90,31,95,32,89,27,95,30,89,25,95,28,89,23,95,28,89,22,95,27,89,21,95,28,89,22,95,29,89,24,95,32,89,28,95,39,90,36,95,49,91,47,95,61,91,60,95,73,92,72,95,80,93,80,95,83,94,82,95,83,94,83,95,83,94,82,95,82,93,81,95,79,93,79,95,76,93,76,95,73,92,72,95,70,92,69,95,65,92,64,95,61,91,60,95,57,91,56,95,54,91,53,95,52,91,50,95,51,91,49,95,50,91,48,95,48,91,46,95,46,90,44,95,42,90,40,95,39,90,37,95,37,90,33,95,34,90,30,95,32,89,27,95,29,89,24,95,26,89,20,95,24,89,17,95,22,89,13,95,21,89,11,95,20,89,10,95,20,89,11,95,22,89,14,95,31,89,26,95,49,91,47,95,72,92,71,95,87,94,87,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,94,95,94,95,92,95,92,95,89,94,89,95,86,94,86,95,83,94,83,95,80,93,80,95,77,93,76,95,73,92,73,95,71,92,70,95,67,92,66,95,64,92,63,95,61,91,59,95,59,91,58,95,58,91,57,95,58,91,57,95,58,91,57,95,60,91,59,95,64,92,63,95,71,92,70,95,79,93,78,95,87,94,87,95,92,95,92,95,94,95,94,95,94,95,94,95,94,95,94,95,94,95,94,95,93,95,93,95,92,95,92,95,92,95,92,95,91,94,91,95,89,94,89,95,88,94,87,95,85,94,85,95,83,94,83,95,81,94,81,95,80,93,79,95,78,93,77,95,76,93,76,95,75,92,74,95,72,92,71,95,69,92,68,95,66,92,66,95,64,92,63,95,61,91,60,95,58,91,57,95,55,91,53,95,51,91,50,95,48,90,46,95,45,90,42,95,41,90,38,95,38,90,35,95,39,90,36,95,44,90,41,95,54,91,53,95,69,92,67,95,83,94,83,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,93,95,93,95,92,95,92,95,92,95,91,95,91,95,91,95,90,94,89,95,88,94,88,95,86,94,86,95,85,94,84,95,83,94,83,95,83,94,83,95,83,94,83,95,84,94,84,95,86,94,86,95,89,94,89,95,92,95,92,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,94,95,94,95,94,95,94,95,93,95,93,95,93,95,92,95,92,95,92,95,92,95,91,95,91,94,91,95,89,94,89,95,88,94,88,95,86,94,86,95,85,94,85,95,83,94,83,95,81,93,81,95,79,93,79,95,76,93,75,95,74,92,73,95,71,92,70,95,69,92,68,95,67,92,66,95,67,92,66,95,71,92,70,95,77,93,76,95,85,94,85,95,91,95,91,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,94,95,94,95,94,95,93,95,93,95,93,95,93,95,93,95,94,95,94,95,94,95,94,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,94,95,94,95,94,95,94,95,93,95,93,95,93,95,93,95,92,95,92,95,91,95,91,95,90,94,90,95,89,94,89,95,88,94,88,95,87,94,87,95,86,94,86,95,88,94,88,95,90,94,89,95,93,95,93,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,94,95,94,95,94,95,94,95,94,95,94,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,96,95,95,96,118,101,101,121,118,101,101,121,118,101,101,121,119,101,101,121,115,100,100,117,107,98,98,108,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,96,95,95,96,118,101,101,121,118,101,101,121,118,101,101,121,118,101,101,121,118,101,101,121,118,101,101,121,118,101,101,121,117,100,101,119,95,95,95,95,95,95,95,95,96,95,95,96,118,101,101,121,118,101,101,121,97,95,95,97,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,105,98,98,106,118,101,101,121,102,97,97,102,95,95,95,95,114,100,101,116,118,101,101,121,118,101,101,121,118,101,101,121,118,101,101,121,118,101,101,121,118,101,101,121,118,101,101,121,118,101,101,121,118,101,101,121,117,100,101,119,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,170,96,100,178,130,102,102,134,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,172,96,99,181,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,179,94,97,188,139,102,103,143,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,130,101,102,134,179,94,97,188,118,101,101,120,95,95,95,95,161,99,101,169,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,171,97,100,180,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,162,99,101,170,118,101,101,121,118,101,101,121,123,101,102,126,138,102,103,142,171,97,100,180,179,94,97,188,131,102,102,135,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,163,99,101,171,120,101,102,123,120,101,102,123,120,101,102,123,120,101,102,123,120,101,102,123,118,100,101,120,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,179,94,97,188,177,95,98,186,110,99,100,112,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,130,101,102,134,179,94,97,188,118,101,101,120,95,95,95,95,115,100,100,117,120,101,102,123,120,101,102,123,120,101,102,123,140,102,103,145,179,94,97,188,146,101,103,152,120,101,102,123,120,101,102,123,120,101,102,123,118,101,101,120,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,107,98,98,108,177,94,98,186,170,97,100,178,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,154,100,102,162,179,94,97,188,161,99,101,169,96,95,95,96,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,130,101,102,134,179,94,97,188,118,101,101,120,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,124,101,102,127,179,94,97,188,133,102,103,137,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,164,98,101,172,179,94,97,188,99,96,96,99,95,95,95,95,101,97,97,101,179,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,139,102,103,143,146,101,103,152,179,94,97,188,133,102,103,137,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,130,101,102,134,179,94,97,188,118,101,101,120,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,124,101,102,127,179,94,97,188,133,102,103,137,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,170,97,100,178,177,95,98,187,96,95,95,96,95,95,95,95,101,97,97,101,179,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,142,102,103,147,101,97,97,101,170,97,100,179,175,95,98,184,106,98,98,107,95,95,95,95,95,95,95,95,95,95,95,95,130,101,102,134,179,94,97,188,118,101,101,120,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,124,101,102,127,179,94,97,188,133,102,103,137,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,121,101,102,124,179,94,97,188,155,100,102,161,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,161,99,101,169,115,100,100,117,115,100,100,117,115,100,100,117,115,100,100,117,115,100,100,117,106,98,98,107,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,144,102,103,149,95,95,95,95,122,101,102,125,179,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,130,101,102,134,179,94,97,188,118,101,101,120,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,124,101,102,127,179,94,97,188,133,102,103,137,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,166,98,101,174,130,101,102,134,130,101,102,134,135,102,103,140,151,101,103,158,177,94,98,187,167,97,100,175,105,98,98,106,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,142,101,103,147,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,145,102,103,150,95,95,95,95,95,95,95,95,151,101,103,158,179,94,97,188,127,102,103,131,95,95,95,95,95,95,95,95,130,101,102,134,179,94,97,188,118,101,101,120,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,124,101,102,127,179,94,97,188,133,102,103,137,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,143,102,103,148,98,96,96,98,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,163,99,101,171,121,101,102,124,121,101,102,124,121,101,102,124,121,101,102,124,121,101,102,124,109,99,99,111,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,144,101,103,150,95,95,95,95,95,95,95,95,103,97,97,104,173,96,98,182,173,96,98,182,103,97,98,104,95,95,95,95,130,102,102,134,179,94,97,188,118,101,101,120,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,124,101,102,127,179,94,97,188,133,102,103,137,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,159,100,102,166,103,97,98,104,103,97,98,104,115,100,100,117,177,94,98,187,159,100,102,166,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,101,97,97,101,179,94,97,188,144,101,103,150,95,95,95,95,95,95,95,95,95,95,95,95,127,102,102,131,179,94,97,188,151,101,103,158,95,95,95,95,129,102,103,133,179,94,97,188,118,101,101,120,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,124,101,102,127,179,94,97,188,133,102,103,137,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95];
-
-const PARTIAL_TRANSPARENCY_TEST_TRANSLUCENT:[[usize;5];100]= [
-[0,0, 400, 0,400],
-[1,400, 800, 0,400],
-[2,800, 1200, 0,400],
-[3,1200, 1600, 0,400],
-[4,1600, 2000, 0,400],
-[5,2000, 2400, 0,400],
-[6,2400, 2800, 0,400],
-[7,2800, 3200, 0,400],
-[8,3200, 3600, 0,400],
-[9,3600, 4000, 0,400],
-[10,4000, 4400, 0,400],
-[11,4400, 4800, 0,400],
-[12,4800, 5200, 0,400],
-[13,5200, 5600, 0,400],
-[14,5600, 6000, 0,400],
-[15,6000, 6400, 0,400],
-[16,6400, 6800, 0,400],
-[17,6800, 7200, 0,400],
-[18,7200, 7600, 0,400],
-[19,7600, 8000, 0,400],
-[20,8000, 8400, 0,400],
-[21,8400, 8800, 0,400],
-[22,8800, 9200, 0,400],
-[23,9200, 9600, 0,400],
-[24,9600, 10000, 0,400],
-[25,10000, 10400, 0,400],
-[26,10400, 10800, 0,400],
-[27,10800, 11200, 0,400],
-[28,11200, 11600, 0,400],
-[29,11600, 12000, 0,400],
-[30,12000, 12400, 0,400],
-[31,12400, 12800, 0,400],
-[32,12800, 13200, 0,400],
-[33,13200, 13600, 0,400],
-[34,13600, 14000, 0,400],
-[35,14000, 14400, 0,400],
-[36,14400, 14800, 0,400],
-[37,14800, 15200, 0,400],
-[38,15200, 15600, 0,400],
-[39,15600, 16000, 0,400],
-[40,16000, 16400, 0,400],
-[41,16400, 16800, 0,400],
-[42,16800, 17200, 0,400],
-[43,17200, 17600, 0,400],
-[44,17600, 18000, 0,400],
-[45,18000, 18400, 0,400],
-[46,18400, 18800, 0,400],
-[47,18800, 19200, 0,400],
-[48,19200, 19600, 0,400],
-[49,19600, 20000, 0,400],
-[50,20000, 20400, 0,400],
-[51,20400, 20800, 0,400],
-[52,20800, 21200, 0,400],
-[53,21200, 21600, 0,400],
-[54,21600, 22000, 0,400],
-[55,22000, 22400, 0,400],
-[56,22400, 22800, 0,400],
-[57,22800, 23200, 0,400],
-[58,23200, 23600, 0,400],
-[59,23600, 24000, 0,400],
-[60,24000, 24400, 0,400],
-[61,24400, 24800, 0,400],
-[62,24800, 25200, 0,400],
-[63,25200, 25600, 0,400],
-[64,25600, 26000, 0,400],
-[65,26000, 26400, 0,400],
-[66,26400, 26800, 0,400],
-[67,26800, 27200, 0,400],
-[68,27200, 27600, 0,400],
-[69,27600, 28000, 0,400],
-[70,28000, 28400, 0,400],
-[71,28400, 28800, 0,400],
-[72,28800, 29200, 0,400],
-[73,29200, 29600, 0,400],
-[74,29600, 30000, 0,400],
-[75,30000, 30400, 0,400],
-[76,30400, 30800, 0,400],
-[77,30800, 31200, 0,400],
-[78,31200, 31600, 0,400],
-[79,31600, 32000, 0,400],
-[80,32000, 32400, 0,400],
-[81,32400, 32800, 0,400],
-[82,32800, 33200, 0,400],
-[83,33200, 33600, 0,400],
-[84,33600, 34000, 0,400],
-[85,34000, 34400, 0,400],
-[86,34400, 34800, 0,400],
-[87,34800, 35200, 0,400],
-[88,35200, 35600, 0,400],
-[89,35600, 36000, 0,400],
-[90,36000, 36400, 0,400],
-[91,36400, 36800, 0,400],
-[92,36800, 37200, 0,400],
-[93,37200, 37600, 0,400],
-[94,37600, 38000, 0,400],
-[95,38000, 38400, 0,400],
-[96,38400, 38800, 0,400],
-[97,38800, 39200, 0,400],
-[98,39200, 39600, 0,400],
-[99,39600, 40000, 0,400],
-];
-
-const PARTIAL_TRANSPARENCY_TEST_SOLID:[[usize;5];0]= [
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-];
@@ -0,0 +1,82 @@
+// ===============================
+//            RLE.RS
+// -------------------------------
+//  Run length encoded sprites. every pixel range
+//  is stored as runs of one repeated color, which
+//  flat pixel art shrinks down to a fraction of the
+//  full rgba image. the renderer can blit runs as
+//  they are or they can be expanded once on load
+// -------------------------------
+
+use crate::{graphics::sprites::{convert::{encode_runs, RangeFields}, PixelRange, Sprite, SpriteSlice}, shared::{Size, BPP}};
+
+///Bytes in one run, a count then the premultiplied rgba every pixel of the run shares
+pub const RUN_BYTES:usize = 1 + BPP;
+
+///RleSprite holds the same ranges as a sprite but only the runs inside them instead of the whole image
+#[derive(Clone,Debug)]
+pub struct RleSprite{
+    pub size:Size,
+    ///runs back to back, each one a count byte followed by the premultiplied rgba of the run
+    pub runs:SpriteSlice<u8>,
+    ///src_index and src_end_index point into runs, everything else matches the uncompressed ranges
+    pub translucent_ranges:SpriteSlice<PixelRange>,
+    pub solid_ranges:SpriteSlice<PixelRange>,
+}
+
+impl RleSprite{
+    ///Encodes sprite, transparent pixels are dropped and every range becomes runs. indexed sprites are encoded with their own palette
+    pub fn encode(sprite:&Sprite) -> Self{
+        let resolved;
+        let sprite = if sprite.is_indexed(){
+            resolved = sprite.resolved(None);
+            &resolved
+        }else{
+            sprite
+        };
+        let fields = |ranges:&[PixelRange]|ranges.iter()
+            .map(|range|[range.line, range.src_index, range.src_end_index, range.x_begin, range.x_end])
+            .collect::<Vec<RangeFields>>();
+        let (runs, translucent, solid) = encode_runs(&sprite.data, &fields(&sprite.translucent_ranges), &fields(&sprite.solid_ranges));
+        let to_ranges = |ranges:Vec<RangeFields>|ranges.into_iter()
+            .map(|[line, src_index, src_end_index, x_begin, x_end]|PixelRange{line, src_index, src_end_index, x_begin, x_end})
+            .collect::<Vec<_>>();
+        RleSprite{
+            size:sprite.size,
+            runs:runs.into(),
+            translucent_ranges:to_ranges(translucent).into(),
+            solid_ranges:to_ranges(solid).into(),
+        }
+    }
+    ///Expands the runs back into a full image sprite, pixel for pixel the same as the one that was encoded
+    pub fn decode(&self) -> Sprite{
+        let mut data = vec![0;self.size.pixels()*BPP];
+        for range in self.solid_ranges.iter().chain(self.translucent_ranges.iter()){
+            let mut index = range.line*self.size.width*BPP + range.x_begin;
+            for (count, color) in self.runs(range){
+                for pixel in data[index..index + count*BPP].chunks_exact_mut(BPP){
+                    pixel.copy_from_slice(color);
+                }
+                index += count*BPP;
+            }
+        }
+        Sprite::from_rgba(data, self.size)
+    }
+    ///Runs inside range as pixel count and color
+    pub fn runs(&self, range:&PixelRange) -> impl Iterator<Item = (usize, &[u8])>{
+        self.runs[range.src_index..range.src_end_index]
+            .chunks_exact(RUN_BYTES)
+            .map(|run|(run[0] as usize, &run[1..]))
+    }
+    ///Bytes of pixel data stored, compare with raw_len to see what encoding saved
+    pub fn encoded_len(&self) -> usize{
+        self.runs.len()
+    }
+}
+
+impl Sprite{
+    ///Bytes of pixel data stored, the whole image including its transparent pixels
+    pub fn raw_len(&self) -> usize{
+        self.data.len()
+    }
+}
@@ -1,253 +0,0 @@
-// ===============================
-//  SPRITE: TETRIS
-// -------------------------------
-//  Auto-generated from Tetris.png
-//  Dimensions: 256x240
-//  Place in sprites folder and declare in mod.rs to use
-//  DO NOT EDIT MANUALLY
-// ===============================
-
-use crate::{graphics::sprites::Sprite, shared::Size};
-
-pub const TETRIS:Sprite = Sprite{data:&TETRIS_SPRITE_DATA,size: Size{width:256,height:240}};
-
-const TETRIS_SPRITE_DATA:[u8;245760]= [255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,
-255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,
-255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,
-255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,100,176,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,228,229,148,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,
-255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,0,0,0,255,92,228,48,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,188,190,0,255,181,49,32,255,0,0,0,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,188,190,0,255,0,0,0,255,188,190,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,181,49,32,255,0,0,0,255,181,49,32,255,92,228,48,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,92,228,48,255,0,0,0,255,92,228,48,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,181,49,32,255,0,0,0,255,92,228,48,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,92,228,48,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,188,190,0,255,181,49,32,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,0,0,0,255,181,49,32,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,92,228,48,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,181,49,32,255,0,0,0,255,181,49,32,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,92,228,48,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,92,228,48,255,0,0,0,255,181,49,32,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,181,49,32,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,92,228,48,255,181,49,32,255,0,0,0,255,181,49,32,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,92,228,48,255,0,0,0,255,92,228,48,255,181,49,32,255,181,49,32,255,92,228,48,255,0,0,0,255,181,49,32,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,0,0,0,255,181,49,32,255,188,190,0,255,0,0,0,255,181,49,32,255,188,190,0,255,0,0,0,255,181,49,32,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,92,228,48,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,181,49,32,255,0,0,0,255,92,228,48,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,92,228,48,255,0,0,0,255,181,49,32,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,
-255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,181,49,32,255,188,190,0,255,188,190,0,255,0,0,0,255,181,49,32,255,188,190,0,255,0,0,0,255,181,49,32,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,92,228,48,255,181,49,32,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,92,228,48,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,181,49,32,255,188,190,0,255,0,0,0,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,181,49,32,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,188,190,0,255,0,0,0,255,92,228,48,255,188,190,0,255,181,49,32,255,0,0,0,255,92,228,48,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,181,49,32,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,181,49,32,255,0,0,0,255,188,190,0,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,188,190,0,255,0,0,0,255,181,49,32,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,188,190,0,255,0,0,0,255,181,49,32,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,181,49,32,255,188,190,0,255,0,0,0,255,181,49,32,255,188,190,0,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,181,49,32,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,181,49,32,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,188,190,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,188,190,0,255,181,49,32,255,181,49,32,255,0,0,0,255,188,190,0,255,0,0,0,255,181,49,32,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,188,190,0,255,0,0,0,255,181,49,32,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,181,49,32,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,188,190,0,255,181,49,32,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,181,49,32,255,0,0,0,255,188,190,0,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,188,190,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,181,49,32,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,181,49,32,255,0,0,0,255,92,228,48,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,181,49,32,255,0,0,0,255,181,49,32,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,181,49,32,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,181,49,32,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,188,190,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,0,0,0,255,181,49,32,255,0,0,0,255,181,49,32,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,181,49,32,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,181,49,32,255,92,228,48,255,92,228,48,255,181,49,32,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,181,49,32,255,92,228,48,255,92,228,48,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,181,49,32,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,181,49,32,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,181,49,32,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,188,190,0,255,0,0,0,255,181,49,32,255,0,0,0,255,188,190,0,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,188,190,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,188,190,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,0,0,0,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,188,190,0,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,181,49,32,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,181,49,32,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,181,49,32,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,181,49,32,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,181,49,32,255,92,228,48,255,92,228,48,255,181,49,32,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,181,49,32,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,181,49,32,255,92,228,48,255,92,228,48,255,92,228,48,255,92,228,48,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,92,228,48,255,92,228,48,255,181,49,32,255,92,228,48,255,92,228,48,255,92,228,48,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,181,49,32,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,181,49,32,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,181,49,32,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,92,228,48,255,188,190,0,255,181,49,32,255,188,190,0,255,92,228,48,255,92,228,48,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,92,228,48,255,92,228,48,255,92,228,48,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,181,49,32,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,92,228,48,255,188,190,0,255,181,49,32,255,188,190,0,255,92,228,48,255,92,228,48,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,92,228,48,255,0,0,0,255,92,228,48,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,181,49,32,255,181,49,32,255,0,0,0,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,181,49,32,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,181,49,32,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,92,228,48,255,0,0,0,255,92,228,48,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,181,49,32,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,
-255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,188,190,0,255,188,190,0,255,188,190,0,255,188,190,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,
-255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,
-255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,255,254,255,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,
-0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,255,254,255,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,102,102,102,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255,0,0,0,255];
@@ -1,320 +0,0 @@
-// ===============================
-//  TRANSPARENCY2_SPRITE.RS
-// -------------------------------
-//  generated from transparency2.png
-//  dimensions: 100x100
-//  place in sprites folder and declare in sprites mod.rs to use
-//  DO NOT EDIT MANUALLY
-// -------------------------------
-
-use crate::{graphics::sprites::{Sprite, PixelRange}, shared::Size};
-
-pub const TRANSPARENCY2_SPRITE:Sprite = Sprite{data:&TRANSPARENCY2_SPRITE_DATA,size: Size{width:100,height:100},
-translucent_ranges:&TRANSPARENCY2_TRANSLUCENT, solid_ranges:&TRANSPARENCY2_SOLID};
-
-const TRANSPARENCY2_SPRITE_DATA:[u8;40000]= [217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,94,95,94,95,94,95,94,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,93,95,93,95,89,94,89,95,87,94,87,95,86,94,86,95,87,94,87,95,89,94,89,95,91,95,91,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,215,164,220,255,215,164,220,255,215,163,219,255,215,163,220,255,214,161,219,255,212,160,217,255,211,159,216,255,212,160,217,255,214,161,219,255,215,162,220,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,94,95,94,95,89,94,89,95,80,93,80,95,71,92,70,95,65,92,64,95,62,91,61,95,65,92,64,95,70,92,69,95,76,93,76,95,82,94,82,95,88,94,88,95,92,95,92,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,215,164,220,255,211,163,216,255,207,162,212,255,203,161,208,255,201,160,206,255,197,156,202,255,194,152,199,255,189,147,195,255,187,144,193,255,185,143,190,255,187,143,192,255,190,145,196,255,197,149,202,255,205,154,210,255,212,160,216,255,215,163,220,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,94,95,94,95,91,95,91,95,83,94,82,95,68,92,67,95,49,91,47,95,38,90,34,95,30,89,25,95,28,89,23,95,29,89,23,95,33,89,29,95,41,90,38,95,50,91,48,95,61,91,60,95,74,92,73,95,85,94,85,95,92,95,92,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,212,163,217,255,204,162,208,255,193,160,198,255,184,158,189,255,176,155,182,255,172,153,177,255,167,151,172,255,163,149,169,255,160,148,166,255,159,147,165,255,159,147,164,255,159,146,165,255,161,143,166,255,165,140,170,255,173,137,179,255,186,142,192,255,201,152,206,255,210,160,215,255,216,164,221,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,213,164,217,255,85,94,84,95,73,92,73,95,53,91,52,95,33,89,28,95,22,89,13,95,19,89,6,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,19,89,6,95,20,89,10,95,25,89,18,95,36,90,32,95,55,91,53,95,75,92,74,95,88,94,88,95,94,95,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,211,163,215,255,199,161,204,255,182,158,187,255,170,156,174,255,162,154,167,255,159,154,164,255,157,153,162,255,157,153,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,153,162,255,156,152,162,255,157,148,162,255,160,141,166,255,170,137,176,255,186,145,192,255,203,156,208,255,212,162,217,255,216,164,221,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,215,164,220,255,209,163,214,255,198,160,203,255,63,91,61,95,42,90,39,95,24,89,17,95,19,89,6,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,5,95,22,89,13,95,38,90,35,95,64,92,63,95,83,94,83,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,213,164,218,255,200,161,204,255,178,157,183,255,163,154,169,255,158,154,163,255,157,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,153,162,255,157,148,162,255,162,140,167,255,176,143,182,255,197,153,202,255,211,162,216,255,216,164,221,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,215,164,220,255,209,162,213,255,194,160,199,255,176,157,181,255,35,90,31,95,20,89,11,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,19,89,6,95,29,89,24,95,56,91,54,95,80,93,80,95,91,95,91,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,215,164,220,255,205,162,210,255,184,158,189,255,164,154,169,255,157,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,151,162,255,159,144,164,255,172,143,177,255,195,153,200,255,211,162,216,255,216,164,221,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,215,164,220,255,209,162,213,255,193,160,198,255,175,156,180,255,161,154,166,255,20,89,9,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,25,89,18,95,52,91,50,95,79,93,79,95,91,95,91,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,211,163,216,255,194,160,199,255,170,155,175,255,158,154,163,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,152,162,255,159,145,164,255,172,145,178,255,196,156,201,255,212,163,216,255,216,164,221,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,210,163,215,255,195,160,200,255,175,157,180,255,162,155,168,255,157,154,162,255,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,24,89,17,95,52,91,50,95,80,93,80,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,216,164,221,255,206,162,211,255,182,156,187,255,161,153,166,255,157,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,152,162,255,160,146,166,255,177,151,182,255,199,160,204,255,212,164,217,255,216,164,221,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,213,164,218,255,201,161,205,255,180,158,185,255,163,155,168,255,157,154,162,255,156,154,162,255,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,26,89,19,95,56,91,54,95,83,94,83,95,94,95,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,214,164,219,255,199,159,204,255,172,153,177,255,158,153,163,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,157,152,162,255,163,151,169,255,180,156,185,255,201,161,206,255,213,164,218,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,209,162,214,255,191,160,196,255,170,156,175,255,158,154,163,255,156,154,162,255,156,154,162,255,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,30,89,25,95,64,92,62,95,88,94,88,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,212,163,217,255,191,155,196,255,165,150,170,255,157,153,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,158,153,163,255,166,154,171,255,183,158,188,255,204,162,208,255,215,164,219,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,215,164,220,255,205,162,210,255,184,158,189,255,163,155,169,255,157,154,162,255,156,154,162,255,156,154,162,255,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,19,89,6,95,39,90,36,95,74,92,73,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,210,161,215,255,185,151,190,255,161,149,166,255,156,153,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,159,154,164,255,169,156,174,255,190,159,195,255,209,163,214,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,215,164,219,255,202,162,207,255,179,158,184,255,161,154,166,255,157,154,162,255,156,154,162,255,156,154,162,255,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,22,89,14,95,54,91,53,95,85,94,85,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,208,159,213,255,181,146,186,255,159,148,165,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,157,154,162,255,162,154,167,255,178,157,183,255,200,161,205,255,214,164,219,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,215,164,219,255,201,161,206,255,178,157,183,255,160,154,165,255,156,154,162,255,156,154,162,255,156,154,162,255,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,19,89,6,95,35,90,31,95,72,92,71,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,207,156,213,255,179,141,184,255,159,147,164,255,156,153,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,158,154,164,255,169,156,174,255,191,160,196,255,210,163,215,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,215,164,219,255,202,162,207,255,178,157,183,255,160,154,165,255,156,154,162,255,156,154,162,255,156,154,162,255,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,24,89,17,95,56,91,54,95,86,94,85,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,207,154,212,255,179,136,184,255,159,143,164,255,156,153,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,157,154,162,255,164,155,169,255,183,158,188,255,204,162,210,255,215,164,220,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,215,164,220,255,204,162,209,255,181,158,185,255,161,154,166,255,157,154,162,255,156,154,162,255,156,154,162,255,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,19,89,8,95,42,90,39,95,76,93,76,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-216,163,221,255,207,152,212,255,180,131,185,255,159,140,164,255,156,153,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,157,154,162,255,160,154,166,255,176,157,181,255,198,160,203,255,213,164,218,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,207,162,212,255,186,158,191,255,163,155,168,255,157,154,162,255,156,154,162,255,156,154,162,255,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,5,95,31,89,26,95,65,92,64,95,89,94,89,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-216,162,221,255,207,151,213,255,182,128,188,255,159,136,165,255,156,152,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,158,154,164,255,170,156,174,255,191,160,196,255,210,163,214,255,216,164,221,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,211,163,216,255,192,160,197,255,168,156,173,255,157,154,163,255,156,154,162,255,156,154,162,255,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,25,89,18,95,56,91,54,95,84,94,84,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-216,163,221,255,208,153,213,255,184,129,190,255,160,133,166,255,156,151,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,158,153,161,255,173,142,150,255,173,142,150,255,173,142,150,255,174,142,150,255,171,144,152,255,164,149,157,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,167,147,155,255,183,140,155,255,189,154,186,255,206,162,211,255,215,164,220,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,163,221,255,221,152,206,255,221,152,206,255,221,152,206,255,221,152,206,255,220,154,208,255,219,158,214,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,220,155,209,255,221,152,206,255,221,152,206,255,221,152,206,255,221,152,206,255,219,151,203,255,208,148,191,255,187,144,167,255,175,142,153,255,173,142,150,255,172,143,151,255,34,90,5,100,72,94,12,121,72,94,12,121,72,94,12,121,72,94,12,121,71,94,12,120,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,56,93,9,111,80,95,14,127,42,91,7,103,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,21,89,12,95,48,90,46,95,80,93,79,95,93,95,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,209,155,214,255,186,135,192,255,162,135,168,255,156,151,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,160,151,159,255,210,103,113,255,210,103,113,255,210,103,113,255,210,103,113,255,210,103,113,255,210,103,113,255,203,112,121,255,178,138,147,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,199,119,127,255,211,104,117,255,202,134,160,255,204,162,208,255,215,164,219,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,229,117,163,255,222,145,197,255,217,164,222,255,217,164,222,255,227,123,170,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,230,110,153,255,227,109,147,255,219,107,133,255,212,104,118,255,210,103,113,255,205,109,118,255,54,93,9,110,150,90,27,181,159,88,29,188,159,88,29,188,159,88,29,188,141,92,25,173,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,131,94,23,165,159,88,29,188,104,95,18,144,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,19,89,8,95,42,90,39,95,76,93,75,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,210,159,215,255,189,143,194,255,164,138,170,255,157,151,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,160,151,159,255,210,103,113,255,200,115,124,255,173,142,150,255,173,142,150,255,176,140,148,255,188,129,138,255,207,107,115,255,210,103,113,255,178,138,147,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,165,148,156,255,209,103,113,255,207,108,120,255,213,112,136,255,203,161,207,255,215,164,219,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,227,123,170,255,221,152,206,255,221,152,206,255,222,149,202,255,224,140,191,255,228,117,161,255,231,110,153,255,223,144,196,255,217,164,222,255,220,154,208,255,221,151,205,255,221,151,205,255,221,151,205,255,224,139,189,255,231,110,153,255,223,134,180,255,205,148,186,255,186,144,164,255,176,142,151,255,173,142,151,255,18,89,3,95,20,89,3,95,108,95,19,147,159,88,29,188,80,95,14,126,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,51,92,8,108,159,88,29,188,151,89,27,182,147,91,27,179,23,89,5,96,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,19,89,7,95,39,90,35,95,73,92,73,95,92,95,91,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,212,162,217,255,193,151,198,255,168,144,173,255,157,151,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,160,151,159,255,210,103,113,255,197,119,128,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,172,143,151,255,210,103,113,255,203,112,122,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,187,130,139,255,209,103,113,255,187,135,148,255,217,106,130,255,211,149,192,255,215,164,220,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,226,127,175,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,219,157,214,255,229,111,155,255,229,118,163,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,202,255,231,110,153,255,222,143,193,255,206,162,211,255,185,158,190,255,164,155,169,255,157,154,162,255,18,89,4,95,18,89,3,95,103,95,18,143,159,88,29,188,72,94,12,121,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,104,95,18,144,159,88,29,188,94,95,17,136,159,88,29,188,75,95,13,123,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,19,89,6,95,38,90,35,95,73,92,72,95,91,95,91,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,214,163,219,255,198,157,203,255,173,149,178,255,158,151,164,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,160,151,159,255,210,103,113,255,197,119,128,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,203,112,120,255,210,103,113,255,158,153,160,255,156,154,162,255,156,154,162,255,158,153,161,255,204,110,119,255,199,117,126,255,169,153,170,255,218,109,137,255,221,128,171,255,216,164,221,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,226,127,175,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,228,122,168,255,231,110,153,255,218,163,220,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,202,255,231,110,153,255,223,143,194,255,212,163,217,255,197,160,202,255,176,157,180,255,160,154,165,255,19,89,6,95,18,89,3,95,103,95,18,143,159,88,29,188,72,94,12,121,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,24,89,5,96,147,91,27,179,134,93,24,168,32,90,5,99,154,89,27,184,123,94,22,159,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,19,89,7,95,40,90,37,95,75,92,75,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,216,164,221,255,205,161,210,255,182,154,187,255,162,152,168,255,157,153,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,160,151,159,255,210,103,113,255,197,119,128,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,202,113,122,255,209,103,113,255,159,153,161,255,156,154,162,255,156,154,162,255,175,141,149,255,210,103,113,255,184,136,148,255,176,157,181,255,213,129,164,255,227,111,151,255,218,160,216,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,226,127,175,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,229,118,163,255,230,112,154,255,218,164,221,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,202,255,231,110,153,255,223,143,195,255,216,164,221,255,208,162,213,255,189,159,194,255,169,156,174,255,23,89,16,95,18,89,4,95,103,95,18,143,159,88,29,188,72,94,12,121,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,76,95,14,124,159,88,29,188,90,95,15,134,18,89,3,95,119,95,21,155,156,88,28,186,42,91,7,104,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,20,89,10,95,46,90,44,95,79,93,79,95,93,95,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,211,163,216,255,193,158,198,255,172,154,177,255,159,153,165,255,157,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,160,151,159,255,210,103,113,255,197,119,128,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,167,147,155,255,209,104,114,255,199,116,125,255,156,154,162,255,156,154,162,255,157,154,162,255,196,122,131,255,209,105,118,255,175,153,174,255,189,159,194,255,213,150,196,255,230,110,153,255,223,143,194,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,226,127,175,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,150,204,255,231,110,153,255,227,129,177,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,202,255,231,110,153,255,223,143,195,255,217,164,222,255,215,164,219,255,204,162,208,255,180,158,185,255,34,90,30,95,19,89,8,95,103,95,18,143,159,88,29,188,72,94,12,121,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,124,94,23,160,156,88,28,186,38,90,6,101,18,89,3,95,73,94,12,121,159,88,29,188,96,95,17,138,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,24,89,17,95,54,91,53,95,85,94,85,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,215,164,220,255,206,162,211,255,187,158,192,255,169,154,174,255,160,153,165,255,157,153,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,160,151,159,255,210,103,113,255,198,118,127,255,162,150,158,255,163,149,157,255,168,146,154,255,182,135,143,255,205,109,118,255,209,103,113,255,174,141,150,255,156,154,162,255,157,154,162,255,165,151,160,255,211,105,120,255,205,125,148,255,191,160,196,255,205,162,210,255,214,164,218,255,229,117,162,255,228,120,166,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,228,121,166,255,222,144,197,255,222,144,197,255,223,141,193,255,225,131,179,255,230,111,154,255,228,119,165,255,219,159,215,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,202,255,231,110,153,255,223,143,195,255,217,164,222,255,217,164,222,255,212,163,216,255,193,160,198,255,45,90,42,95,23,89,15,95,103,95,19,143,159,88,29,188,72,94,12,121,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,43,91,7,104,156,88,28,186,121,95,22,158,18,89,3,95,18,89,3,95,21,90,3,96,147,90,27,178,142,91,26,174,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,19,89,6,95,32,89,28,95,65,92,64,95,89,94,89,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,214,164,219,255,205,162,210,255,188,158,193,255,174,155,180,255,164,153,170,255,160,153,165,255,158,153,163,255,157,153,162,255,157,154,162,255,157,154,162,255,156,154,162,255,160,151,159,255,210,103,113,255,210,103,113,255,210,103,113,255,210,103,113,255,210,103,113,255,210,103,113,255,203,112,120,255,175,140,149,255,156,154,162,255,157,154,162,255,160,154,165,255,191,134,152,255,218,107,132,255,206,147,187,255,209,162,213,255,214,163,218,255,217,163,220,255,224,138,188,255,231,110,153,255,220,153,208,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,225,137,187,255,218,163,220,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,202,255,231,110,153,255,223,143,195,255,217,164,222,255,217,164,222,255,215,164,219,255,200,161,205,255,55,91,54,95,28,89,22,95,103,95,19,143,159,88,29,188,72,94,12,121,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,97,95,17,139,159,88,29,188,77,94,13,124,24,89,5,96,24,89,5,96,24,89,5,96,106,95,18,146,159,88,29,188,67,93,11,117,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,20,89,10,95,43,90,41,95,73,92,72,95,91,94,91,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,214,164,219,255,207,162,212,255,196,160,201,255,186,157,191,255,178,155,183,255,172,153,177,255,169,153,174,255,166,153,171,255,163,153,168,255,161,154,167,255,164,151,162,255,210,104,115,255,202,113,123,255,182,135,143,255,181,135,144,255,178,137,146,255,170,145,153,255,157,153,161,255,156,154,162,255,157,154,162,255,160,154,165,255,170,155,174,255,214,116,143,255,225,109,145,255,229,110,151,255,230,110,153,255,230,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,225,134,183,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,227,127,173,255,218,160,217,255,218,160,217,255,220,153,208,255,230,111,154,255,227,126,173,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,202,255,231,110,153,255,223,143,195,255,217,164,222,255,217,164,222,255,215,164,220,255,204,162,208,255,61,91,60,95,33,89,29,95,103,95,20,143,159,88,29,188,72,94,12,121,18,89,4,95,19,89,6,95,19,89,8,95,21,89,8,95,143,92,27,175,159,88,29,188,159,88,29,188,159,88,29,188,159,88,29,188,159,88,30,188,159,88,30,188,159,88,29,188,117,95,21,154,18,89,4,95,18,89,3,95,18,89,3,95,18,89,5,95,25,89,18,95,52,91,50,95,77,93,77,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,212,163,217,255,207,162,213,255,201,161,206,255,198,160,202,255,194,159,199,255,192,158,196,255,188,158,193,255,184,158,189,255,182,155,181,255,215,105,124,255,202,121,138,255,164,155,170,255,161,154,167,255,159,154,165,255,158,154,164,255,158,154,164,255,159,154,165,255,163,155,168,255,171,156,176,255,196,147,179,255,226,109,145,255,226,126,171,255,224,136,186,255,225,136,187,255,225,136,187,255,225,136,187,255,225,136,187,255,229,118,163,255,229,114,157,255,218,162,219,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,226,127,175,255,217,164,222,255,217,164,222,255,217,164,222,255,225,136,185,255,231,110,153,255,222,147,200,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,202,255,231,110,153,255,223,143,195,255,217,164,222,255,217,164,222,255,215,164,220,255,206,162,210,255,65,92,64,95,38,90,35,95,103,95,21,143,159,88,29,188,72,94,12,121,18,89,5,95,20,89,10,95,28,89,22,95,75,94,36,118,162,88,45,188,135,94,45,166,116,96,44,149,116,96,46,149,117,96,48,149,117,96,50,149,116,96,46,149,148,91,36,178,154,89,30,184,35,90,7,100,18,89,4,95,18,89,4,95,19,89,8,95,31,89,26,95,58,91,57,95,81,93,81,95,93,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,215,164,219,255,214,164,219,255,212,164,217,255,212,163,217,255,211,163,216,255,209,163,214,255,206,160,206,255,224,109,141,255,213,124,155,255,185,158,190,255,179,158,184,255,175,157,180,255,172,156,178,255,172,156,178,255,175,156,180,255,182,158,186,255,192,160,197,255,219,131,172,255,229,110,151,255,219,157,213,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,223,143,194,255,231,110,153,255,222,145,198,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,226,127,175,255,217,164,222,255,217,164,222,255,217,164,222,255,219,161,218,255,229,116,159,255,229,115,160,255,219,161,218,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,202,255,231,110,153,255,223,143,195,255,217,164,222,255,217,164,222,255,216,164,221,255,208,162,213,255,69,92,68,95,44,90,41,95,104,96,24,143,159,88,29,188,72,94,12,121,18,89,4,95,21,89,11,95,32,89,28,95,126,97,55,155,168,91,70,188,91,97,78,110,79,93,78,95,80,93,80,95,81,93,81,95,75,93,75,95,59,91,58,95,101,96,41,138,160,88,32,188,89,95,16,133,18,89,4,95,18,89,4,95,21,89,12,95,38,90,35,95,63,92,62,95,85,94,84,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,162,217,255,229,110,151,255,222,126,169,255,206,162,211,255,202,162,207,255,198,160,203,255,196,160,201,255,195,160,200,255,198,161,203,255,203,162,208,255,211,160,212,255,229,113,155,255,227,125,171,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,219,161,217,255,229,112,155,255,227,124,170,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,226,127,175,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,222,144,197,255,231,110,153,255,225,135,184,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,202,255,231,110,153,255,223,143,195,255,217,164,222,255,217,164,222,255,217,164,222,255,211,163,216,255,75,92,74,95,50,91,48,95,104,96,27,143,159,88,29,188,72,94,12,121,18,89,4,95,19,89,7,95,41,91,21,101,159,90,49,185,147,96,73,167,84,94,84,95,91,95,91,95,93,95,93,95,91,94,91,95,79,93,79,95,57,91,55,95,51,92,32,103,157,88,30,186,135,93,24,169,18,89,4,95,18,89,5,95,24,89,16,95,45,90,42,95,70,92,69,95,88,94,88,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,226,127,174,255,215,164,220,255,215,164,219,255,214,164,218,255,213,164,218,255,212,164,217,255,214,164,219,255,215,164,220,255,222,145,196,255,231,110,153,255,222,147,199,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,225,130,179,255,231,110,153,255,220,156,212,255,217,164,222,255,218,162,219,255,231,110,153,255,226,127,175,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,164,221,255,227,122,168,255,230,111,154,255,220,154,209,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,202,255,231,110,153,255,223,143,195,255,217,164,222,255,217,164,222,255,217,164,222,255,214,164,219,255,81,93,80,96,80,95,58,113,125,94,35,159,159,88,29,188,104,95,18,144,52,92,8,108,18,89,4,95,91,95,19,134,161,88,39,188,98,97,53,131,69,92,68,95,81,93,80,95,84,94,84,95,79,93,79,95,63,91,62,95,42,90,39,95,26,89,19,95,122,94,22,158,159,88,29,188,58,93,10,112,19,89,7,95,29,89,23,95,53,91,51,95,77,93,76,95,91,95,91,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,226,127,175,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,227,123,170,255,229,114,157,255,218,162,219,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,152,205,255,231,110,153,255,225,137,187,255,217,164,222,255,218,162,219,255,231,110,153,255,226,127,175,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,220,153,208,255,231,110,153,255,227,122,169,255,218,163,221,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,202,255,231,110,153,255,223,143,195,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,104,98,90,114,168,91,69,188,161,88,43,188,159,88,31,188,159,88,29,188,154,89,27,184,18,89,3,95,137,93,25,170,154,89,30,184,42,90,30,99,46,90,43,95,57,91,55,95,60,91,59,95,54,91,52,95,40,90,37,95,27,89,22,95,19,89,8,95,73,94,13,122,159,88,29,188,110,95,19,148,21,89,11,95,36,90,32,95,61,91,60,95,83,94,83,95,93,95,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,90,94,90,95,74,92,73,95,52,91,50,95,28,89,22,95,19,89,7,95,18,89,4,95,18,89,3,95,18,89,4,95,19,89,6,95,21,89,12,95,27,89,21,95,32,89,28,95,34,90,30,95,30,89,25,95,24,89,16,95,19,89,8,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,5,95,25,89,18,95,45,90,43,95,71,92,70,95,88,94,88,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,94,95,94,95,85,94,85,95,64,92,63,95,42,90,39,95,25,89,19,95,19,89,7,95,18,89,4,95,18,89,3,95,18,89,4,95,18,89,5,95,19,89,7,95,20,89,10,95,20,89,10,95,20,89,9,95,19,89,6,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,4,95,20,89,10,95,33,89,29,95,58,91,57,95,79,93,79,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,92,95,91,95,81,93,80,95,60,91,59,95,43,90,40,95,27,89,21,95,20,89,10,95,18,89,5,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,4,95,19,89,7,95,28,89,22,95,47,90,45,95,71,92,70,95,88,94,88,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,91,94,91,95,80,93,80,95,62,91,61,95,47,90,44,95,31,89,27,95,23,89,15,95,19,89,8,95,18,89,5,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,19,89,7,95,26,89,19,95,43,90,40,95,63,91,62,95,83,94,83,95,93,95,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,91,95,91,95,83,94,82,95,68,92,67,95,54,91,52,95,39,90,37,95,29,89,24,95,23,89,16,95,20,89,11,95,19,89,8,95,19,89,6,95,19,89,6,95,19,89,7,95,20,89,9,95,22,89,13,95,28,89,23,95,43,90,40,95,61,91,60,95,81,93,81,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,92,95,92,95,86,94,86,95,75,92,75,95,63,91,62,95,52,91,50,95,42,90,40,95,35,90,31,95,30,89,25,95,28,89,22,95,27,89,21,95,29,89,23,95,34,90,29,95,41,90,38,95,53,91,51,95,66,92,65,95,82,93,81,95,91,95,91,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,89,94,89,95,83,94,82,95,75,93,75,95,68,92,67,95,61,91,60,95,57,91,55,95,54,91,53,95,54,91,52,95,56,91,54,95,60,91,58,95,68,92,67,95,76,93,75,95,85,94,85,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,93,95,93,95,90,94,90,95,86,94,86,95,83,94,82,95,80,93,79,95,78,93,77,95,78,93,77,95,80,93,79,95,83,94,82,95,87,94,86,95,91,94,91,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,93,95,93,95,92,95,92,95,92,95,91,95,92,95,92,95,92,95,92,95,93,95,93,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,216,164,221,255,216,164,221,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,215,164,220,255,213,164,218,255,210,163,215,255,210,163,215,255,210,163,215,255,213,164,218,255,215,164,220,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,215,164,220,255,211,163,216,255,205,162,210,255,198,161,203,255,194,160,199,255,192,160,197,255,194,160,199,255,199,161,204,255,206,162,210,255,212,163,217,255,216,164,220,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,211,163,216,255,204,162,209,255,194,160,199,255,184,158,189,255,177,157,182,255,172,156,177,255,170,156,175,255,172,156,177,255,177,157,182,255,185,158,190,255,196,160,201,255,205,162,210,255,212,163,217,255,215,164,220,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,220,155,209,255,221,152,206,255,221,152,206,255,221,152,206,255,221,152,206,255,221,152,206,255,221,152,206,255,221,152,206,255,221,152,206,255,221,152,206,255,221,153,207,255,217,164,222,255,218,163,221,255,220,152,205,255,217,151,202,255,211,149,194,255,203,147,185,255,192,148,177,255,180,150,172,255,166,155,171,255,161,154,166,255,159,154,164,255,158,154,163,255,159,154,164,255,161,154,166,255,166,155,171,255,174,156,179,255,183,158,188,255,199,153,191,255,211,146,190,255,212,159,210,255,213,164,218,255,215,164,219,255,216,164,221,255,216,164,221,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,219,161,218,255,222,149,202,255,223,143,195,255,222,145,198,255,221,150,204,255,219,161,217,255,217,164,222,255,217,164,222,255,95,95,95,95,96,95,95,96,118,101,101,121,118,101,101,121,118,101,101,121,119,101,101,121,115,100,100,117,105,98,98,106,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,109,99,99,111,124,101,102,127,102,97,97,103,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,227,123,170,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,228,117,161,255,217,164,222,255,216,161,216,255,227,109,148,255,223,108,141,255,218,107,131,255,214,105,123,255,212,104,118,255,210,104,115,255,206,110,120,255,181,135,144,255,156,154,162,255,156,154,162,255,156,154,162,255,157,154,162,255,158,154,163,255,160,154,165,255,165,155,170,255,205,120,139,255,217,106,129,255,206,134,166,255,196,160,201,255,202,161,206,255,206,162,210,255,209,162,214,255,210,163,215,255,212,163,217,255,213,164,218,255,221,142,192,255,229,112,155,255,230,110,153,255,230,110,153,255,230,110,153,255,231,110,153,255,230,111,154,255,223,142,194,255,217,164,222,255,95,95,95,95,101,97,97,101,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,167,97,100,175,124,101,102,128,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,158,100,102,165,179,94,97,188,139,102,103,144,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,220,154,208,255,221,151,205,255,221,151,205,255,221,151,205,255,224,139,189,255,231,110,153,255,225,134,184,255,221,151,205,255,221,151,205,255,221,151,205,255,220,153,206,255,213,164,218,255,205,160,206,255,220,107,135,255,208,117,138,255,180,143,158,255,176,142,153,255,177,140,149,255,186,131,140,255,205,109,118,255,210,103,113,255,182,134,143,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,157,154,162,255,166,148,157,255,210,104,115,255,208,109,121,255,209,111,127,255,175,156,179,255,179,158,184,255,184,158,189,255,188,159,193,255,190,159,195,255,194,160,199,255,205,145,183,255,224,109,142,255,221,120,157,255,211,150,196,255,211,158,208,255,216,154,206,255,219,148,199,255,225,132,180,255,220,153,207,255,217,164,222,255,95,95,95,95,101,97,97,101,179,94,97,188,162,99,101,170,118,101,101,120,118,100,101,120,123,101,102,126,141,102,103,146,175,96,99,184,179,94,97,188,124,101,102,128,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,107,98,98,108,178,94,98,188,173,96,98,182,170,97,100,179,96,95,95,96,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,202,255,231,110,153,255,223,143,195,255,217,164,222,255,217,164,222,255,214,164,219,255,208,162,213,255,198,160,202,255,185,155,185,255,213,105,121,255,199,120,132,255,158,154,163,255,157,154,162,255,156,154,162,255,156,154,162,255,166,148,156,255,208,104,114,255,204,110,119,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,187,130,139,255,209,103,113,255,183,134,144,255,210,104,114,255,177,142,152,255,162,154,167,255,164,155,169,255,166,156,172,255,169,156,174,255,171,156,176,255,204,123,143,255,216,106,127,255,187,151,178,255,185,158,190,255,190,159,195,255,196,160,201,255,202,162,207,255,208,162,213,255,213,164,218,255,215,164,220,255,95,95,95,95,101,97,97,101,179,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,117,100,101,119,179,94,97,188,166,98,100,174,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,139,102,103,144,178,94,98,188,132,101,102,136,179,94,97,188,120,101,101,123,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,202,255,231,110,153,255,223,143,194,255,215,164,219,255,209,163,214,255,199,161,204,255,186,158,191,255,174,156,179,255,168,153,167,255,210,104,115,255,197,119,129,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,201,114,123,255,210,103,113,255,159,153,160,255,156,154,162,255,156,154,162,255,156,154,162,255,158,153,161,255,204,110,119,255,199,116,126,255,159,151,160,255,207,106,115,255,195,122,131,255,157,154,162,255,157,154,162,255,158,154,163,255,158,154,163,255,158,154,164,255,198,119,130,255,211,104,116,255,166,153,165,255,165,155,170,255,169,156,174,255,174,156,179,255,181,158,185,255,188,159,193,255,197,160,202,255,205,162,210,255,89,94,89,95,98,96,94,101,178,94,97,188,156,100,102,163,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,169,98,101,177,179,94,97,188,99,96,96,99,95,95,95,95,95,95,95,95,96,95,95,96,170,97,100,179,160,99,101,168,99,96,96,99,175,95,98,184,152,100,102,159,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,201,255,229,110,152,255,218,142,188,255,201,161,206,255,189,159,194,255,176,157,180,255,166,156,172,255,161,154,166,255,162,152,160,255,210,103,113,255,197,119,128,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,157,154,162,255,205,110,120,255,209,104,114,255,158,153,162,255,157,154,162,255,156,154,162,255,156,154,162,255,175,141,149,255,210,103,113,255,181,135,144,255,156,154,162,255,193,124,133,255,208,104,114,255,162,150,158,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,189,128,137,255,210,103,113,255,184,134,143,255,157,154,163,255,158,154,163,255,160,154,165,255,163,155,168,255,168,156,173,255,175,157,180,255,184,158,189,255,74,92,73,95,88,95,83,101,176,93,91,188,154,100,99,163,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,166,98,100,173,178,94,98,188,97,95,95,97,95,95,95,95,95,95,95,95,121,101,102,124,179,94,97,188,130,102,102,134,95,95,95,95,149,101,103,155,177,94,98,186,103,97,98,104,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,215,164,219,255,215,147,194,255,224,109,142,255,205,138,171,255,179,157,184,255,168,156,173,255,161,154,166,255,158,154,163,255,157,154,162,255,160,151,159,255,210,103,113,255,197,119,128,255,156,154,162,255,156,154,162,255,156,154,162,255,157,154,162,255,177,141,150,255,210,104,114,255,196,121,130,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,195,121,130,255,208,104,114,255,161,151,159,255,156,154,162,255,174,142,150,255,210,103,113,255,184,133,142,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,163,150,157,255,206,107,117,255,210,103,113,255,192,123,132,255,169,146,154,255,157,154,162,255,157,154,162,255,158,154,164,255,161,154,166,255,165,155,171,255,50,91,48,95,69,93,61,101,170,91,74,188,148,98,87,163,89,94,89,95,93,95,93,95,95,95,95,95,95,95,95,95,109,99,99,111,177,94,98,187,161,99,102,169,95,95,95,95,95,95,95,95,95,95,95,95,153,101,103,160,177,95,98,186,101,96,96,101,95,95,95,95,119,101,101,121,179,94,97,188,134,102,103,138,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,212,163,217,255,203,162,208,255,200,144,176,255,217,106,127,255,190,135,152,255,162,155,167,255,158,154,164,255,157,154,162,255,156,154,162,255,156,154,162,255,160,151,159,255,210,103,113,255,202,113,122,255,181,135,144,255,181,135,144,255,185,133,142,255,196,123,134,255,209,104,116,255,203,112,122,255,164,148,157,255,156,154,162,255,156,154,162,255,156,154,162,255,162,150,158,255,208,104,114,255,194,123,131,255,156,154,162,255,156,154,162,255,157,154,161,255,205,109,119,255,203,112,122,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,165,148,156,255,199,117,127,255,210,103,113,255,210,103,113,255,199,116,125,255,177,138,147,255,157,154,161,255,157,154,162,255,158,154,163,255,28,89,23,95,48,91,34,101,163,89,52,188,142,96,65,166,82,95,75,104,94,96,86,105,108,99,95,113,131,102,102,136,171,97,100,180,178,94,98,188,119,101,101,122,95,95,95,95,95,95,95,95,103,97,97,104,177,94,98,186,151,101,103,158,95,95,95,95,95,95,95,95,96,96,96,96,170,96,100,178,166,98,100,174,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,213,164,218,255,201,161,206,255,183,158,188,255,186,141,159,255,211,104,116,255,184,134,144,255,157,154,162,255,157,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,160,151,159,255,210,103,113,255,210,103,113,255,210,104,114,255,210,104,115,255,211,104,117,255,211,104,116,255,191,128,138,255,158,153,160,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,184,133,141,255,210,103,113,255,175,140,149,255,158,153,161,255,158,153,161,255,158,153,161,255,188,129,138,255,210,103,113,255,171,143,152,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,171,144,152,255,193,123,133,255,208,105,115,255,210,103,113,255,198,118,127,255,161,151,159,255,156,154,162,255,19,89,7,95,38,91,14,101,160,88,35,188,161,88,43,188,164,89,55,188,167,91,67,188,172,91,80,188,175,93,89,188,167,97,98,177,121,101,101,124,95,95,95,95,95,95,95,95,95,95,95,95,134,102,103,139,179,94,97,188,121,101,101,124,96,95,95,96,96,95,95,96,96,95,95,96,141,102,103,146,179,94,97,188,115,100,100,117,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,207,162,212,255,186,158,191,255,167,156,172,255,178,139,149,255,210,103,113,255,183,134,142,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,160,151,159,255,210,103,113,255,199,118,128,255,165,151,161,255,166,151,163,255,175,144,157,255,209,104,116,255,199,117,127,255,157,153,162,255,156,154,162,255,156,154,162,255,156,154,162,255,157,153,162,255,203,113,121,255,210,103,113,255,210,103,113,255,210,103,113,255,210,103,113,255,210,103,113,255,210,103,113,255,210,103,113,255,192,125,133,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,163,149,157,255,196,121,129,255,210,103,113,255,193,124,133,255,156,154,162,255,18,89,4,95,36,91,7,101,159,88,29,188,141,92,28,174,95,96,29,136,97,96,42,135,96,97,56,129,89,96,69,114,81,93,80,96,89,94,89,95,94,95,94,95,95,95,95,95,95,95,95,95,167,98,101,175,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,179,94,97,188,148,101,103,154,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,214,164,218,255,199,161,203,255,178,157,183,255,160,154,165,255,177,139,147,255,210,103,113,255,183,134,142,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,157,154,162,255,161,151,160,255,210,104,114,255,198,120,130,255,160,154,165,255,159,154,164,255,158,154,163,255,190,127,135,255,210,103,113,255,179,137,145,255,156,154,162,255,156,154,162,255,156,154,162,255,172,143,151,255,210,103,113,255,198,118,127,255,189,128,136,255,189,128,136,255,189,128,136,255,189,128,136,255,189,128,136,255,204,110,119,255,207,106,115,255,160,151,159,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,159,153,161,255,204,108,118,255,208,106,115,255,156,154,162,255,18,89,4,95,36,91,6,101,159,88,29,188,128,93,23,163,19,89,8,95,23,89,16,95,31,89,26,95,42,90,40,95,57,91,55,95,73,92,73,95,86,94,85,95,92,95,92,95,115,100,99,118,179,94,97,188,159,100,102,166,144,102,103,149,144,102,103,149,144,102,103,149,144,102,103,149,144,102,103,149,170,97,100,178,175,95,98,184,100,96,96,100,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,212,164,217,255,194,160,199,255,170,156,175,255,158,154,163,255,176,139,147,255,210,103,113,255,183,134,142,255,156,154,162,255,156,154,162,255,157,154,162,255,157,154,162,255,158,154,163,255,162,152,161,255,210,104,114,255,197,120,129,255,157,154,162,255,157,154,162,255,156,154,162,255,162,151,159,255,206,108,117,255,206,108,117,255,161,151,159,255,156,154,162,255,156,154,162,255,193,124,133,255,210,103,113,255,166,147,155,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,183,134,142,255,210,103,113,255,180,136,144,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,157,154,162,255,200,115,125,255,209,104,114,255,158,153,162,255,19,89,6,95,36,91,7,101,159,88,29,188,128,93,22,163,18,89,4,95,18,89,5,95,19,89,8,95,23,89,15,95,33,89,29,95,49,91,47,95,67,92,66,95,81,93,81,95,145,101,96,155,178,94,95,188,107,99,98,110,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,134,102,103,138,179,94,97,188,129,102,103,133,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,209,163,214,255,189,159,193,255,168,156,173,255,157,154,163,255,176,139,147,255,210,103,113,255,183,134,142,255,156,154,162,255,156,154,162,255,157,154,162,255,157,154,162,255,157,154,162,255,161,151,160,255,210,103,113,255,197,119,128,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,181,135,144,255,210,103,113,255,191,126,135,255,156,154,162,255,160,151,159,255,208,106,115,255,199,117,126,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,162,150,158,255,208,104,114,255,199,116,125,255,156,154,162,255,156,154,162,255,159,152,159,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,166,147,155,255,208,104,114,255,200,116,125,255,157,154,162,255,21,89,12,95,37,91,11,101,159,88,29,188,128,93,23,163,18,89,3,95,18,89,3,95,18,89,4,95,18,89,5,95,21,89,11,95,31,89,26,95,44,90,41,95,67,93,59,101,167,93,75,185,153,98,87,167,89,94,89,95,93,95,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,103,97,97,103,177,95,98,186,161,99,101,169,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,215,164,220,255,207,162,211,255,185,158,190,255,164,155,169,255,157,154,162,255,176,139,147,255,210,103,113,255,183,134,142,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,160,151,159,255,210,103,113,255,197,119,128,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,157,153,161,255,201,114,124,255,209,103,113,255,170,144,152,255,181,135,144,255,210,103,113,255,180,137,145,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,194,122,131,255,210,103,113,255,167,147,154,255,156,154,162,255,206,107,116,255,195,121,130,255,187,130,139,255,180,136,144,255,180,137,145,255,189,128,137,255,206,107,116,255,208,104,114,255,173,142,151,255,156,154,162,255,20,89,10,95,38,91,15,101,159,88,31,188,128,93,24,163,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,20,89,9,95,26,89,20,95,95,96,37,134,164,89,52,188,103,98,65,131,73,92,72,95,83,94,82,95,90,94,90,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,151,100,102,158,179,94,97,188,110,99,100,112,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,212,164,217,255,203,162,208,255,183,158,187,255,162,154,168,255,157,154,162,255,176,139,147,255,210,103,113,255,183,134,142,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,160,151,159,255,210,103,113,255,197,119,128,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,172,143,151,255,210,103,113,255,201,115,124,255,201,114,124,255,207,107,115,255,159,151,160,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,174,141,150,255,210,103,113,255,189,128,137,255,156,154,162,255,194,122,131,255,207,107,115,255,210,103,113,255,210,103,113,255,210,103,113,255,209,104,113,255,197,118,128,255,170,145,152,255,156,154,162,255,156,154,162,255,18,89,5,95,38,91,14,101,160,88,33,188,129,94,27,163,19,89,8,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,4,95,18,89,4,95,19,89,6,95,137,93,27,170,154,89,33,184,44,90,33,99,48,91,46,95,63,91,61,95,76,93,75,95,88,94,87,95,93,95,93,95,95,95,95,95,95,95,95,95,119,101,101,122,179,94,97,188,143,102,103,148,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,212,164,217,255,202,162,207,255,181,158,186,255,162,154,167,255,157,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,157,154,161,255,164,149,157,255,167,147,154,255,166,147,156,255,159,152,159,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,18,89,4,95,19,89,7,95,23,89,16,95,25,89,18,95,22,89,13,95,19,89,8,95,18,89,5,95,18,89,4,95,18,89,4,95,18,89,3,95,18,89,4,95,18,89,4,95,19,89,6,95,21,89,11,95,26,89,20,95,38,90,35,95,54,91,53,95,72,92,72,95,87,94,86,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,212,164,217,255,204,162,208,255,186,158,191,255,166,155,172,255,157,154,163,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,18,89,3,95,18,89,4,95,19,89,8,95,21,89,11,95,20,89,11,95,20,89,9,95,19,89,7,95,18,89,5,95,18,89,4,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,19,89,6,95,22,89,13,95,34,89,29,95,54,91,51,95,75,92,75,95,89,94,89,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,215,164,220,255,208,162,213,255,194,160,199,255,176,157,182,255,163,154,168,255,157,154,163,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,18,89,3,95,18,89,4,95,18,89,4,95,18,89,5,95,18,89,5,95,18,89,5,95,18,89,5,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,4,95,22,89,12,95,38,90,35,95,65,92,64,95,84,94,83,95,93,95,93,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,213,164,218,255,205,162,210,255,194,160,199,255,179,157,184,255,167,156,172,255,160,154,165,255,157,154,163,255,157,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,18,89,3,95,18,89,3,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,19,89,6,95,29,89,24,95,57,91,56,95,80,93,79,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,214,164,218,255,207,162,213,255,199,161,204,255,189,159,194,255,178,157,183,255,169,156,174,255,164,155,169,255,161,154,166,255,159,154,164,255,157,154,163,255,157,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,157,154,162,255,157,154,162,255,157,154,163,255,158,154,163,255,158,154,163,255,158,154,163,255,157,154,163,255,157,154,162,255,157,154,162,255,157,154,162,255,157,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,18,89,4,95,18,89,4,95,18,89,5,95,18,89,5,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,3,95,18,89,5,95,29,89,23,95,57,91,56,95,79,93,79,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,215,164,220,255,213,164,218,255,209,163,214,255,204,162,209,255,197,160,202,255,190,159,195,255,184,158,189,255,178,157,183,255,171,156,176,255,165,155,170,255,161,154,166,255,159,154,164,255,158,154,163,255,157,154,163,255,157,154,162,255,157,154,162,255,157,154,162,255,157,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,156,154,162,255,157,154,162,255,158,154,163,255,160,154,165,255,163,155,169,255,168,156,174,255,172,156,177,255,175,156,180,255,175,156,180,255,174,157,179,255,172,156,177,255,170,156,175,255,168,156,173,255,165,155,170,255,162,155,168,255,161,154,166,255,159,154,164,255,159,154,164,255,158,154,163,255,158,154,163,255,24,89,16,95,24,89,16,95,23,89,16,95,22,89,14,95,21,89,12,95,20,89,11,95,20,89,10,95,20,89,9,95,19,89,7,95,19,89,6,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,18,89,4,95,20,89,10,95,34,90,31,95,61,91,60,95,82,94,82,95,93,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,216,164,221,255,215,164,219,255,213,163,217,255,210,163,215,255,206,162,211,255,201,161,206,255,192,160,197,255,185,158,190,255,178,157,183,255,174,156,179,255,171,156,176,255,168,156,173,255,166,155,171,255,164,155,169,255,163,155,168,255,161,154,166,255,160,154,166,255,160,154,165,255,159,154,165,255,159,154,164,255,159,154,165,255,160,154,165,255,161,154,167,255,165,155,170,255,171,156,176,255,181,158,186,255,192,160,197,255,200,161,205,255,203,162,208,255,204,162,208,255,203,162,208,255,201,161,206,255,199,161,204,255,196,160,201,255,192,160,197,255,189,159,194,255,185,158,190,255,181,158,186,255,178,157,183,255,175,157,180,255,174,156,178,255,172,156,178,255,50,91,48,95,48,91,46,95,46,90,44,95,42,90,40,95,39,90,37,95,37,90,33,95,34,90,30,95,32,89,27,95,29,89,24,95,26,89,20,95,24,89,17,95,22,89,13,95,21,89,11,95,20,89,10,95,20,89,11,95,22,89,14,95,31,89,26,95,49,91,47,95,72,92,71,95,87,94,87,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,215,164,220,255,213,164,218,255,210,163,215,255,207,162,212,255,203,162,208,255,200,161,205,255,197,160,201,255,193,160,198,255,190,159,195,255,186,158,191,255,184,158,189,255,181,158,185,255,179,158,184,255,178,157,183,255,178,157,183,255,179,157,184,255,180,158,185,255,184,158,189,255,190,159,195,255,199,161,203,255,208,162,213,255,214,164,219,255,216,164,221,255,216,164,221,255,216,164,221,255,215,164,220,255,215,164,219,255,214,164,219,255,213,164,218,255,212,163,217,255,210,163,215,255,208,162,213,255,206,162,210,255,203,162,208,255,201,162,206,255,199,161,204,255,198,161,202,255,76,93,76,95,75,92,74,95,72,92,71,95,69,92,68,95,66,92,66,95,64,92,63,95,61,91,60,95,58,91,57,95,55,91,53,95,51,91,50,95,48,90,46,95,45,90,42,95,41,90,38,95,38,90,35,95,39,90,36,95,44,90,41,95,54,91,53,95,69,92,67,95,83,94,83,95,92,95,92,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,215,164,220,255,214,164,219,255,213,164,218,255,212,164,217,255,211,163,215,255,209,163,214,255,207,162,212,255,205,162,210,255,204,162,209,255,203,162,208,255,204,162,208,255,204,162,209,255,207,162,212,255,210,163,215,255,214,164,219,255,216,164,221,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,216,164,221,255,215,164,220,255,215,164,219,255,215,164,219,255,214,164,219,255,92,95,91,95,91,94,91,95,89,94,89,95,88,94,88,95,86,94,86,95,85,94,85,95,83,94,83,95,81,93,81,95,79,93,79,95,76,93,75,95,74,92,73,95,71,92,70,95,69,92,68,95,67,92,66,95,67,92,66,95,71,92,70,95,77,93,76,95,85,94,85,95,91,95,91,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,216,164,221,255,216,164,221,255,215,164,220,255,215,164,220,255,215,164,220,255,215,164,220,255,216,164,221,255,216,164,221,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,94,95,94,95,94,95,94,95,93,95,93,95,93,95,93,95,92,95,92,95,91,95,91,95,90,94,90,95,89,94,89,95,88,94,88,95,87,94,87,95,86,94,86,95,88,94,88,95,90,94,89,95,93,95,93,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,94,95,94,95,94,95,94,95,94,95,94,95,94,95,94,95,94,95,94,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,163,221,255,221,152,206,255,221,152,206,255,221,152,206,255,221,152,206,255,220,154,208,255,219,158,214,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,163,221,255,221,152,206,255,221,152,206,255,221,152,206,255,221,152,206,255,221,152,206,255,221,152,206,255,221,152,206,255,221,153,207,255,217,164,222,255,217,164,222,255,218,163,221,255,221,152,206,255,221,152,206,255,218,163,220,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,219,160,215,255,221,152,206,255,219,161,218,255,217,164,222,255,220,155,209,255,221,152,206,255,221,152,206,255,221,152,206,255,221,152,206,255,221,152,206,255,221,152,206,255,221,152,206,255,221,152,206,255,118,101,101,121,117,100,101,119,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,229,117,163,255,222,145,197,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,229,115,160,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,231,110,153,255,224,140,191,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,222,144,197,255,231,110,153,255,221,153,207,255,217,164,222,255,227,123,170,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,179,94,97,188,171,97,100,180,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,227,123,170,255,221,152,206,255,221,152,206,255,222,149,202,255,224,140,191,255,228,117,161,255,231,110,153,255,223,144,196,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,227,123,169,255,221,151,205,255,221,151,205,255,221,151,205,255,221,151,205,255,221,151,205,255,221,152,206,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,231,110,153,255,229,112,155,255,220,156,212,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,222,144,197,255,231,110,153,255,221,153,207,255,217,164,222,255,220,154,208,255,221,151,205,255,221,151,205,255,221,151,205,255,224,139,189,255,231,110,153,255,225,134,184,255,221,151,205,255,221,151,205,255,120,101,102,123,118,101,101,120,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,226,127,175,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,219,157,214,255,229,111,155,255,229,118,163,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,226,127,175,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,226,128,176,255,231,110,153,255,227,124,170,255,218,164,221,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,222,144,197,255,231,110,153,255,221,153,207,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,202,255,231,110,153,255,223,143,195,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,226,127,175,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,228,122,168,255,231,110,153,255,218,163,220,255,217,164,222,255,218,162,219,255,231,110,153,255,226,127,175,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,224,139,190,255,225,134,184,255,231,110,153,255,223,144,195,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,222,144,197,255,231,110,153,255,221,153,207,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,202,255,231,110,153,255,223,143,195,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,226,127,175,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,229,118,163,255,230,112,154,255,218,164,221,255,217,164,222,255,218,162,219,255,231,110,153,255,226,127,175,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,225,138,188,255,218,162,219,255,228,117,162,255,229,114,157,255,219,158,215,255,217,164,222,255,217,164,222,255,217,164,222,255,222,144,197,255,231,110,153,255,221,153,207,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,202,255,231,110,153,255,223,143,195,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,226,127,175,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,150,204,255,231,110,153,255,227,129,177,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,227,124,170,255,220,154,208,255,220,154,208,255,220,154,208,255,220,154,208,255,220,154,208,255,219,159,215,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,225,136,187,255,217,164,222,255,222,150,203,255,231,110,153,255,226,127,175,255,217,164,222,255,217,164,222,255,217,164,222,255,222,144,197,255,231,110,153,255,221,153,207,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,202,255,231,110,153,255,223,143,195,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,228,121,166,255,222,144,197,255,222,144,197,255,223,141,193,255,225,131,179,255,230,111,154,255,228,119,165,255,219,159,215,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,225,137,187,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,225,136,185,255,217,164,222,255,217,164,222,255,225,131,179,255,231,110,153,255,222,147,200,255,217,164,222,255,217,164,222,255,222,144,197,255,231,110,153,255,221,153,207,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,202,255,231,110,153,255,223,143,195,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,231,110,153,255,225,137,187,255,218,163,220,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,227,122,169,255,221,150,204,255,221,150,204,255,221,150,204,255,221,150,204,255,221,150,204,255,219,157,212,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,224,135,185,255,217,164,222,255,217,164,222,255,218,160,216,255,229,115,159,255,229,115,159,255,218,160,217,255,217,164,222,255,222,145,197,255,231,110,153,255,221,153,207,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,202,255,231,110,153,255,223,143,195,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,227,127,173,255,218,160,217,255,218,160,217,255,220,153,208,255,230,111,154,255,227,126,173,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,226,127,175,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,218,162,219,255,231,110,153,255,224,135,185,255,217,164,222,255,217,164,222,255,217,164,222,255,222,147,199,255,231,110,153,255,225,131,179,255,217,164,222,255,222,145,198,255,231,110,153,255,221,153,207,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,221,148,202,255,231,110,153,255,223,143,195,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,217,164,222,255,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,
-95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95,95];
-
-const TRANSPARENCY2_TRANSLUCENT:[PixelRange;100]= [
-PixelRange{line:0,src_index:240,src_end_index:400,x_begin:240,x_end:400},
-PixelRange{line:1,src_index:640,src_end_index:800,x_begin:240,x_end:400},
-PixelRange{line:2,src_index:1040,src_end_index:1200,x_begin:240,x_end:400},
-PixelRange{line:3,src_index:1440,src_end_index:1600,x_begin:240,x_end:400},
-PixelRange{line:4,src_index:1840,src_end_index:2000,x_begin:240,x_end:400},
-PixelRange{line:5,src_index:2240,src_end_index:2400,x_begin:240,x_end:400},
-PixelRange{line:6,src_index:2640,src_end_index:2800,x_begin:240,x_end:400},
-PixelRange{line:7,src_index:3040,src_end_index:3200,x_begin:240,x_end:400},
-PixelRange{line:8,src_index:3440,src_end_index:3600,x_begin:240,x_end:400},
-PixelRange{line:9,src_index:3840,src_end_index:4000,x_begin:240,x_end:400},
-PixelRange{line:10,src_index:4240,src_end_index:4400,x_begin:240,x_end:400},
-PixelRange{line:11,src_index:4640,src_end_index:4800,x_begin:240,x_end:400},
-PixelRange{line:12,src_index:5040,src_end_index:5200,x_begin:240,x_end:400},
-PixelRange{line:13,src_index:5440,src_end_index:5600,x_begin:240,x_end:400},
-PixelRange{line:14,src_index:5840,src_end_index:6000,x_begin:240,x_end:400},
-PixelRange{line:15,src_index:6240,src_end_index:6400,x_begin:240,x_end:400},
-PixelRange{line:16,src_index:6640,src_end_index:6800,x_begin:240,x_end:400},
-PixelRange{line:17,src_index:7040,src_end_index:7200,x_begin:240,x_end:400},
-PixelRange{line:18,src_index:7440,src_end_index:7600,x_begin:240,x_end:400},
-PixelRange{line:19,src_index:7840,src_end_index:8000,x_begin:240,x_end:400},
-PixelRange{line:20,src_index:8240,src_end_index:8400,x_begin:240,x_end:400},
-PixelRange{line:21,src_index:8640,src_end_index:8800,x_begin:240,x_end:400},
-PixelRange{line:22,src_index:9040,src_end_index:9200,x_begin:240,x_end:400},
-PixelRange{line:23,src_index:9440,src_end_index:9600,x_begin:240,x_end:400},
-PixelRange{line:24,src_index:9840,src_end_index:10000,x_begin:240,x_end:400},
-PixelRange{line:25,src_index:10240,src_end_index:10400,x_begin:240,x_end:400},
-PixelRange{line:26,src_index:10640,src_end_index:10800,x_begin:240,x_end:400},
-PixelRange{line:27,src_index:11040,src_end_index:11200,x_begin:240,x_end:400},
-PixelRange{line:28,src_index:11440,src_end_index:11600,x_begin:240,x_end:400},
-PixelRange{line:29,src_index:11840,src_end_index:12000,x_begin:240,x_end:400},
-PixelRange{line:30,src_index:12240,src_end_index:12400,x_begin:240,x_end:400},
-PixelRange{line:31,src_index:12640,src_end_index:12800,x_begin:240,x_end:400},
-PixelRange{line:32,src_index:13040,src_end_index:13200,x_begin:240,x_end:400},
-PixelRange{line:33,src_index:13440,src_end_index:13600,x_begin:240,x_end:400},
-PixelRange{line:34,src_index:13840,src_end_index:14000,x_begin:240,x_end:400},
-PixelRange{line:35,src_index:14240,src_end_index:14400,x_begin:240,x_end:400},
-PixelRange{line:36,src_index:14640,src_end_index:14800,x_begin:240,x_end:400},
-PixelRange{line:37,src_index:15040,src_end_index:15200,x_begin:240,x_end:400},
-PixelRange{line:38,src_index:15440,src_end_index:15600,x_begin:240,x_end:400},
-PixelRange{line:39,src_index:15840,src_end_index:16000,x_begin:240,x_end:400},
-PixelRange{line:40,src_index:16240,src_end_index:16400,x_begin:240,x_end:400},
-PixelRange{line:41,src_index:16640,src_end_index:16800,x_begin:240,x_end:400},
-PixelRange{line:42,src_index:17040,src_end_index:17200,x_begin:240,x_end:400},
-PixelRange{line:43,src_index:17440,src_end_index:17600,x_begin:240,x_end:400},
-PixelRange{line:44,src_index:17840,src_end_index:18000,x_begin:240,x_end:400},
-PixelRange{line:45,src_index:18240,src_end_index:18400,x_begin:240,x_end:400},
-PixelRange{line:46,src_index:18640,src_end_index:18800,x_begin:240,x_end:400},
-PixelRange{line:47,src_index:19040,src_end_index:19200,x_begin:240,x_end:400},
-PixelRange{line:48,src_index:19440,src_end_index:19600,x_begin:240,x_end:400},
-PixelRange{line:49,src_index:19840,src_end_index:20000,x_begin:240,x_end:400},
-PixelRange{line:50,src_index:20240,src_end_index:20400,x_begin:240,x_end:400},
-PixelRange{line:51,src_index:20640,src_end_index:20800,x_begin:240,x_end:400},
-PixelRange{line:52,src_index:21040,src_end_index:21200,x_begin:240,x_end:400},
-PixelRange{line:53,src_index:21440,src_end_index:21600,x_begin:240,x_end:400},
-PixelRange{line:54,src_index:21840,src_end_index:22000,x_begin:240,x_end:400},
-PixelRange{line:55,src_index:22240,src_end_index:22400,x_begin:240,x_end:400},
-PixelRange{line:56,src_index:22640,src_end_index:22800,x_begin:240,x_end:400},
-PixelRange{line:57,src_index:23040,src_end_index:23200,x_begin:240,x_end:400},
-PixelRange{line:58,src_index:23440,src_end_index:23600,x_begin:240,x_end:400},
-PixelRange{line:59,src_index:23840,src_end_index:24000,x_begin:240,x_end:400},
-PixelRange{line:60,src_index:24240,src_end_index:24400,x_begin:240,x_end:400},
-PixelRange{line:61,src_index:24640,src_end_index:24800,x_begin:240,x_end:400},
-PixelRange{line:62,src_index:25040,src_end_index:25200,x_begin:240,x_end:400},
-PixelRange{line:63,src_index:25440,src_end_index:25600,x_begin:240,x_end:400},
-PixelRange{line:64,src_index:25840,src_end_index:26000,x_begin:240,x_end:400},
-PixelRange{line:65,src_index:26240,src_end_index:26400,x_begin:240,x_end:400},
-PixelRange{line:66,src_index:26640,src_end_index:26800,x_begin:240,x_end:400},
-PixelRange{line:67,src_index:27040,src_end_index:27200,x_begin:240,x_end:400},
-PixelRange{line:68,src_index:27440,src_end_index:27600,x_begin:240,x_end:400},
-PixelRange{line:69,src_index:27840,src_end_index:28000,x_begin:240,x_end:400},
-PixelRange{line:70,src_index:28240,src_end_index:28400,x_begin:240,x_end:400},
-PixelRange{line:71,src_index:28640,src_end_index:28800,x_begin:240,x_end:400},
-PixelRange{line:72,src_index:29040,src_end_index:29200,x_begin:240,x_end:400},
-PixelRange{line:73,src_index:29440,src_end_index:29600,x_begin:240,x_end:400},
-PixelRange{line:74,src_index:29840,src_end_index:30000,x_begin:240,x_end:400},
-PixelRange{line:75,src_index:30240,src_end_index:30400,x_begin:240,x_end:400},
-PixelRange{line:76,src_index:30640,src_end_index:30800,x_begin:240,x_end:400},
-PixelRange{line:77,src_index:31040,src_end_index:31200,x_begin:240,x_end:400},
-PixelRange{line:78,src_index:31440,src_end_index:31600,x_begin:240,x_end:400},
-PixelRange{line:79,src_index:31840,src_end_index:32000,x_begin:240,x_end:400},
-PixelRange{line:80,src_index:32240,src_end_index:32400,x_begin:240,x_end:400},
-PixelRange{line:81,src_index:32640,src_end_index:32800,x_begin:240,x_end:400},
-PixelRange{line:82,src_index:33040,src_end_index:33200,x_begin:240,x_end:400},
-PixelRange{line:83,src_index:33440,src_end_index:33600,x_begin:240,x_end:400},
-PixelRange{line:84,src_index:33840,src_end_index:34000,x_begin:240,x_end:400},
-PixelRange{line:85,src_index:34240,src_end_index:34400,x_begin:240,x_end:400},
-PixelRange{line:86,src_index:34640,src_end_index:34800,x_begin:240,x_end:400},
-PixelRange{line:87,src_index:35040,src_end_index:35200,x_begin:240,x_end:400},
-PixelRange{line:88,src_index:35440,src_end_index:35600,x_begin:240,x_end:400},
-PixelRange{line:89,src_index:35840,src_end_index:36000,x_begin:240,x_end:400},
-PixelRange{line:90,src_index:36240,src_end_index:36400,x_begin:240,x_end:400},
-PixelRange{line:91,src_index:36640,src_end_index:36800,x_begin:240,x_end:400},
-PixelRange{line:92,src_index:37040,src_end_index:37200,x_begin:240,x_end:400},
-PixelRange{line:93,src_index:37440,src_end_index:37600,x_begin:240,x_end:400},
-PixelRange{line:94,src_index:37840,src_end_index:38000,x_begin:240,x_end:400},
-PixelRange{line:95,src_index:38240,src_end_index:38400,x_begin:240,x_end:400},
-PixelRange{line:96,src_index:38640,src_end_index:38800,x_begin:240,x_end:400},
-PixelRange{line:97,src_index:39040,src_end_index:39200,x_begin:240,x_end:400},
-PixelRange{line:98,src_index:39440,src_end_index:39600,x_begin:240,x_end:400},
-PixelRange{line:99,src_index:39600,src_end_index:40000,x_begin:0,x_end:400},
-];
-
-const TRANSPARENCY2_SOLID:[PixelRange;99]= [
-PixelRange{line:0,src_index:0,src_end_index:240,x_begin:0,x_end:240},
-PixelRange{line:1,src_index:400,src_end_index:640,x_begin:0,x_end:240},
-PixelRange{line:2,src_index:800,src_end_index:1040,x_begin:0,x_end:240},
-PixelRange{line:3,src_index:1200,src_end_index:1440,x_begin:0,x_end:240},
-PixelRange{line:4,src_index:1600,src_end_index:1840,x_begin:0,x_end:240},
-PixelRange{line:5,src_index:2000,src_end_index:2240,x_begin:0,x_end:240},
-PixelRange{line:6,src_index:2400,src_end_index:2640,x_begin:0,x_end:240},
-PixelRange{line:7,src_index:2800,src_end_index:3040,x_begin:0,x_end:240},
-PixelRange{line:8,src_index:3200,src_end_index:3440,x_begin:0,x_end:240},
-PixelRange{line:9,src_index:3600,src_end_index:3840,x_begin:0,x_end:240},
-PixelRange{line:10,src_index:4000,src_end_index:4240,x_begin:0,x_end:240},
-PixelRange{line:11,src_index:4400,src_end_index:4640,x_begin:0,x_end:240},
-PixelRange{line:12,src_index:4800,src_end_index:5040,x_begin:0,x_end:240},
-PixelRange{line:13,src_index:5200,src_end_index:5440,x_begin:0,x_end:240},
-PixelRange{line:14,src_index:5600,src_end_index:5840,x_begin:0,x_end:240},
-PixelRange{line:15,src_index:6000,src_end_index:6240,x_begin:0,x_end:240},
-PixelRange{line:16,src_index:6400,src_end_index:6640,x_begin:0,x_end:240},
-PixelRange{line:17,src_index:6800,src_end_index:7040,x_begin:0,x_end:240},
-PixelRange{line:18,src_index:7200,src_end_index:7440,x_begin:0,x_end:240},
-PixelRange{line:19,src_index:7600,src_end_index:7840,x_begin:0,x_end:240},
-PixelRange{line:20,src_index:8000,src_end_index:8240,x_begin:0,x_end:240},
-PixelRange{line:21,src_index:8400,src_end_index:8640,x_begin:0,x_end:240},
-PixelRange{line:22,src_index:8800,src_end_index:9040,x_begin:0,x_end:240},
-PixelRange{line:23,src_index:9200,src_end_index:9440,x_begin:0,x_end:240},
-PixelRange{line:24,src_index:9600,src_end_index:9840,x_begin:0,x_end:240},
-PixelRange{line:25,src_index:10000,src_end_index:10240,x_begin:0,x_end:240},
-PixelRange{line:26,src_index:10400,src_end_index:10640,x_begin:0,x_end:240},
-PixelRange{line:27,src_index:10800,src_end_index:11040,x_begin:0,x_end:240},
-PixelRange{line:28,src_index:11200,src_end_index:11440,x_begin:0,x_end:240},
-PixelRange{line:29,src_index:11600,src_end_index:11840,x_begin:0,x_end:240},
-PixelRange{line:30,src_index:12000,src_end_index:12240,x_begin:0,x_end:240},
-PixelRange{line:31,src_index:12400,src_end_index:12640,x_begin:0,x_end:240},
-PixelRange{line:32,src_index:12800,src_end_index:13040,x_begin:0,x_end:240},
-PixelRange{line:33,src_index:13200,src_end_index:13440,x_begin:0,x_end:240},
-PixelRange{line:34,src_index:13600,src_end_index:13840,x_begin:0,x_end:240},
-PixelRange{line:35,src_index:14000,src_end_index:14240,x_begin:0,x_end:240},
-PixelRange{line:36,src_index:14400,src_end_index:14640,x_begin:0,x_end:240},
-PixelRange{line:37,src_index:14800,src_end_index:15040,x_begin:0,x_end:240},
-PixelRange{line:38,src_index:15200,src_end_index:15440,x_begin:0,x_end:240},
-PixelRange{line:39,src_index:15600,src_end_index:15840,x_begin:0,x_end:240},
-PixelRange{line:40,src_index:16000,src_end_index:16240,x_begin:0,x_end:240},
-PixelRange{line:41,src_index:16400,src_end_index:16640,x_begin:0,x_end:240},
-PixelRange{line:42,src_index:16800,src_end_index:17040,x_begin:0,x_end:240},
-PixelRange{line:43,src_index:17200,src_end_index:17440,x_begin:0,x_end:240},
-PixelRange{line:44,src_index:17600,src_end_index:17840,x_begin:0,x_end:240},
-PixelRange{line:45,src_index:18000,src_end_index:18240,x_begin:0,x_end:240},
-PixelRange{line:46,src_index:18400,src_end_index:18640,x_begin:0,x_end:240},
-PixelRange{line:47,src_index:18800,src_end_index:19040,x_begin:0,x_end:240},
-PixelRange{line:48,src_index:19200,src_end_index:19440,x_begin:0,x_end:240},
-PixelRange{line:49,src_index:19600,src_end_index:19840,x_begin:0,x_end:240},
-PixelRange{line:50,src_index:20000,src_end_index:20240,x_begin:0,x_end:240},
-PixelRange{line:51,src_index:20400,src_end_index:20640,x_begin:0,x_end:240},
-PixelRange{line:52,src_index:20800,src_end_index:21040,x_begin:0,x_end:240},
-PixelRange{line:53,src_index:21200,src_end_index:21440,x_begin:0,x_end:240},
-PixelRange{line:54,src_index:21600,src_end_index:21840,x_begin:0,x_end:240},
-PixelRange{line:55,src_index:22000,src_end_index:22240,x_begin:0,x_end:240},
-PixelRange{line:56,src_index:22400,src_end_index:22640,x_begin:0,x_end:240},
-PixelRange{line:57,src_index:22800,src_end_index:23040,x_begin:0,x_end:240},
-PixelRange{line:58,src_index:23200,src_end_index:23440,x_begin:0,x_end:240},
-PixelRange{line:59,src_index:23600,src_end_index:23840,x_begin:0,x_end:240},
-PixelRange{line:60,src_index:24000,src_end_index:24240,x_begin:0,x_end:240},
-PixelRange{line:61,src_index:24400,src_end_index:24640,x_begin:0,x_end:240},
-PixelRange{line:62,src_index:24800,src_end_index:25040,x_begin:0,x_end:240},
-PixelRange{line:63,src_index:25200,src_end_index:25440,x_begin:0,x_end:240},
-PixelRange{line:64,src_index:25600,src_end_index:25840,x_begin:0,x_end:240},
-PixelRange{line:65,src_index:26000,src_end_index:26240,x_begin:0,x_end:240},
-PixelRange{line:66,src_index:26400,src_end_index:26640,x_begin:0,x_end:240},
-PixelRange{line:67,src_index:26800,src_end_index:27040,x_begin:0,x_end:240},
-PixelRange{line:68,src_index:27200,src_end_index:27440,x_begin:0,x_end:240},
-PixelRange{line:69,src_index:27600,src_end_index:27840,x_begin:0,x_end:240},
-PixelRange{line:70,src_index:28000,src_end_index:28240,x_begin:0,x_end:240},
-PixelRange{line:71,src_index:28400,src_end_index:28640,x_begin:0,x_end:240},
-PixelRange{line:72,src_index:28800,src_end_index:29040,x_begin:0,x_end:240},
-PixelRange{line:73,src_index:29200,src_end_index:29440,x_begin:0,x_end:240},
-PixelRange{line:74,src_index:29600,src_end_index:29840,x_begin:0,x_end:240},
-PixelRange{line:75,src_index:30000,src_end_index:30240,x_begin:0,x_end:240},
-PixelRange{line:76,src_index:30400,src_end_index:30640,x_begin:0,x_end:240},
-PixelRange{line:77,src_index:30800,src_end_index:31040,x_begin:0,x_end:240},
-PixelRange{line:78,src_index:31200,src_end_index:31440,x_begin:0,x_end:240},
-PixelRange{line:79,src_index:31600,src_end_index:31840,x_begin:0,x_end:240},
-PixelRange{line:80,src_index:32000,src_end_index:32240,x_begin:0,x_end:240},
-PixelRange{line:81,src_index:32400,src_end_index:32640,x_begin:0,x_end:240},
-PixelRange{line:82,src_index:32800,src_end_index:33040,x_begin:0,x_end:240},
-PixelRange{line:83,src_index:33200,src_end_index:33440,x_begin:0,x_end:240},
-PixelRange{line:84,src_index:33600,src_end_index:33840,x_begin:0,x_end:240},
-PixelRange{line:85,src_index:34000,src_end_index:34240,x_begin:0,x_end:240},
-PixelRange{line:86,src_index:34400,src_end_index:34640,x_begin:0,x_end:240},
-PixelRange{line:87,src_index:34800,src_end_index:35040,x_begin:0,x_end:240},
-PixelRange{line:88,src_index:35200,src_end_index:35440,x_begin:0,x_end:240},
-PixelRange{line:89,src_index:35600,src_end_index:35840,x_begin:0,x_end:240},
-PixelRange{line:90,src_index:36000,src_end_index:36240,x_begin:0,x_end:240},
-PixelRange{line:91,src_index:36400,src_end_index:36640,x_begin:0,x_end:240},
-PixelRange{line:92,src_index:36800,src_end_index:37040,x_begin:0,x_end:240},
-PixelRange{line:93,src_index:37200,src_end_index:37440,x_begin:0,x_end:240},
-PixelRange{line:94,src_index:37600,src_end_index:37840,x_begin:0,x_end:240},
-PixelRange{line:95,src_index:38000,src_end_index:38240,x_begin:0,x_end:240},
-PixelRange{line:96,src_index:38400,src_end_index:38640,x_begin:0,x_end:240},
-PixelRange{line:97,src_index:38800,src_end_index:39040,x_begin:0,x_end:240},
-PixelRange{line:98,src_index:39200,src_end_index:39440,x_begin:0,x_end:240},
-
-];
@@ -1,358 +1,534 @@
 // ===============================
 //             TEXTPARSER.RS
 // -------------------------------
-//  interacts with c libraries for image parsing and wraps them in safe functions for 
-//  use in the rest of the code
+//  interacts with c libraries for image parsing and wraps them in safe functions for
+//  use in the rest of the code. fonts are rasterized once per size into an atlas,
+//  strings are then put together from the atlas into sprites the display draws
 // -------------------------------
 
 use std::{
-    alloc::{alloc, Layout}, mem::ManuallyDrop, ops::Range, ptr::{copy_nonoverlapping, NonNull}, slice::from_raw_parts
+    collections::HashMap, io::Error, ops::Range, sync::Arc
 };
 
-use crate::graphics::parse_ttf_bindings::{generate_glyph, LoadedFont, LoadedGlyph};
-
-use josie_collections::josie_vec::{josievec_extend::ExtendType, JosieVec};
+use crate::{
+    engine::layer::Layer,
+    graphics::{
+        parse_ttf_bindings::{self, find_glyph_index, generate_glyph, get_codepoint_bitmap_box, get_codepoint_hmetrics, get_font_vmetrics, get_kerning, is_glyph_empty, scale_for_pixel_height, LoadedFont, LoadedGlyph},
+        bitmapfont::BitmapGlyph,
+        richtext::{parse_markup, MarkupError, RichText, Run},
+        sdf::{SdfFont, TextEffects},
+        sprites::{convert::premultiply, Sprite},
+        textlayout::{layout_text, TextBox, TextLayout},
+    },
+    shared::{Point, Rect, Size, BPP},
+};
 
-pub(crate) struct GlyphRange{
-    line:usize,
-    range:Range<usize>
+///GlyphRange is a run of pixels on one line of a glyph that are all fully covered or all partly covered
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GlyphRange{
+    pub line:usize,
+    ///x positions inside the glyph
+    pub range:Range<usize>,
 }
 
-pub(crate) struct SizedGlyph<'a>{
-    char:char,
-    transparent_indexes:&'a [Range<usize>],
-    solid_indexes:&'a [Range<usize>],
+///SizedGlyph is one char rasterized at one size. its coverage lives in the atlas of the sized font it belongs to
+#[derive(Clone, Debug, Default)]
+pub struct SizedGlyph{
+    pub char:char,
+    ///size of the bitmap, zero for chars with nothing to draw like spaces
+    pub size:Size,
+    ///offset from the pen position on the baseline to the top left of the bitmap
+    pub xoff:i32,
+    pub yoff:i32,
+    ///pixels the pen moves right after drawing the glyph
+    pub advance:f32,
+    //where the glyphs coverage starts in the atlas, one byte per pixel
+    atlas_index:usize,
+    translucent_ranges:Vec<GlyphRange>,
+    solid_ranges:Vec<GlyphRange>,
 }
 
-impl<'a> Default for SizedGlyph<'a>{
-    fn default() -> Self {
-        Self { char: Default::default(), transparent_indexes: Default::default(), solid_indexes: Default::default() }
+impl SizedGlyph{
+    ///Copies the coverage of loaded_glyph to the end of the atlas and splits it into ranges
+    pub fn push_ranges(&mut self, atlas:&mut Vec<u8>, loaded_glyph:&LoadedGlyph){
+        self.atlas_index = atlas.len();
+        self.size = Size::is(loaded_glyph.width as usize, loaded_glyph.height as usize);
+        self.xoff = loaded_glyph.xoff;
+        self.yoff = loaded_glyph.yoff;
+        loaded_glyph.push_data(atlas);
+        loaded_glyph.push_ranges(&mut self.translucent_ranges, &mut self.solid_ranges);
+    }
+    ///Same as push_ranges for coverage that didnt come from stbtt, like the glyphs of bitmap fonts
+    pub fn push_coverage(&mut self, atlas:&mut Vec<u8>, coverage:&[u8], size:Size){
+        self.atlas_index = atlas.len();
+        self.size = size;
+        atlas.extend_from_slice(&coverage[..size.pixels()]);
+        split_coverage(coverage, size.width, &mut self.translucent_ranges, &mut self.solid_ranges);
     }
 }
 
-impl<'a> SizedGlyph<'a>{
-    pub fn push_ranges(&mut self,font_index:usize ,atlas:&mut JosieVec<u8>, loaded_glyph:&LoadedGlyph){
-        unsafe{atlas.bulk_extend_guarded(ExtendType::Ammortized, |start_ptr,end_ptr|{
-            //creates working josievecs
-            let mut translucent_ranges:JosieVec<GlyphRange> = JosieVec::with_capacity(loaded_glyph.size);
-            let mut solid_ranges:JosieVec<GlyphRange> = JosieVec::with_capacity(loaded_glyph.size);
-            //the most disgusting typecast i think i have ever done lol, needed to 
-            let ptr =
-                {
-                    let offset = start_ptr.align_offset(align_of::<GlyphRange>());
-                    start_ptr.add(offset);
-                    &mut *(start_ptr as *mut *mut u8 as *mut *mut GlyphRange)
-                };
-            //creates a new slice contaning all the sprite data
-            let glyph_data = loaded_glyph.as_slice();
-            //chunks the glyph sprite into lines to be processed 
-            let lines = glyph_data.chunks_exact(loaded_glyph.width as usize);
-            //iterates through the chunks in lines and enumerates them with a line number
-            for (line,data) in lines.enumerate(){
-                //creates variable curr index which holds the indsex at which the current range starts
-                let mut range_begin = 0usize;
-                // creates new iteration over the lines
-                for (i, (curr,next)) in data.iter()
-                //zips in next value with current value
-                .zip(data.iter().skip(1))
-                //maps curr and next with derferenced versions and enumerates
-                .map(|(curr,next)|(*curr, *next)).enumerate(){
-                    //if current pixel is not equal to the next or at the end of a line, push the current range to the requestie vec
-                    if curr != next || data.len() == i{
-                        match_ranges(&mut translucent_ranges,  &mut solid_ranges, curr,line, range_begin, i);
-                        range_begin = i
-                    }
-                }
+///Splits coverage, one byte a pixel in lines width long, into runs of fully and partly covered pixels. uncovered runs are left out
+pub fn split_coverage(coverage:&[u8], width:usize, translucent_ranges:&mut Vec<GlyphRange>, solid_ranges:&mut Vec<GlyphRange>){
+    if width == 0{return;}
+    for (line, data) in coverage.chunks_exact(width).enumerate(){
+        let mut x = 0;
+        while x < width{
+            //finds the end of the run of pixels with the same kind of coverage as the one at x
+            let start = x;
+            let kind = |coverage:u8|match coverage{0 => 0, 255 => 2, _ => 1};
+            while x < width && kind(data[x]) == kind(data[start]){
+                x += 1;
+            }
+            match kind(data[start]){
+                1 => translucent_ranges.push(GlyphRange{line, range:start..x}),
+                2 => solid_ranges.push(GlyphRange{line, range:start..x}),
+                _ =>{}
             }
-        })};
+        }
     }
 }
-//helper function to make my code a bit cleaner, takes in the current pixel value and pushes the glyph range struct to the correct buffer.
-fn match_ranges(translucent_ranges:&mut JosieVec<GlyphRange>, solid_ranges: &mut JosieVec<GlyphRange>, curr:u8, line:usize, range_begin:usize, i:usize){
-    match curr{
-        255=>{
-            solid_ranges.push(
-                GlyphRange {
-                    line,
-                    range:range_begin..i
-                }
-            );
+
+///SizedFont is every char of a charset rasterized at one size, along with the kerning between them
+#[derive(Clone, Debug)]
+pub struct SizedFont{
+    pub font_size:f32,
+    ///pixels from the top of a line down to the baseline, enough for the tallest glyph in the charset
+    pub ascent:i32,
+    ///pixels from the baseline down to the bottom of a line, enough for the lowest glyph in the charset
+    pub descent:i32,
+    ///extra pixels the font asks for between one line and the next
+    pub line_gap:i32,
+    ///pixels from one baseline to the next, the fonts own ascent and descent plus its line gap
+    pub line_spacing:i32,
+    //coverage of every glyph back to back
+    atlas:Vec<u8>,
+    //sorted by char so lookups can binary search
+    glyphs:Vec<SizedGlyph>,
+    //kerning in pixels for every pair of chars in the charset that has any
+    kerning:HashMap<(char, char), f32>,
+}
+
+impl SizedFont{
+    ///Glyph for char, none if it wasnt in the charset the font was sized with
+    pub fn glyph(&self, char:char) -> Option<&SizedGlyph>{
+        self.glyphs.binary_search_by_key(&char, |glyph|glyph.char).ok().map(|index|&self.glyphs[index])
+    }
+    ///Replaces the line gap and line spacing, for fonts put together from glyphs that know more about their lines than the glyphs do
+    pub fn with_line_spacing(mut self, line_gap:i32, line_spacing:i32) -> Self{
+        self.line_gap = line_gap;
+        self.line_spacing = line_spacing;
+        self
+    }
+    ///Replaces the kerning between pairs of chars, in pixels
+    pub fn with_kerning(mut self, kerning:HashMap<(char, char), f32>) -> Self{
+        self.kerning = kerning;
+        self
+    }
+    ///Number of chars the font has glyphs for
+    pub fn glyphs_len(&self) -> usize{
+        self.glyphs.len()
+    }
+    ///Pixels to add between first and second on top of the advance of first
+    pub fn kerning(&self, first:char, second:char) -> f32{
+        self.kerning.get(&(first, second)).copied().unwrap_or(0.0)
+    }
+    ///Height of one line of text
+    pub fn line_height(&self) -> usize{
+        (self.ascent + self.descent).max(0) as usize
+    }
+    ///Coverage of glyph, one byte a pixel in reading order
+    pub fn coverage(&self, glyph:&SizedGlyph) -> &[u8]{
+        &self.atlas[glyph.atlas_index..glyph.atlas_index + glyph.size.pixels()]
+    }
+    //walks text the way it is drawn, calling place with every glyph and the x of its pen position. chars missing from the charset are skipped
+    pub(crate) fn layout(&self, text:&str, mut place:impl FnMut(&SizedGlyph, i32)){
+        let mut pen = 0.0;
+        let mut previous = None;
+        for glyph in text.chars().filter_map(|char|self.glyph(char)){
+            if let Some(previous) = previous{
+                pen += self.kerning(previous, glyph.char);
+            }
+            place(glyph, pen.round() as i32);
+            pen += glyph.advance;
+            previous = Some(glyph.char);
         }
-        0=>(),
-        _=>{
-            translucent_ranges.push(
-                GlyphRange {
-                    line,
-                    range:range_begin..i
-                }
-            );
+    }
+    ///Left and right edge of everything text covers on one line, pen start is 0. includes the advance so trailing spaces count
+    pub fn horizontal_bounds(&self, text:&str) -> (i32, i32){
+        let (mut left, mut right) = (0, 0);
+        self.layout(text, |glyph, pen|{
+            left = left.min(pen + glyph.xoff);
+            right = right.max(pen + glyph.xoff + glyph.size.width as i32).max(pen + glyph.advance.ceil() as i32);
+        });
+        (left, right)
+    }
+    ///Size text takes up on one line
+    pub fn measure(&self, text:&str) -> Size{
+        let (left, right) = self.horizontal_bounds(text);
+        Size::is((right - left) as usize, self.line_height())
+    }
+    ///Draws text on one line into a sprite of straight alpha color. returns the sprite and where its top left sits relative to the top of the line
+    ///at the pen start, which is only off zero when a glyph hangs left of where the pen starts
+    pub fn render(&self, text:&str, color:[u8;4]) -> (Sprite, Point){
+        let (left, right) = self.horizontal_bounds(text);
+        let size = Size::is((right - left) as usize, self.line_height());
+        let mut coverage = vec![0u8;size.pixels()];
+        self.draw_line(&mut coverage, size, text, -left, 0);
+        (colorize(coverage, size, color), Point::at(left as f32, 0.0))
+    }
+    ///Draws every line of layout into one sprite of straight alpha color. returns the sprite and where its top left sits relative to the
+    ///top left of the box the text was laid out in
+    pub fn render_layout(&self, layout:&TextLayout, color:[u8;4]) -> (Sprite, Point){
+        let bounds = layout.bounds;
+        let size = Size::is(bounds.width() as usize, bounds.height() as usize);
+        let mut coverage = vec![0u8;size.pixels()];
+        for line in layout.lines.iter(){
+            self.draw_line(&mut coverage, size, &line.text, line.x - bounds.x0, line.y - bounds.y0);
         }
+        (colorize(coverage, size, color), Point::at(bounds.x0 as f32, bounds.y0 as f32))
     }
+    //adds the coverage of text to a coverage buffer of size with the pen starting at x and the top of the line at y. glyphs that overlap
+    //keep whichever covers more, anything outside the buffer is dropped
+    fn draw_line(&self, coverage:&mut [u8], size:Size, text:&str, x:i32, y:i32){
+        let clip = Rect::of_size(size);
+        self.layout(text, |glyph, pen|{
+            let glyph_x = x + pen + glyph.xoff;
+            let glyph_y = y + self.ascent + glyph.yoff;
+            let source = self.coverage(glyph);
+            for (ranges, solid) in [(&glyph.solid_ranges, true), (&glyph.translucent_ranges, false)]{
+                for range in ranges{
+                    let line = glyph_y + range.line as i32;
+                    let span = Rect::new(glyph_x + range.range.start as i32, line, glyph_x + range.range.end as i32, line + 1).intersect(&clip);
+                    if span.is_empty(){continue;}
+                    let start = line as usize*size.width;
+                    let destination = &mut coverage[start + span.x0 as usize..start + span.x1 as usize];
+                    if solid{
+                        destination.fill(255);
+                    }else{
+                        //skips however much of the range got clipped off the left
+                        let source_start = range.line*glyph.size.width + (span.x0 - glyph_x) as usize;
+                        let source = &source[source_start..source_start + destination.len()];
+                        for (dst, src) in destination.iter_mut().zip(source){
+                            *dst = (*dst).max(*src);
+                        }
+                    }
+                }
+            }
+        });
+    }
+}
 
+//turns a coverage buffer into a premultiplied sprite of color
+fn colorize(coverage:Vec<u8>, size:Size, color:[u8;4]) -> Sprite{
+    let mut data = Vec::with_capacity(size.pixels()*BPP);
+    for covered in coverage{
+        let alpha = (color[3] as u16*covered as u16 + 127)/255;
+        data.extend_from_slice(&[color[0], color[1], color[2], alpha as u8]);
+    }
+    premultiply(&mut data);
+    Sprite::from_rgba(data, size)
 }
 
-pub(crate) struct FontAtlas{
-    ptr:NonNull<u8>,
-    len:usize
+///Font is a loaded font file and every size it has been rasterized at so far
+pub struct Font{
+    font_handle:LoadedFont,
+    //every size rasterized so far along with the sorted charset it was rasterized from
+    sized_fonts:Vec<(Vec<char>, Arc<SizedFont>)>,
 }
-pub struct SizedFont<'a>{
-    atlas:JosieVec<u8>,
-    glyphs:&'a [SizedGlyph<'a>]
 
+impl Font{
+    ///Sized font for font_size with every char of charset the font has. a size already rasterized with all of charset is reused,
+    ///otherwise the size is rasterized again with the chars it had and the new ones. sized fonts already handed out keep working
+    pub fn sized(&mut self, font_size:f32, charset:&[char]) -> Result<Arc<SizedFont>, Error>{
+        let mut charset = charset.to_vec();
+        charset.sort_unstable();
+        charset.dedup();
+        let existing = self.sized_fonts.iter().position(|(_, sized_font)|sized_font.font_size == font_size);
+        if let Some(index) = existing{
+            let (rasterized, sized_font) = &self.sized_fonts[index];
+            if charset.iter().all(|char|rasterized.binary_search(char).is_ok()){
+                return Ok(sized_font.clone());
+            }
+            charset.extend_from_slice(rasterized);
+            charset.sort_unstable();
+            charset.dedup();
+        }
+        let sized_font = Arc::new(create_sized_font(&mut self.font_handle, font_size, &charset)?);
+        match existing{
+            Some(index) => self.sized_fonts[index] = (charset, sized_font.clone()),
+            None => self.sized_fonts.push((charset, sized_font.clone())),
+        }
+        Ok(sized_font)
+    }
+    pub fn loaded_font(&mut self) -> &mut LoadedFont{
+        &mut self.font_handle
+    }
+    ///Switches to another face of a collection. sizes rasterized from the old face are dropped, sized fonts already handed out keep working
+    pub fn select_face(&mut self, face:usize) -> Result<(), Error>{
+        parse_ttf_bindings::select_face(&mut self.font_handle, face)?;
+        self.sized_fonts.clear();
+        Ok(())
+    }
+    ///True if the font has a glyph for char instead of falling back on its missing glyph
+    pub fn has_glyph(&mut self, char:char) -> bool{
+        find_glyph_index(&mut self.font_handle, char).is_some()
+    }
+    ///Size text would take up on one line at font_size, straight from the font metrics so nothing has to be rasterized.
+    ///the height is the fonts own ascent and descent, so it can differ a pixel from a sized font which only counts the glyphs it has
+    pub fn measure(&mut self, font_size:f32, text:&str) -> Size{
+        let font = &mut self.font_handle;
+        let scale = scale_for_pixel_height(font, font_size);
+        let (mut pen, mut left, mut right) = (0.0f32, 0, 0);
+        let mut previous = None;
+        for char in text.chars(){
+            if let Some(previous) = previous{
+                pen += get_kerning(font, previous, char) as f32*scale;
+            }
+            let x = pen.round() as i32;
+            let bitmap_box = get_codepoint_bitmap_box(font, char, scale);
+            let (advance, _) = get_codepoint_hmetrics(font, char);
+            if !bitmap_box.is_empty(){
+                left = left.min(x + bitmap_box.x0);
+                right = right.max(x + bitmap_box.x1);
+            }
+            pen += advance as f32*scale;
+            right = right.max(x + (advance as f32*scale).ceil() as i32);
+            previous = Some(char);
+        }
+        let (ascent, descent, _) = get_font_vmetrics(font);
+        Size::is((right - left) as usize, ((ascent - descent) as f32*scale).ceil() as usize)
+    }
 }
-impl<'a> Default for SizedFont<'a>{
-    fn default() -> Self {
-        Self { atlas: Default::default(), glyphs: Default::default() }
+
+///Wraps a loaded font file so it can be rasterized at different sizes
+pub fn load_font(loaded_font:LoadedFont) -> Font{
+    Font{
+        font_handle:loaded_font,
+        sized_fonts:Vec::new(),
     }
 }
 
-pub struct Font<'a>{
-    font_handle:LoadedFont,
-    sized_fonts:Vec<SizedFont<'a>>
+///Printable ascii, space to tilde
+pub fn ascii_charset() -> Vec<char>{
+    (' '..='~').collect()
 }
 
-pub fn load_font<'a>(loaded_font:LoadedFont) -> Font<'a>{
-    todo!();
+///Rasterizes every char in charset at font_size pixels tall
+pub fn create_sized_font(loaded_font:&mut LoadedFont, font_size:f32, charset:&[char]) -> Result<SizedFont, Error>{
+    let mut atlas = Vec::new();
+    let rasterized = rasterize_chars(loaded_font, font_size, charset, |loaded_font, char|{
+        let mut sized_glyph = SizedGlyph{char, ..Default::default()};
+        sized_glyph.push_ranges(&mut atlas, &generate_glyph(loaded_font, char, font_size)?);
+        Ok(sized_glyph)
+    })?;
+    atlas.shrink_to_fit();
+    let glyphs:Vec<SizedGlyph> = rasterized.glyphs.into_iter().map(|(char, advance, glyph)|SizedGlyph{char, advance, ..glyph.unwrap_or_default()}).collect();
+    let (ascent, descent) = glyph_extents(&glyphs);
+    let metrics = rasterized.metrics;
+    Ok(SizedFont{
+        font_size,
+        ascent,
+        descent,
+        line_gap:metrics.line_gap.round() as i32,
+        line_spacing:metrics.line_spacing.round() as i32,
+        atlas,
+        glyphs,
+        kerning:rasterized.kerning,
+    })
 }
 
-pub fn create_sized_font<'a>(loaded_font:&mut LoadedFont, font_size:f32, charset:&[char]) -> SizedFont<'a>{
-    //creates defauld uninitialized sized font struct
-    let mut sized_font = SizedFont::default();
-    //working storage for the generated glyphs, dropped and contents copied to end of bump at the end of sized font generation
-    let mut working_glyphs:JosieVec<SizedGlyph<'a>> = JosieVec::with_capacity(charset.len());
-    //reserves capacity in the font atlas for worst case scenerio, note vec is only being used here as storage for the contents, drop code will treat like u8 which is fine because nothing that will be stored here will do heap allocations
-    sized_font.atlas.reserve_exact(9*(font_size as usize)^2 + charset.len()*4);
-    //iterates through the charset
-    for char in charset.iter().map(|char|char.clone()){
-        let glyph = generate_glyph(loaded_font, char, font_size).expect(&format!("faileds to generate glyph {}", char));=
-        unsafe{
-            //copies the content of the memory allocation to the point on the vector
-            copy_nonoverlapping(glyph.ptr, sized_font.atlas.as_mut_ptr().add(sized_font.atlas.len()), glyph.size);
-            //new len is the  len after the copy_nonoverlapping
-            let new_len = sized_font.atlas.len() + glyph.size;
-            //current range of the loaded sprite in the josievec bump
-            let curr_index = sized_font.atlas.len();
-            //manually sets length to new size
-            sized_font.atlas.set_len(new_len);
-            //creates new sized glyph struct
-            let mut sized_glyph = SizedGlyph::default();
-            //pushes translucent and solid ranges to the new sized_glyph
-            sized_glyph.push_ranges(curr_index,&mut sized_font.atlas,&glyph);
-            working_glyphs.push(sized_glyph);
+///FontMetrics is how a font lays out lines at one size, in pixels. descent is how far below the baseline lines reach so it is positive
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FontMetrics{
+    pub scale:f32,
+    pub ascent:f32,
+    pub descent:f32,
+    pub line_gap:f32,
+    ///baseline to baseline
+    pub line_spacing:f32,
+}
 
+impl FontMetrics{
+    pub fn new(loaded_font:&mut LoadedFont, font_size:f32) -> Self{
+        let scale = scale_for_pixel_height(loaded_font, font_size);
+        let (ascent, descent, line_gap) = get_font_vmetrics(loaded_font);
+        FontMetrics{
+            scale,
+            ascent:ascent as f32*scale,
+            descent:-descent as f32*scale,
+            line_gap:line_gap as f32*scale,
+            line_spacing:(ascent - descent + line_gap) as f32*scale,
         }
     }
-    unsafe{
-        let glyphs_ptr = sized_font.atlas.as_mut_ptr().add(sized_font.atlas.len()) as *mut SizedGlyph<'a>;
-        copy_nonoverlapping(
-            //source is the working glyphs pointer
-            working_glyphs.as_mut_ptr(), 
-            //destination is the end of the atlas
-            glyphs_ptr,
-            //the number of working glyphs created
-            working_glyphs.len()
-        );
-        //creates a slice from raw parts
-        sized_font.glyphs = from_raw_parts(glyphs_ptr, working_glyphs.len());
-    }
-    sized_font.atlas.shrink_to_fit();
-
-    sized_font
-    
 }
 
+///RasterizedChars is what rasterize_chars made of a charset
+pub struct RasterizedChars<G>{
+    pub metrics:FontMetrics,
+    ///every char the font has in order, with its advance and what it was rasterized into. none for chars with nothing to draw
+    pub glyphs:Vec<(char, f32, Option<G>)>,
+    ///kerning between every pair of those chars, in pixels
+    pub kerning:HashMap<(char, char), f32>,
+}
 
-// use std::{
-//     collections::HashMap, f32::MAX, io::Error, ops::Range
-// };
-
-// use bumpalo::Bump;
-// use smallvec::SmallVec;
-
-// use crate::{
-//     graphics::{parse_ttf_bindings::*, renderer::Renderable},
-//     shared::{Point, Size},
-// };
-
-// impl Renderable for String{
-//     fn render(&self, point:Point){
-//         todo!();
-//     }
-// }
-
-// ///Sets the default preallocation for the hash map of fonts. 
-// const FONTS_PREALLOC:usize = 64;
-// ///Sets the preallocated vec size for different font sizes per font struct. if you use more than 16 sizes of the same font
-// ///nudge this up to avoid reallocations.
-// const FONT_SIZE_PREALLOC:usize = 16;
-
-// ///Preallocates on the stack 1024kb worth of space for computing font values smallvec
-// const MAX_FONT_SMALLVEC_PREALLOC:usize = 1048576;
-
-// ///FontCode is the tuple struct in which is used as the key for the hashmap. It contains a string for font name and usize for size of font
-// pub type FontCode = String;
-// ///Char sprite is a tuple struct that contains a dynsprite for 
-// pub struct CharCode{
-//     pub char:char,
-//     pub code:u32,
-// }
-
-// #[derive(Debug)]
-// pub struct GlyphSprite<'a>{
-//     pub size:Size,
-//     pub offset:Point,
-//     pub translucent_ranges:&'a [TranslucentRange],
-//     pub solid_ranges:&'a [SolidRange],
-// }
-
-// impl<'a> GlyphSprite<'a>{
-//     ///constructor for GlyphSprite struct.
-//     pub fn new(glyph:&LoadedGlyph,index:usize,translucent_index:usize,solid_index:usize) -> Self{
-//         //creates new size struct with the width and height held inside of 
-//         let glyph_size = Size::is(glyph.width as usize,glyph.height as usize);
-//         //creates new point for the offsett of the glyph based
-//         let glyph_offset = Point::at(glyph.xoff as f32, glyph.yoff as f32);
-//         let data_slice:&'a [u8] = [index..index+glyph_size.pixels()];
-//         Self{
-//             //Range starts in the index, which is the current length of the data vector in parent font struct and ends
-//             //in the index plus number of pixels 
-//             size:glyph_size,
-//             offset:glyph_offset,
-//             translucent_ranges,
-//             solid_ranges,
-//         }
-//     }
-// }
-
-
-
-// pub type FontAtlas = &'a [u8];
-
-// pub struct PixelIndex<'a>{
-//     //index of raw sprite data
-//     index:&'a[u8],
-//     //line number
-//     pub line:usize,
-//     //position of the beginning of the block
-//     pub x_begin:usize,
-//     //offset from x
-//     pub x_end:usize,
-// }
-
-// pub type TranslucentIndex = PixelIndex;
-
-// pub type SolidIndex = PixelIndex;
-
-// pub type TranslucentRange =  &'a [TranslucentIndex];
-
-// pub type SolidRange = &'a [SolidIndex];
-
-// pub type TranslucentRanges = &'a [TranslucentRange];
-
-// pub type SolidRanges = &'a [SolidRange];
-
-// pub struct SizedFont<'a>{
-//     ///contains a vector of the struct CharSprite
-//     pub data:Bump,
-//     pub font_atlas:&'a [u8],
-//     pub metadata:&'a [GlyphSprite<'a>],
-// }
-
-// impl<'a> SizedFont<'a>{
-//     fn new_sized_font(&mut self, loaded_font:&mut LoadedFont ,charset:&[()], font_size:f32) -> Result<(),Error>{
-//         //capactity is max size of the atlas given every character generates a bitmap exatly the size of the font size every time
-//         //at the end of sized font generation it is shrunk to fit the actual capacity and then bumpaloed into an area of memory
-//         //contiguous with the range indexes for improved cache performance on text rasterization. :3
-//         let capacity:usize = font_size as usize^2 * charset.len();
-//         //sets working atlas to be a vector with capacity capacity 
-//         let working_font_atlas:Vec<u8> = Vec::with_capacity(capacity);
-//         //both working ranges have a stack allocated size equal to 1024(way more than enough for pretty much every font)
-//         //but are both heap allocated to worst case scenerio (font where every pixel alternates between translucent and solid)
-//         let working_translucent_ranges:SmallVec<[Range<u8>;1024]> = SmallVec::with_capacity(capacity/2);
-//         let working_solid_ranges:SmallVec<[Range<u8>;1024]> = SmallVec::with_capacity(capacity/2);
-        
-//         //iterates throughout the charset and generates a glyph for each char. then uses that Loaded glyph to generate a new GlyphSprite
-//         //and 
-//         for &char in charset{
-//             //grabs the glyph from its codepoint in the ttf file and returns a Loaded glyph struct
-//             let glyph = generate_glyph(loaded_font, char, font_size)?;
-//             glyph.push_data(&mut working_font_atlas);
-//             glyph.push_ranges(&mut working_translucent_ranges, &mut working_solid_ranges);
-//         }
-//         //calculates size of bump based off of the size of the current buffers
-//         let bump_capacity =
-//             working_font_atlas.len()*size_of::<&[()]>() +
-//             working_translucent_ranges.len()*size_of::<&[()]>() +
-//             working_solid_ranges.len()
-//         ;
-//         //allocates bump on the heap first to make sure that when the temporary working vecs are dropped it is not fragmented.
-//         //bump capacity is equal to 2 times the capacity so it could hypothetically take all the elements of hypothetically maxed
-//         //out sprites
-//         self.data = Bump::with_capacity(bump_capacity);
-//         //iterates through working_font_atlas and saves each value to the location in bump, returning a reference to the data
-//         self.font_atlas = self.data.alloc_slice_fill_iter(working_font_atlas.iter());
-//         //drops working_font_atlas
-//         drop(working_font_atlas);
-//         let final_translucent_ranges =
-        
-//         SizedFont{
-//             metadata: working_font_atlas.into_boxed_slice(),
-
-//         };
-//         Ok(())
-//     }
-// }
-
-// ///Font is a struct that contains a vec data that contains all the font data and a vec of charsprite structs that slice the data vec
-// /// contains a boxed slice 
-// pub struct Font{
-//     ///contains a vector of sized fonts for each of the font sizes intitialized for this specific font
-//     pub font_sizes:Vec<SizedFont>,
-//     pub charset:Box<[char]>,
-//     loaded_font:LoadedFont,
-// }
-
-// impl Font{
-
-//     fn new_font(&mut self, font_name:String, charset:&[char], font_sizes:&[f32]) -> Result<(), Error>{
-//         //loads ttf into memory based off of string, returns error if font doesnt exist or font file is corrupted
-//         let mut loaded_font = load_font(&font_name)?;
-//         //iterates through charset and generates a glyph for each char provided and inserts 
-        
-//         //shrinks working font data to fit the size of the buffer
-//         working_font.data.shrink_to_fit();
-//         //inserts completed font into hashmap
-//         self.loaded_fonts.insert(FontCode(font_name, font_height), working_font);
-//         Ok(())
-//     }
+///Looks up everything a sized font needs for charset at font_size, calling rasterize for every char with something to draw.
+///chars the font doesnt have are left out so they can be told apart from the ones it does
+pub fn rasterize_chars<G>(loaded_font:&mut LoadedFont, font_size:f32, charset:&[char], mut rasterize:impl FnMut(&mut LoadedFont, char) -> Result<G, Error>) -> Result<RasterizedChars<G>, Error>{
+    let metrics = FontMetrics::new(loaded_font, font_size);
+    let mut charset = charset.to_vec();
+    charset.sort_unstable();
+    charset.dedup();
+    charset.retain(|&char|find_glyph_index(loaded_font, char).is_some());
+    let mut glyphs = Vec::with_capacity(charset.len());
+    for &char in charset.iter(){
+        let (advance, _) = get_codepoint_hmetrics(loaded_font, char);
+        //spaces and the like only move the pen, stbtt has no bitmap to give back for them
+        let glyph = if is_glyph_empty(loaded_font, char) {None} else {Some(rasterize(loaded_font, char)?)};
+        glyphs.push((char, advance as f32*metrics.scale, glyph));
+    }
+    let mut kerning = HashMap::new();
+    for &first in charset.iter(){
+        for &second in charset.iter(){
+            let kern = get_kerning(loaded_font, first, second);
+            if kern != 0{
+                kerning.insert((first, second), kern as f32*metrics.scale);
+            }
+        }
+    }
+    Ok(RasterizedChars{metrics, glyphs, kerning})
+}
 
-//     ///public constructor for font class, creates a font with all fields pre-allocated ready to be written
-//     pub fn generate_font_size(size:usize, font_height:usize, charset:&[char]) -> Self{
-//         //max_px is the maximum size of the buffer assuming every character is exactly height squared in size.
-//         //it is shrunk to actual size once font initialization is over. done to avoid reallocations during text generation
-//         let max_px = size*font_height^2;
-//         Font{
-//             metadata:Vec::with_capacity(size),
-//             data:Vec::with_capacity(max_px),
-//             charset:charset.to_vec().into_boxed_slice()
-//         }
-//     }
-// }
+//pixels the glyphs reach above and below the baseline, only counting the ones with something to draw
+fn glyph_extents(glyphs:&[SizedGlyph]) -> (i32, i32){
+    let drawn = ||glyphs.iter().filter(|glyph|glyph.size.pixels() != 0);
+    let ascent = drawn().map(|glyph|-glyph.yoff).max().unwrap_or(0).max(0);
+    let descent = drawn().map(|glyph|glyph.yoff + glyph.size.height as i32).max().unwrap_or(0).max(0);
+    (ascent, descent)
+}
 
-// ///Text handler is the public facing text handling struct. It contains the hash map of all the fonts currently loaded into memory at every size
-// pub struct TextHandler{
-//     pub loaded_fonts:HashMap<FontCode, Font>,
-// }
+///Puts bitmap glyphs together into a sized font the same as a rasterized ttf one, so text draws the same whatever kind of font it uses.
+///ascent and descent are what the font says its lines are, they grow if a glyph reaches further. later glyphs for the same char are dropped
+pub fn sized_from_bitmaps(font_size:f32, ascent:i32, descent:i32, mut bitmaps:Vec<BitmapGlyph>) -> SizedFont{
+    bitmaps.sort_by_key(|bitmap|bitmap.char);
+    bitmaps.dedup_by_key(|bitmap|bitmap.char);
+    let mut atlas = Vec::new();
+    let glyphs:Vec<SizedGlyph> = bitmaps.iter().map(|bitmap|{
+        let mut glyph = SizedGlyph{char:bitmap.char, xoff:bitmap.xoff, yoff:bitmap.yoff, advance:bitmap.advance, ..Default::default()};
+        if bitmap.coverage.iter().any(|&covered|covered != 0){
+            glyph.push_coverage(&mut atlas, &bitmap.coverage, bitmap.size);
+        }
+        glyph
+    }).collect();
+    let (glyph_ascent, glyph_descent) = glyph_extents(&glyphs);
+    let (ascent, descent) = (ascent.max(glyph_ascent), descent.max(glyph_descent));
+    SizedFont{font_size, ascent, descent, line_gap:0, line_spacing:ascent + descent, atlas, glyphs, kerning:HashMap::new()}
+}
 
-// impl TextHandler{
-//     pub fn init() -> Self{
-//         TextHandler{
-//             loaded_fonts:HashMap::with_capacity(FONTS_PREALLOC)
-//         }
-//     }
+///Text is a string drawn in one color with a sized font, sent to the display with DrawCall::DrawText every frame it should be on screen
+#[derive(Clone, Debug)]
+pub struct Text{
+    pub font:Arc<SizedFont>,
+    pub text:String,
+    ///top left of the line, the baseline sits the fonts ascent below it
+    pub point:Point,
+    ///straight alpha, premultiplied when the text is drawn
+    pub color:[u8;4],
+    pub layer:Layer,
+    pub z:i32,
+    ///box to wrap and align the text in with point as its top left, none draws it all on one line
+    pub text_box:Option<TextBox>,
+    ///distance fields the text is drawn from along with the effects drawn around it, none draws the coverage of font
+    pub sdf:Option<SdfStyle>,
+    ///fonts and sprites the text is read as markup with, none draws it as it is written
+    pub rich:Option<RichStyle>,
+}
 
-    
-// }
+///RichStyle is what markup in text can refer to along with the tick its motions are drawn at
+#[derive(Clone, Debug)]
+pub struct RichStyle{
+    pub rich:Arc<RichText>,
+    ///the text parsed once when it was made, shared between every copy sent to the display
+    pub runs:Arc<[Run]>,
+    pub tick:u32,
+}
 
-// fn slices_from_ranges(){}
+///SdfStyle is the sdf font text is drawn from and the effects around it, font of the text is then only used to lay it out
+#[derive(Clone, Debug)]
+pub struct SdfStyle{
+    pub font:Arc<SdfFont>,
+    pub effects:TextEffects,
+}
 
+impl Text{
+    ///Text on the ui layer
+    pub fn new(font:Arc<SizedFont>, text:impl Into<String>, point:Point, color:[u8;4]) -> Self{
+        Text{font, text:text.into(), point, color, layer:Layer::UI, z:0, text_box:None, sdf:None, rich:None}
+    }
+    ///Text on the ui layer drawn from the distance fields of font at font_size, which can be any size without rasterizing again
+    pub fn sdf(font:&Arc<SdfFont>, font_size:f32, text:impl Into<String>, point:Point, color:[u8;4]) -> Self{
+        let sdf = Some(SdfStyle{font:font.clone(), effects:TextEffects::default()});
+        Text{sdf, ..Text::new(font.sized(font_size), text, point, color)}
+    }
+    ///Text on the ui layer read as markup with the fonts and sprites of rich, color is what text outside color tags gets.
+    ///the markup is parsed and checked here once, so mistakes in it come back straight away instead of every time it is drawn.
+    ///send it again with the next tick to animate motions
+    pub fn markup(rich:&Arc<RichText>, markup:impl Into<String>, point:Point, color:[u8;4], tick:u32) -> Result<Self, MarkupError>{
+        let markup = markup.into();
+        let runs = parse_markup(&markup, color)?;
+        rich.check(&runs)?;
+        Ok(Text{rich:Some(RichStyle{rich:rich.clone(), runs:runs.into(), tick}), ..Text::new(rich.regular.clone(), markup, point, color)})
+    }
+    ///Draws effects around the text, only sdf text has the distance fields they need so plain text is left as it is
+    pub fn with_effects(mut self, effects:TextEffects) -> Self{
+        if let Some(sdf) = self.sdf.as_mut(){
+            sdf.effects = effects;
+        }
+        self
+    }
+    ///Lays the text out inside text_box instead of on one line
+    pub fn in_box(mut self, text_box:TextBox) -> Self{
+        self.text_box = Some(text_box);
+        self
+    }
+    pub fn on_layer(mut self, layer:Layer) -> Self{
+        self.layer = layer;
+        self
+    }
+    pub fn with_z(mut self, z:i32) -> Self{
+        self.z = z;
+        self
+    }
+    ///True if drawing other would give exactly the same pixels, lets the display keep the sprite it already made
+    pub fn same_as(&self, other:&Text) -> bool{
+        Arc::ptr_eq(&self.font, &other.font) && self.text == other.text && self.point == other.point && self.color == other.color
+            && self.layer == other.layer && self.z == other.z && self.text_box == other.text_box
+            && match (&self.sdf, &other.sdf){
+                (Some(sdf), Some(other)) => Arc::ptr_eq(&sdf.font, &other.font) && sdf.effects == other.effects,
+                (None, None) => true,
+                _ => false,
+            }
+            && match (&self.rich, &other.rich){
+                (Some(rich), Some(other)) => Arc::ptr_eq(&rich.rich, &other.rich) && rich.tick == other.tick,
+                (None, None) => true,
+                _ => false,
+            }
+    }
+    ///Lays out the text in its box, text without one is laid out as unwrapped lines
+    pub fn layout(&self) -> TextLayout{
+        layout_text(&self.font, &self.text, self.text_box.as_ref().unwrap_or(&TextBox::default()))
+    }
+    ///Draws the text into a sprite, returns it along with where its top left sits relative to point
+    pub fn render(&self) -> (Sprite, Point){
+        //the runs were checked when the text was made, only a rich style put together by hand can fail here and it is drawn as written
+        if let Some(rich) = &self.rich
+            && let Ok(layout) = rich.rich.layout(&rich.runs, self.text_box.as_ref().unwrap_or(&TextBox::default())){
+            return rich.rich.render(&layout, rich.tick);
+        }
+        match (&self.text_box, &self.sdf){
+            (Some(_), Some(sdf)) => sdf.font.render_layout(&self.font, &self.layout(), self.color, &sdf.effects),
+            (None, Some(sdf)) => sdf.font.render(&self.font, &self.text, self.color, &sdf.effects),
+            (Some(_), None) => self.font.render_layout(&self.layout(), self.color),
+            (None, None) => self.font.render(&self.text, self.color),
+        }
+    }
+}
@@ -0,0 +1,219 @@
+// ===============================
+//           TEXTLAYOUT.RS
+// -------------------------------
+//  breaks text into lines that fit inside a box and places
+//  them according to the boxes alignment. the layout can be
+//  measured before anything is drawn so ui can size panels
+//  around it
+// -------------------------------
+
+use crate::{graphics::texthandler::SizedFont, shared::{Rect, Size}};
+
+///what is put at the end of a line when text after it had to be cut
+pub const ELLIPSIS:&str = "...";
+
+///HorizontalAlign is where lines sit between the left and right edge of the box
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HorizontalAlign{
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+///VerticalAlign is where the block of lines sits between the top and bottom of the box
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VerticalAlign{
+    #[default]
+    Top,
+    Middle,
+    Bottom,
+}
+
+///TextBox is the area text is laid out in along with how it is fit into it
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextBox{
+    ///lines are wrapped at this many pixels, none lets them run as long as they are
+    pub width:Option<usize>,
+    ///lines that dont fit under this many pixels are dropped, none keeps them all
+    pub height:Option<usize>,
+    pub align:HorizontalAlign,
+    pub vertical_align:VerticalAlign,
+    ///multiplies the line spacing of the font, 1.0 keeps it as the font wants
+    pub line_spacing:f32,
+    ///breaks lines at spaces when they get wider than width, otherwise only newlines start a new line
+    pub wrap:bool,
+    ///ends cut off text with ELLIPSIS
+    pub ellipsis:bool,
+}
+
+impl Default for TextBox{
+    fn default() -> Self{
+        TextBox{
+            width:None,
+            height:None,
+            align:HorizontalAlign::Left,
+            vertical_align:VerticalAlign::Top,
+            line_spacing:1.0,
+            wrap:true,
+            ellipsis:false,
+        }
+    }
+}
+
+impl TextBox{
+    ///Box of size, text is wrapped to its width and cut at its height
+    pub fn new(size:Size) -> Self{
+        TextBox{width:Some(size.width), height:Some(size.height), ..Default::default()}
+    }
+    ///Box that only limits width and grows as tall as the text needs, for measuring text before making a panel for it
+    pub fn with_width(width:usize) -> Self{
+        TextBox{width:Some(width), ..Default::default()}
+    }
+    pub fn aligned(mut self, align:HorizontalAlign, vertical_align:VerticalAlign) -> Self{
+        self.align = align;
+        self.vertical_align = vertical_align;
+        self
+    }
+    pub fn with_line_spacing(mut self, line_spacing:f32) -> Self{
+        self.line_spacing = line_spacing;
+        self
+    }
+    ///Lines too wide for the box run past it, or get cut with an ellipsis if ellipsis is set
+    pub fn unwrapped(mut self) -> Self{
+        self.wrap = false;
+        self
+    }
+    pub fn with_ellipsis(mut self) -> Self{
+        self.ellipsis = true;
+        self
+    }
+}
+
+///LaidLine is one line of a layout and where it goes
+#[derive(Clone, Debug, PartialEq)]
+pub struct LaidLine{
+    pub text:String,
+    ///pen start of the line relative to the left of the box
+    pub x:i32,
+    ///top of the line relative to the top of the box
+    pub y:i32,
+    ///pixels the line covers
+    pub width:usize,
+}
+
+///TextLayout is text broken into lines and placed inside a box
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextLayout{
+    pub lines:Vec<LaidLine>,
+    ///everything the lines cover relative to the top left of the box, can stick out of the box when the text doesnt fit
+    pub bounds:Rect,
+    ///true if lines were dropped or cut short to fit the box
+    pub truncated:bool,
+}
+
+impl TextLayout{
+    ///Size of the laid out text, what a panel needs to fit it
+    pub fn size(&self) -> Size{
+        Size::is(self.bounds.width() as usize, self.bounds.height() as usize)
+    }
+}
+
+///Breaks text into lines that fit text_box and aligns them inside it
+pub fn layout_text(font:&SizedFont, text:&str, text_box:&TextBox) -> TextLayout{
+    let width = |line:&str|font.measure(line).width;
+    let mut lines = Vec::new();
+    let mut truncated = false;
+    for paragraph in text.split('\n'){
+        match text_box.width{
+            Some(max_width) if text_box.wrap => wrap_paragraph(paragraph, max_width, &width, &mut lines),
+            Some(max_width) if text_box.ellipsis && width(paragraph) > max_width =>{
+                lines.push(with_ellipsis(paragraph, Some(max_width), &width));
+                truncated = true;
+            }
+            _ => lines.push(paragraph.to_string()),
+        }
+    }
+    //drops whatever doesnt fit under the height, the last line kept is marked with an ellipsis
+    let spacing = (font.line_spacing as f32*text_box.line_spacing).round() as i32;
+    let line_height = font.line_height() as i32;
+    if let Some(max_height) = text_box.height{
+        let fitting = if line_height > max_height as i32 || spacing <= 0{
+            usize::from(line_height <= max_height as i32)
+        }else{
+            ((max_height as i32 - line_height)/spacing + 1) as usize
+        };
+        if fitting < lines.len(){
+            lines.truncate(fitting);
+            truncated = true;
+            if text_box.ellipsis && let Some(last) = lines.last_mut(){
+                *last = with_ellipsis(last, text_box.width, &width);
+            }
+        }
+    }
+    //places the lines, anything without a box edge to align to aligns to the text itself
+    let widths:Vec<usize> = lines.iter().map(|line|width(line)).collect();
+    let box_width = text_box.width.unwrap_or(widths.iter().copied().max().unwrap_or(0)) as i32;
+    let content_height = if lines.is_empty() {0} else {(lines.len() as i32 - 1)*spacing + line_height};
+    let box_height = text_box.height.map_or(content_height, |height|height as i32);
+    let top = match text_box.vertical_align{
+        VerticalAlign::Top => 0,
+        VerticalAlign::Middle => (box_height - content_height)/2,
+        VerticalAlign::Bottom => box_height - content_height,
+    };
+    let mut bounds = Rect::default();
+    let lines = lines.into_iter().zip(widths).enumerate().map(|(index, (text, width))|{
+        let left = match text_box.align{
+            HorizontalAlign::Left => 0,
+            HorizontalAlign::Center => (box_width - width as i32)/2,
+            HorizontalAlign::Right => box_width - width as i32,
+        };
+        let y = top + index as i32*spacing;
+        //the pen starts right of the left edge when a glyph hangs left of it
+        let (hang, _) = font.horizontal_bounds(&text);
+        bounds = bounds.union(&Rect::new(left, y, left + width as i32, y + line_height));
+        LaidLine{text, x:left - hang, y, width}
+    }).collect();
+    TextLayout{lines, bounds, truncated}
+}
+
+//greedily fits the words of paragraph onto lines no wider than max_width, words wider than a line on their own are broken between chars
+fn wrap_paragraph(paragraph:&str, max_width:usize, width:&impl Fn(&str) -> usize, lines:&mut Vec<String>){
+    let mut line = String::new();
+    for word in paragraph.split(' '){
+        let candidate = if line.is_empty() {word.to_string()} else {format!("{line} {word}")};
+        if width(&candidate) <= max_width{
+            line = candidate;
+            continue;
+        }
+        if !line.is_empty(){
+            lines.push(line);
+        }
+        line = word.to_string();
+        while width(&line) > max_width && line.chars().count() > 1{
+            //longest start of the word that fits, at least one char so it always moves forward
+            let mut split = line.char_indices().nth(1).map_or(line.len(), |(index, _)|index);
+            for (index, _) in line.char_indices().skip(2){
+                if width(&line[..index]) > max_width{break;}
+                split = index;
+            }
+            let rest = line.split_off(split);
+            lines.push(line);
+            line = rest;
+        }
+    }
+    lines.push(line);
+}
+
+//line with ELLIPSIS on the end, chars are taken off until it fits in max_width
+fn with_ellipsis(line:&str, max_width:Option<usize>, width:&impl Fn(&str) -> usize) -> String{
+    let mut line = line.trim_end().to_string();
+    loop{
+        let cut = format!("{line}{ELLIPSIS}");
+        if line.is_empty() || max_width.is_none_or(|max_width|width(&cut) <= max_width){
+            return cut;
+        }
+        line.pop();
+        line.truncate(line.trim_end().len());
+    }
+}
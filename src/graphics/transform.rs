@@ -0,0 +1,168 @@
+// ===============================
+//            TRANSFORM.RS
+// -------------------------------
+//  Per object sprite transforms. flips, quarter
+//  turns and integer scaling that the renderer
+//  applies while blitting, so one sprite file
+//  covers every facing and size. affine adds any
+//  angle and fractional scale on top of that
+// -------------------------------
+
+use crate::shared::Size;
+
+///Clockwise quarter turns
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Rotation{
+    #[default]
+    None,
+    Quarter,
+    Half,
+    ThreeQuarter,
+}
+
+///Transform is applied to a sprite in the order flip, rotate, scale. the default transform draws the sprite as is
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Transform{
+    ///mirrors the sprite left to right
+    pub flip_x:bool,
+    ///mirrors the sprite top to bottom
+    pub flip_y:bool,
+    pub rotation:Rotation,
+    ///every sprite pixel becomes a scale by scale block, 0 is treated as 1
+    pub scale:usize,
+}
+
+impl Default for Transform{
+    fn default() -> Self{
+        Transform::IDENTITY
+    }
+}
+
+impl Transform{
+    pub const IDENTITY:Transform = Transform{flip_x:false, flip_y:false, rotation:Rotation::None, scale:1};
+
+    pub fn flipped_x(mut self) -> Self{
+        self.flip_x = !self.flip_x;
+        self
+    }
+    pub fn flipped_y(mut self) -> Self{
+        self.flip_y = !self.flip_y;
+        self
+    }
+    pub fn rotated(mut self, rotation:Rotation) -> Self{
+        self.rotation = rotation;
+        self
+    }
+    pub fn scaled(mut self, scale:usize) -> Self{
+        self.scale = scale;
+        self
+    }
+    ///True if the sprite is drawn exactly as stored, lets the renderer take the straight range copy path
+    pub fn is_identity(&self) -> bool{
+        self.scale() == 1 && !self.flip_x && !self.flip_y && self.rotation == Rotation::None
+    }
+    pub fn scale(&self) -> usize{
+        self.scale.max(1)
+    }
+    ///Size of a sprite of size after the transform
+    pub fn apply_size(&self, size:Size) -> Size{
+        let scale = self.scale();
+        match self.rotation{
+            Rotation::None | Rotation::Half => Size::is(size.width*scale, size.height*scale),
+            Rotation::Quarter | Rotation::ThreeQuarter => Size::is(size.height*scale, size.width*scale),
+        }
+    }
+    ///Maps a pixel of a sprite of size to where its block starts in the transformed sprite, before scaling
+    pub fn map(&self, x:usize, y:usize, size:Size) -> (usize, usize){
+        let x = if self.flip_x{size.width - 1 - x}else{x};
+        let y = if self.flip_y{size.height - 1 - y}else{y};
+        match self.rotation{
+            Rotation::None => (x, y),
+            Rotation::Quarter => (size.height - 1 - y, x),
+            Rotation::Half => (size.width - 1 - x, size.height - 1 - y),
+            Rotation::ThreeQuarter => (y, size.width - 1 - x),
+        }
+    }
+    ///Continuous version of map run backwards. takes a position inside the transformed sprite, where pixel i covers i to i+1,
+    ///and returns the position inside the sprite of size it came from
+    pub fn unmap(&self, x:f32, y:f32, size:Size) -> (f32, f32){
+        let scale = self.scale() as f32;
+        let (x, y) = (x/scale, y/scale);
+        let (width, height) = (size.width as f32, size.height as f32);
+        let (x, y) = match self.rotation{
+            Rotation::None => (x, y),
+            Rotation::Quarter => (y, height - x),
+            Rotation::Half => (width - x, height - y),
+            Rotation::ThreeQuarter => (width - y, x),
+        };
+        (
+            if self.flip_x{width - x}else{x},
+            if self.flip_y{height - y}else{y},
+        )
+    }
+}
+
+///How the affine path picks a color for a display pixel that lands between sprite pixels
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Sampling{
+    ///closest sprite pixel, keeps pixel art crisp
+    #[default]
+    Nearest,
+    ///weighted blend of the four closest sprite pixels, done on premultiplied colors so edges fade out cleanly
+    Bilinear,
+}
+
+///Affine rotates and scales an object around its center by any amount, on top of its transform.
+///objects with one are drawn by sampling the sprite for every covered display pixel instead of copying ranges
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Affine{
+    ///clockwise rotation in radians
+    pub angle:f32,
+    pub scale_x:f32,
+    pub scale_y:f32,
+    pub sampling:Sampling,
+}
+
+impl Default for Affine{
+    fn default() -> Self{
+        Affine{angle:0.0, scale_x:1.0, scale_y:1.0, sampling:Sampling::Nearest}
+    }
+}
+
+impl Affine{
+    ///Rotation by angle radians clockwise at 1x scale
+    pub fn rotation(angle:f32) -> Self{
+        Affine{angle, ..Default::default()}
+    }
+    ///Scaling by x and y without rotating
+    pub fn scaling(scale_x:f32, scale_y:f32) -> Self{
+        Affine{scale_x, scale_y, ..Default::default()}
+    }
+    pub fn rotated(mut self, angle:f32) -> Self{
+        self.angle = angle;
+        self
+    }
+    pub fn scaled(mut self, scale_x:f32, scale_y:f32) -> Self{
+        self.scale_x = scale_x;
+        self.scale_y = scale_y;
+        self
+    }
+    pub fn with_sampling(mut self, sampling:Sampling) -> Self{
+        self.sampling = sampling;
+        self
+    }
+    ///Half the width and height of the box around a size sized sprite once rotated and scaled
+    pub fn half_extents(&self, size:Size) -> (f32, f32){
+        let (sin, cos) = self.angle.sin_cos();
+        let half_width = size.width as f32*self.scale_x.abs()/2.0;
+        let half_height = size.height as f32*self.scale_y.abs()/2.0;
+        (cos.abs()*half_width + sin.abs()*half_height, sin.abs()*half_width + cos.abs()*half_height)
+    }
+    ///Maps an offset from the center on the display back to an offset from the center of the unrotated, unscaled sprite.
+    ///none if a scale is zero, nothing maps back then
+    pub fn inverse(&self, x:f32, y:f32) -> Option<(f32, f32)>{
+        if self.scale_x == 0.0 || self.scale_y == 0.0{return None;}
+        let (sin, cos) = self.angle.sin_cos();
+        Some(((x*cos + y*sin)/self.scale_x, (y*cos - x*sin)/self.scale_y))
+    }
+}
@@ -6,10 +6,14 @@ use crate::shared::*;
 
 pub struct Input{
     pub keyboard_input:WinitInputHelper,
-    gamepad_input:Gilrs,
+    gamepad_input:Option<Gilrs>,
     gamepad_id:Option<GamepadId>,
-    pub input_state:InputState
+    pub input_state:InputState,
+    ///when set get_input only recomputes the direction from input_state, so headless runs can write the buttons directly
+    pub scripted:bool,
 }
+///InputState defaults to nothing pressed
+#[derive(Clone, Copy, Debug, Default)]
 pub struct InputState{
     pub up:bool,
     pub down:bool,
@@ -23,43 +27,74 @@ pub struct InputState{
     pub east:bool,
     pub direction:Point
 }
+
+impl InputState{
+    ///Translates the directional buttons into the direction vector
+    pub fn update_direction(&mut self){
+        //defines a new tuple with all directional inputs as members
+        let directions = (
+            self.up,
+            self.down,
+            self.left,
+            self.right,
+        );
+        //translates directional inputs into a motion vector.
+        self.direction = match directions{
+            (true, false, false, false)=> UP,
+            (false, true, false, false)=> DOWN,
+            (false, false, true, false)=> LEFT,
+            (false, false, false, true)=> RIGHT,
+            (true, false, true, false)=> UP_LEFT,
+            (true, false, false, true)=> UP_RIGHT,
+            (false, true, true, false)=> DOWN_LEFT,
+            (false, true, false, true)=> DOWN_RIGHT,
+            _=> Point::at(0.0, 0.0)
+        }
+    }
+}
 //creates input handler
 impl Input{
     pub fn new() -> Self{
         //creates default input struct
         let mut input =Input{
             keyboard_input:WinitInputHelper::new(),
-            gamepad_input:Gilrs::new().expect("Failed to initialize gilrs"),
+            gamepad_input:Some(Gilrs::new().expect("Failed to initialize gilrs")),
             gamepad_id:None,
-            input_state:InputState{
-                up:false,
-                down:false,
-                left:false,
-                right:false,
-                start:false,
-                select:false,
-                north:false,
-                south:false,
-                west:false,
-                east:false,
-                direction:Point::at(0.0,0.0)
-            }
+            input_state:InputState::default(),
+            scripted:false,
         };
         //if gilrs detects gamepad save it to gamepad_id field of Input struct, if not remains none
-        if let Some(gamepad_id) = input.gamepad_input.gamepads().next(){
+        if let Some(gamepad_id) = input.gamepad_input.as_ref().and_then(|gilrs|gilrs.gamepads().next()){
             input.gamepad_id = Some(gamepad_id.0);
         }
         input
     }
 
-    pub fn get_input(&mut self){
+    ///Creates an input handler that never initializes gilrs, input_state is whatever the caller writes into it
+    pub fn headless() -> Self{
+        Input{
+            keyboard_input:WinitInputHelper::new(),
+            gamepad_input:None,
+            gamepad_id:None,
+            input_state:InputState::default(),
+            scripted:true,
+        }
+    }
 
+    pub fn get_input(&mut self){
+        //scripted input is written by whoever owns the engine, dont overwrite it, just keep direction in sync with the buttons
+        if self.scripted{
+            self.input_state.update_direction();
+            return;
+        }
         //dumps the input for Gilrs so it can be read by get_input. also auto connects/ reconnects controller.
-        while let Some(Event{id,event,..}) = self.gamepad_input.next_event(){
-            match event{
-                EventType::Connected => self.gamepad_id = Some(id),
-                EventType::Disconnected => if self.gamepad_id == Some(id){self.gamepad_id = None;},
-                _ =>{}
+        if let Some(gamepad_input) = self.gamepad_input.as_mut(){
+            while let Some(Event{id,event,..}) = gamepad_input.next_event(){
+                match event{
+                    EventType::Connected => self.gamepad_id = Some(id),
+                    EventType::Disconnected => if self.gamepad_id == Some(id){self.gamepad_id = None;},
+                    _ =>{}
+                }
             }
         }
     
@@ -75,36 +110,18 @@ impl Input{
         self.input_state.west = self.keyboard_input.key_held(KeyCode::KeyE);
         self.input_state.east = self.keyboard_input.key_held(KeyCode::ShiftRight);
         //gets gamepad input and ors it with keyboard input
-        if let Some(gamepad_id) = self.gamepad_id{
-            self.input_state.up |= self.gamepad_input.gamepad(gamepad_id).is_pressed(Button::DPadUp);
-            self.input_state.down |= self.gamepad_input.gamepad(gamepad_id).is_pressed(Button::DPadDown);
-            self.input_state.left |= self.gamepad_input.gamepad(gamepad_id).is_pressed(Button::DPadLeft);
-            self.input_state.right |= self.gamepad_input.gamepad(gamepad_id).is_pressed(Button::DPadRight);
-            self.input_state.start |= self.gamepad_input.gamepad(gamepad_id).is_pressed(Button::Start);
-            self.input_state.select |= self.gamepad_input.gamepad(gamepad_id).is_pressed(Button::Select);
-            self.input_state.north |= self.gamepad_input.gamepad(gamepad_id).is_pressed(Button::North);
-            self.input_state.south |= self.gamepad_input.gamepad(gamepad_id).is_pressed(Button::South);
-            self.input_state.west |= self.gamepad_input.gamepad(gamepad_id).is_pressed(Button::West);
-            self.input_state.east |= self.gamepad_input.gamepad(gamepad_id).is_pressed(Button::East);
-        }
-        //defines a new tuple with all directional inputs as members
-        let directions = (
-            self.input_state.up,
-            self.input_state.down,
-            self.input_state.left,
-            self.input_state.right,
-        );
-        //translates directional inputs into a motion vector.
-        self.input_state.direction = match directions{
-            (true, false, false, false)=> UP,
-            (false, true, false, false)=> DOWN,
-            (false, false, true, false)=> LEFT,
-            (false, false, false, true)=> RIGHT,
-            (true, false, true, false)=> UP_LEFT,
-            (true, false, false, true)=> UP_RIGHT,
-            (false, true, true, false)=> DOWN_LEFT,
-            (false, true, false, true)=> DOWN_RIGHT,
-            _=> Point::at(0.0, 0.0)
+        if let (Some(gamepad_id), Some(gamepad_input)) = (self.gamepad_id, self.gamepad_input.as_ref()){
+            self.input_state.up |= gamepad_input.gamepad(gamepad_id).is_pressed(Button::DPadUp);
+            self.input_state.down |= gamepad_input.gamepad(gamepad_id).is_pressed(Button::DPadDown);
+            self.input_state.left |= gamepad_input.gamepad(gamepad_id).is_pressed(Button::DPadLeft);
+            self.input_state.right |= gamepad_input.gamepad(gamepad_id).is_pressed(Button::DPadRight);
+            self.input_state.start |= gamepad_input.gamepad(gamepad_id).is_pressed(Button::Start);
+            self.input_state.select |= gamepad_input.gamepad(gamepad_id).is_pressed(Button::Select);
+            self.input_state.north |= gamepad_input.gamepad(gamepad_id).is_pressed(Button::North);
+            self.input_state.south |= gamepad_input.gamepad(gamepad_id).is_pressed(Button::South);
+            self.input_state.west |= gamepad_input.gamepad(gamepad_id).is_pressed(Button::West);
+            self.input_state.east |= gamepad_input.gamepad(gamepad_id).is_pressed(Button::East);
         }
+        self.input_state.update_direction();
     }
-}
+}
\ No newline at end of file
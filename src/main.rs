@@ -85,12 +85,12 @@ fn run_program() -> Result<(), Box<dyn std::error::Error>>{
                 }
                 WindowEvent::Resized(size) =>{
                     //resizes pixels surface texture based on window logical size
-                    let _ = engine.renderer.pixels.resize_surface(size.width, size.height);
+                    let _ = engine.renderer.target.resize_surface(size.width, size.height);
                 }
                 WindowEvent::RedrawRequested =>{
                     //renders pixels backbuffer
-                    let _ = engine.renderer.pixels.render();
-                    engine.renderer.pixels.device().poll(Maintain::Poll);
+                    let _ = engine.renderer.target.render();
+                    engine.renderer.target.device().poll(Maintain::Poll);
                 }
                 WindowEvent::Focused(new_focused_state) =>{
                     //on window focus hides cursor
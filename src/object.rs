@@ -1,10 +1,33 @@
-use crate::{graphics::sprites::{default_sprite::DEFAULT_SPRITE, Sprite}, shared::Point};
+use std::{error::Error, fmt::{self, Display, Formatter}};
+
+use crate::{engine::layer::Layer, graphics::{animation::Animator, blend::{Blend, BlendMode}, sprites::{default_sprite::DEFAULT_SPRITE, palette::Palette, rle::RleSprite, Sprite}, transform::{Affine, Transform}}, shared::{Point, Rect, Size}};
 
 #[derive(Clone, Debug)]
 pub struct Object{
         pub sprite:Sprite,
         pub point:Point,
+        ///dirty flag, set whenever the object changes so the display re-composites the area it covers
         pub needs_draw:bool,
+        ///layer the object is drawn on
+        pub layer:Layer,
+        ///draw order inside the layer, higher z draws over lower z
+        pub z:i32,
+        ///keeps the object fully inside the display when it is set or moved, turn off to let it hang off the edges
+        pub clamp:bool,
+        ///flip, rotation and scale the sprite is drawn with
+        pub transform:Transform,
+        ///arbitrary rotation and scale around the center, applied after transform
+        pub affine:Option<Affine>,
+        ///plays clips from a sprite sheet, swapping sprite every time the frame changes
+        pub animator:Option<Animator>,
+        ///palette indexed sprites are drawn with instead of their own, rgba sprites ignore it
+        pub palette:Option<Palette>,
+        ///opacity, tint and blend mode the object is mixed into the display with
+        pub blend:Blend,
+        ///rect the object covered the last time it was composited, invalidated when it moves or stops being drawn
+        pub(crate) drawn:Option<Rect>,
+        ///render frame the object was last queued to draw in
+        pub(crate) drawn_frame:u64,
     }
 
 impl Object{
@@ -13,21 +36,141 @@ impl Object{
         Object{
             sprite,
             point,
-            needs_draw:true
+            needs_draw:true,
+            layer:Layer::WORLD,
+            z:0,
+            clamp:true,
+            transform:Transform::IDENTITY,
+            affine:None,
+            animator:None,
+            palette:None,
+            blend:Blend::NORMAL,
+            drawn:None,
+            drawn_frame:0,
+        }
+    }
+    ///Object with the sprite decoded from a run length encoded one, the runs are only read here and not kept around
+    pub fn from_rle(point:Point, rle:&RleSprite) -> Self{
+        Object::new(point, rle.decode())
+    }
+    ///Moves the object onto layer
+    pub fn on_layer(mut self, layer:Layer) -> Self{
+        self.layer = layer;
+        self
+    }
+    ///Sets the draw order of the object inside its layer
+    pub fn with_z(mut self, z:i32) -> Self{
+        self.z = z;
+        self
+    }
+    ///Draws the object with transform
+    pub fn with_transform(mut self, transform:Transform) -> Self{
+        self.transform = transform;
+        self
+    }
+    ///Rotates and scales the object around its center by affine
+    pub fn with_affine(mut self, affine:Affine) -> Self{
+        self.affine = Some(affine);
+        self
+    }
+    ///Animates the object with animator, the sprite is replaced by the animators current frame straight away
+    pub fn with_animator(mut self, animator:Animator) -> Self{
+        self.sprite = animator.sprite().clone();
+        self.animator = Some(animator);
+        self
+    }
+    ///Draws the objects indexed sprite with palette instead of the sprites own
+    pub fn with_palette(mut self, palette:Palette) -> Self{
+        self.palette = Some(palette);
+        self
+    }
+    ///Mixes the object into the display with blend
+    pub fn with_blend(mut self, blend:Blend) -> Self{
+        self.blend = blend;
+        self
+    }
+    ///Fades the object, 0 is invisible and 255 fully opaque
+    pub fn with_opacity(mut self, opacity:u8) -> Self{
+        self.blend.opacity = opacity;
+        self
+    }
+    ///Multiplies every color of the sprite by tint
+    pub fn with_tint(mut self, tint:[u8;3]) -> Self{
+        self.blend.tint = tint;
+        self
+    }
+    pub fn with_blend_mode(mut self, mode:BlendMode) -> Self{
+        self.blend.mode = mode;
+        self
+    }
+    ///Palette the object is drawn with, its own if it has one and otherwise its sprites. none for rgba sprites
+    pub fn palette(&self) -> Option<&Palette>{
+        self.palette.as_ref().or(self.sprite.palette.as_deref())
+    }
+    ///Palette the object can change without touching the shared sprite, copied from the sprite the first time.
+    ///none for rgba sprites
+    pub fn palette_mut(&mut self) -> Option<&mut Palette>{
+        if self.palette.is_none(){
+            self.palette = Some((**self.sprite.palette.as_ref()?).clone());
         }
+        self.palette.as_mut()
+    }
+    ///Size the object takes up on the display once its transform is applied, not counting the affine which spins around the center of this
+    pub fn size(&self) -> Size{
+        self.transform.apply_size(self.sprite.size)
+    }
+    ///Lets the object be placed partly or fully outside the display, it gets clipped when drawn
+    pub fn unclamped(mut self) -> Self{
+        self.clamp = false;
+        self
+    }
+    ///Moves the object to point, keeping it inside display_size if the object is clamped
+    pub fn place(&mut self, point:Point, display_size:Size){
+        let Point{mut x, mut y} = point;
+        if self.clamp{
+            //saturating sub so objects bigger than the display just pin to the top left
+            let size = self.size();
+            x = x.clamp(0.0,(display_size.width.saturating_sub(size.width)) as f32);
+            y = y.clamp(0.0,(display_size.height.saturating_sub(size.height)) as f32);
+        }
+        self.point = Point::at(x,y);
     }
     pub fn default() -> Self{
         Self{
-            sprite:DEFAULT_SPRITE,
+            sprite:DEFAULT_SPRITE.clone(),
             point:Point::at(0.0,0.0),
             needs_draw:false,
+            layer:Layer::WORLD,
+            z:0,
+            clamp:true,
+            transform:Transform::IDENTITY,
+            affine:None,
+            animator:None,
+            palette:None,
+            blend:Blend::NORMAL,
+            drawn:None,
+            drawn_frame:0,
         }
     }
+    ///Rect the object covers on the display grid
+    pub fn rect(&self) -> Rect{
+        let size = self.size();
+        let Some(affine) = self.affine else{return Rect::at(self.point, size);};
+        //rotated and scaled sprites grow or shrink around the center of their unrotated rect
+        let (center_x, center_y) = (self.point.x + size.width as f32/2.0, self.point.y + size.height as f32/2.0);
+        let (half_width, half_height) = affine.half_extents(size);
+        Rect::new(
+            (center_x - half_width).floor() as i32,
+            (center_y - half_height).floor() as i32,
+            (center_x + half_width).ceil() as i32,
+            (center_y + half_height).ceil() as i32,
+        )
+    }
     pub fn bounds(&self) -> Point{
-        self.point + self.sprite.size
+        self.point + self.size()
     }
     pub fn bounds_neg(&self) ->Point{
-        self.point - self.sprite.size
+        self.point - self.size()
     }
     pub fn contains(&self, other:&Object) -> bool{
     if self.point <= other.point && other.point <= self.bounds()||
@@ -39,3 +182,146 @@ impl Object{
     }
     }
 }
+
+///ObjectHandle addresses an object in the object registry. the generation is bumped every time a slot is despawned, so a handle
+///to a despawned object stays stale even after its slot is reused
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObjectHandle{
+    index:u32,
+    generation:u32,
+}
+
+impl ObjectHandle{
+    ///Handle that never refers to an object, registry generations start at 1. useful as a placeholder before a screen has spawned its objects
+    pub const DANGLING:ObjectHandle = ObjectHandle{index:u32::MAX, generation:0};
+}
+
+///Errors returned by the object registry
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ObjectError{
+    ///handle points at a slot that was despawned (and maybe reused) or was never issued by this registry
+    StaleHandle(ObjectHandle),
+}
+
+impl Display for ObjectError{
+    fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result{
+        match self{
+            ObjectError::StaleHandle(handle) => write!(f, "Stale object handle {} (generation {})", handle.index, handle.generation),
+        }
+    }
+}
+
+impl Error for ObjectError{}
+
+//one slot in the registry, object is none while the slot sits in the free list
+#[derive(Clone, Debug)]
+struct ObjectSlot{
+    generation:u32,
+    object:Option<Object>,
+}
+
+///ObjectRegistry owns every object on the display. spawning returns a generational handle, despawned slots are reused and the
+///registry grows as needed
+#[derive(Clone, Debug, Default)]
+pub struct ObjectRegistry{
+    slots:Vec<ObjectSlot>,
+    free:Vec<u32>,
+    ///rects left behind by despawned objects that were on screen, the display drains these to redraw what was underneath
+    pub(crate) vacated:Vec<Rect>,
+}
+
+impl ObjectRegistry{
+    ///Public constructor for the registry, preallocates capacity slots
+    pub fn with_capacity(capacity:usize) -> Self{
+        ObjectRegistry{
+            slots:Vec::with_capacity(capacity),
+            free:Vec::new(),
+            vacated:Vec::new(),
+        }
+    }
+    ///Stores object in a free slot, or a new one if none are free, and returns its handle
+    pub fn spawn(&mut self, object:Object) -> ObjectHandle{
+        if let Some(index) = self.free.pop(){
+            let slot = &mut self.slots[index as usize];
+            slot.object = Some(object);
+            return ObjectHandle{index, generation:slot.generation};
+        }
+        let index = self.slots.len() as u32;
+        self.slots.push(ObjectSlot{generation:1, object:Some(object)});
+        ObjectHandle{index, generation:1}
+    }
+    ///Removes the object behind handle and returns it, every copy of handle is stale afterwards
+    pub fn despawn(&mut self, handle:ObjectHandle) -> Result<Object, ObjectError>{
+        let slot = self.slot_mut(handle)?;
+        let object = slot.object.take().ok_or(ObjectError::StaleHandle(handle))?;
+        slot.generation = slot.generation.wrapping_add(1).max(1);
+        self.free.push(handle.index);
+        self.vacated.extend(object.drawn);
+        Ok(object)
+    }
+    ///Despawns every object, all handles issued so far become stale
+    pub fn clear(&mut self){
+        self.free.clear();
+        for (index, slot) in self.slots.iter_mut().enumerate(){
+            if let Some(object) = slot.object.take(){
+                slot.generation = slot.generation.wrapping_add(1).max(1);
+                self.vacated.extend(object.drawn);
+            }
+            self.free.push(index as u32);
+        }
+    }
+    ///Returns the object behind handle
+    pub fn get(&self, handle:ObjectHandle) -> Result<&Object, ObjectError>{
+        self.slots.get(handle.index as usize)
+            .filter(|slot|slot.generation == handle.generation)
+            .and_then(|slot|slot.object.as_ref())
+            .ok_or(ObjectError::StaleHandle(handle))
+    }
+    ///Returns the object behind handle mutably to change it.
+    ///this also sets needs_draw on the object, whether or not anything is changed, so the display redraws the area it covers.
+    ///use get for anything that only reads the object
+    pub fn get_mut(&mut self, handle:ObjectHandle) -> Result<&mut Object, ObjectError>{
+        let object = self.peek_mut(handle)?;
+        object.needs_draw = true;
+        Ok(object)
+    }
+    ///Swaps the object behind handle for a new one, handle stays valid
+    pub fn replace(&mut self, handle:ObjectHandle, mut object:Object) -> Result<Object, ObjectError>{
+        let slot = self.get_mut(handle)?;
+        //the new object inherits where the old one was drawn so that area gets cleaned up
+        object.drawn = slot.drawn.take();
+        object.needs_draw = true;
+        Ok(std::mem::replace(slot, object))
+    }
+    //mutable access for the display, doesnt touch the dirty flag
+    pub(crate) fn peek_mut(&mut self, handle:ObjectHandle) -> Result<&mut Object, ObjectError>{
+        self.slot_mut(handle)?.object.as_mut().ok_or(ObjectError::StaleHandle(handle))
+    }
+    //every live object mutably, doesnt touch the dirty flag
+    pub(crate) fn iter_peek_mut(&mut self) -> impl Iterator<Item = &mut Object>{
+        self.slots.iter_mut().filter_map(|slot|slot.object.as_mut())
+    }
+    ///Returns true if handle still refers to a live object
+    pub fn contains(&self, handle:ObjectHandle) -> bool{
+        self.get(handle).is_ok()
+    }
+    ///Number of live objects
+    pub fn len(&self) -> usize{
+        self.slots.len() - self.free.len()
+    }
+    pub fn is_empty(&self) -> bool{
+        self.len() == 0
+    }
+    ///Iterates over every live object along with its handle, in slot order
+    pub fn iter(&self) -> impl Iterator<Item = (ObjectHandle, &Object)>{
+        self.slots.iter().enumerate().filter_map(|(index, slot)|{
+            slot.object.as_ref().map(|object|(ObjectHandle{index:index as u32, generation:slot.generation}, object))
+        })
+    }
+
+    fn slot_mut(&mut self, handle:ObjectHandle) -> Result<&mut ObjectSlot, ObjectError>{
+        self.slots.get_mut(handle.index as usize)
+            .filter(|slot|slot.generation == handle.generation)
+            .ok_or(ObjectError::StaleHandle(handle))
+    }
+}
@@ -42,7 +42,68 @@ impl Size{
     }
 }   
 
-    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+///Rect is an integer rectangle on the display grid, x0 and y0 are inclusive, x1 and y1 are exclusive. can sit partly or fully off the grid
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Rect{
+    pub x0:i32,
+    pub y0:i32,
+    pub x1:i32,
+    pub y1:i32,
+}
+
+impl Rect{
+    pub fn new(x0:i32, y0:i32, x1:i32, y1:i32) -> Self{
+        Rect{x0, y0, x1, y1}
+    }
+    ///Rect covering size with the top left corner at point, point is floored onto the grid
+    pub fn at(point:Point, size:Size) -> Self{
+        let x0 = point.x.floor() as i32;
+        let y0 = point.y.floor() as i32;
+        Rect{x0, y0, x1:x0 + size.width as i32, y1:y0 + size.height as i32}
+    }
+    ///Rect covering size with the top left corner at the origin
+    pub fn of_size(size:Size) -> Self{
+        Rect{x0:0, y0:0, x1:size.width as i32, y1:size.height as i32}
+    }
+    pub fn width(&self) -> i32{
+        (self.x1 - self.x0).max(0)
+    }
+    pub fn height(&self) -> i32{
+        (self.y1 - self.y0).max(0)
+    }
+    pub fn is_empty(&self) -> bool{
+        self.x1 <= self.x0 || self.y1 <= self.y0
+    }
+    ///Smallest rect containing both, empty rects are ignored
+    pub fn union(&self, other:&Rect) -> Rect{
+        if self.is_empty(){return *other;}
+        if other.is_empty(){return *self;}
+        Rect{
+            x0:self.x0.min(other.x0),
+            y0:self.y0.min(other.y0),
+            x1:self.x1.max(other.x1),
+            y1:self.y1.max(other.y1),
+        }
+    }
+    ///Overlap of both rects, may be empty
+    pub fn intersect(&self, other:&Rect) -> Rect{
+        Rect{
+            x0:self.x0.max(other.x0),
+            y0:self.y0.max(other.y0),
+            x1:self.x1.min(other.x1),
+            y1:self.y1.min(other.y1),
+        }
+    }
+    pub fn intersects(&self, other:&Rect) -> bool{
+        !self.intersect(other).is_empty()
+    }
+    ///True if the rects overlap or share an edge, touching rects can be merged without covering anything extra along that edge
+    pub fn touches(&self, other:&Rect) -> bool{
+        self.x0 <= other.x1 && other.x0 <= self.x1 && self.y0 <= other.y1 && other.y0 <= self.y1
+    }
+}
+
+    #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
     ///Point stores an x, y position on the terminal grid, can also be encoded with netagives to work as a directional offset
     pub struct Point{pub x:f32, pub y:f32}
 
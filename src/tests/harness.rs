@@ -0,0 +1,251 @@
+// ===============================
+//            HARNESS.RS
+// -------------------------------
+//  Steps screens headless with scripted input
+//  and compares frames against golden images
+//  stored in tests/golden
+// -------------------------------
+
+use std::{
+    env,
+    fs::{create_dir_all, File},
+    io::{BufReader, BufWriter, Error},
+    path::{Path, PathBuf}
+};
+
+use png::{BitDepth, ColorType, Decoder, Encoder, Transformations};
+
+use smallvec::SmallVec;
+
+use crate::{
+    engine::{display::{DrawCall, LogicCall}, engine::Engine, screens::Screen},
+    graphics::render_target::Framebuffer,
+    io::input::InputState,
+    object::{Object, ObjectHandle, ObjectRegistry},
+    shared::{Point, Size, BPP},
+};
+
+///setting this environment variable to anything rewrites the golden images instead of comparing against them
+pub const BLESS_VAR:&str = "JOSENGINE_BLESS";
+
+///Snapshot is a copy of an rgba frame along with its size
+#[derive(Clone, Debug, PartialEq)]
+pub struct Snapshot{
+    pub size:Size,
+    pub data:Vec<u8>,
+}
+
+///ScreenHarness owns a headless engine running one screen, every step is exactly one logic tick followed by a render
+pub struct ScreenHarness{
+    pub engine:Engine<Framebuffer>,
+}
+
+impl ScreenHarness{
+    ///Public constructor for the harness, runs the screens init frame immediately
+    pub fn new(screen:Box<dyn Screen>) -> Self{
+        //tickrate doesnt matter here, the synthetic clock always advances exactly one tick per step
+        let mut engine = Engine::headless(60.0, Point::at(0.0,0.0), screen);
+        engine.run_once();
+        ScreenHarness{engine}
+    }
+    ///Runs one tick with the given input, returns true if the screen asked the engine to quit
+    pub fn step(&mut self, input:InputState) -> bool{
+        self.engine.input.input_state = input;
+        self.engine.run_once()
+    }
+    ///Runs one tick for every input in the script, stops early if the screen quits
+    pub fn run(&mut self, script:&[InputState]) -> bool{
+        script.iter().any(|input|self.step(*input))
+    }
+    ///Runs ticks ticks holding the same input
+    pub fn run_for(&mut self, ticks:usize, input:InputState) -> bool{
+        (0..ticks).any(|_|self.step(input))
+    }
+    ///Copy of the whole display buffer, before any camera is applied
+    pub fn display_buffer(&self) -> Snapshot{
+        let display = self.engine.display();
+        Snapshot{
+            size:display.display_size,
+            data:display.display_buffer[..display.display_size.pixels()*BPP].to_vec(),
+        }
+    }
+    ///Copy of the last frame the renderer presented, which is the camera view if the screen set an output camera
+    pub fn camera_frame(&self) -> Snapshot{
+        Snapshot{
+            size:self.engine.renderer.render_size,
+            data:self.engine.renderer.target.frame().to_vec(),
+        }
+    }
+}
+
+///ObjectScreen spawns a fixed set of objects on a display of a given size and draws all of them every frame, for tests that only care about how objects render
+pub struct ObjectScreen{
+    pub display_size:Size,
+    pub objects:Vec<Object>,
+    pub handles:Vec<ObjectHandle>,
+}
+
+impl ObjectScreen{
+    pub fn new(display_size:Size, objects:Vec<Object>) -> Box<Self>{
+        Box::new(ObjectScreen{display_size, objects, handles:Vec::new()})
+    }
+}
+
+impl Screen for ObjectScreen{
+    fn init_screen(&mut self, _drawbuffer:&mut SmallVec<[DrawCall;256]>, logic_buffer:&mut SmallVec<[LogicCall;256]>, objects:&mut ObjectRegistry){
+        self.handles = self.objects.drain(..).map(|object|objects.spawn(object)).collect();
+        logic_buffer.push(LogicCall::SetDisplaySize(self.display_size));
+    }
+    fn update(&mut self, _logic_buffer:&mut SmallVec<[LogicCall;256]>, _objects:&mut ObjectRegistry, _input:&InputState) -> (Option<Box<dyn Screen>>,bool){
+        (None, false)
+    }
+    fn draw(&self, drawbuffer:&mut SmallVec<[DrawCall;256]>){
+        drawbuffer.extend(self.handles.iter().map(|handle|DrawCall::DrawObject(*handle)));
+    }
+}
+
+type LogicBuffer = SmallVec<[LogicCall;256]>;
+type DrawBuffer = SmallVec<[DrawCall;256]>;
+
+///ScriptedScreen runs closures as its init, update and draw, for tests that need a screen of their own. init spawns the objects and
+///sends the logic calls the test starts with, whatever it returns is the state update and draw are handed every tick
+pub struct ScriptedScreen<S>{
+    pub display_size:Size,
+    pub state:Option<S>,
+    init:Box<dyn FnMut(&mut ObjectRegistry, &mut LogicBuffer) -> S>,
+    update:Box<dyn FnMut(&mut S, &mut LogicBuffer, &InputState)>,
+    draw:Box<dyn Fn(&S, &mut DrawBuffer)>,
+}
+
+impl<S:'static> ScriptedScreen<S>{
+    pub fn new(
+        display_size:Size,
+        init:impl FnMut(&mut ObjectRegistry, &mut LogicBuffer) -> S + 'static,
+        update:impl FnMut(&mut S, &mut LogicBuffer, &InputState) + 'static,
+        draw:impl Fn(&S, &mut DrawBuffer) + 'static,
+    ) -> Box<Self>{
+        Box::new(ScriptedScreen{display_size, state:None, init:Box::new(init), update:Box::new(update), draw:Box::new(draw)})
+    }
+}
+
+impl<S> Screen for ScriptedScreen<S>{
+    fn init_screen(&mut self, _drawbuffer:&mut DrawBuffer, logic_buffer:&mut LogicBuffer, objects:&mut ObjectRegistry){
+        logic_buffer.push(LogicCall::SetDisplaySize(self.display_size));
+        self.state = Some((self.init)(objects, logic_buffer));
+    }
+    fn update(&mut self, logic_buffer:&mut LogicBuffer, _objects:&mut ObjectRegistry, input:&InputState) -> (Option<Box<dyn Screen>>,bool){
+        if let Some(state) = self.state.as_mut(){
+            (self.update)(state, logic_buffer, input);
+        }
+        (None, false)
+    }
+    fn draw(&self, drawbuffer:&mut DrawBuffer){
+        if let Some(state) = self.state.as_ref(){
+            (self.draw)(state, drawbuffer);
+        }
+    }
+}
+
+///Compares snapshot against tests/golden/<name>.png. channels may differ by up to tolerance before a pixel counts as a mismatch.
+///on mismatch writes the actual frame and a diff image to target/golden/ and panics with the mismatch count
+pub fn assert_golden(name:&str, snapshot:&Snapshot, tolerance:u8){
+    let golden_path = golden_dir().join(format!("{name}.png"));
+    //blessing just overwrites the reference with whatever the screen renders now
+    if env::var_os(BLESS_VAR).is_some(){
+        write_png(&golden_path, snapshot).expect("failed to write golden image");
+        return;
+    }
+    let golden = match read_png(&golden_path){
+        Ok(golden) => golden,
+        Err(error) => panic!("could not read golden image {} ({error}), run with {BLESS_VAR}=1 to create it", golden_path.display()),
+    };
+    let failure = if golden.size != snapshot.size{
+        Some(format!("size mismatch, golden is {:?} but frame is {:?}", golden.size, snapshot.size))
+    }else{
+        let mismatched = diff_count(&golden, snapshot, tolerance);
+        (mismatched != 0).then(||format!("{mismatched} of {} pixels differ by more than {tolerance}", snapshot.size.pixels()))
+    };
+    if let Some(failure) = failure{
+        //writes out the actual frame and the diff next to each other so they can be inspected
+        let out_dir = diff_dir();
+        let actual_path = out_dir.join(format!("{name}.actual.png"));
+        let diff_path = out_dir.join(format!("{name}.diff.png"));
+        write_png(&actual_path, snapshot).expect("failed to write actual image");
+        //a diff only makes sense when the sizes line up
+        if golden.size != snapshot.size{
+            panic!("golden image {name} failed: {failure}\nactual: {}", actual_path.display());
+        }
+        write_png(&diff_path, &diff_image(&golden, snapshot, tolerance)).expect("failed to write diff image");
+        panic!("golden image {name} failed: {failure}\nactual: {}\ndiff: {}", actual_path.display(), diff_path.display());
+    }
+}
+
+///Returns the number of pixels where any channel differs by more than tolerance
+pub fn diff_count(golden:&Snapshot, actual:&Snapshot, tolerance:u8) -> usize{
+    golden.data.chunks_exact(BPP)
+        .zip(actual.data.chunks_exact(BPP))
+        .filter(|(golden, actual)|!pixel_matches(golden, actual, tolerance))
+        .count()
+}
+
+///Builds a diff image, pixels within tolerance are a dimmed greyscale of the frame and mismatches are solid red
+pub fn diff_image(golden:&Snapshot, actual:&Snapshot, tolerance:u8) -> Snapshot{
+    let mut data = Vec::with_capacity(actual.data.len());
+    for (golden, actual) in golden.data.chunks_exact(BPP).zip(actual.data.chunks_exact(BPP)){
+        if pixel_matches(golden, actual, tolerance){
+            //averages rgb and dims it so the red stands out
+            let grey = ((actual[0] as u16 + actual[1] as u16 + actual[2] as u16)/9) as u8;
+            data.extend_from_slice(&[grey, grey, grey, 255]);
+        }else{
+            data.extend_from_slice(&[255, 0, 0, 255]);
+        }
+    }
+    Snapshot{size:actual.size, data}
+}
+
+fn pixel_matches(golden:&[u8], actual:&[u8], tolerance:u8) -> bool{
+    golden.iter().zip(actual).all(|(golden, actual)|golden.abs_diff(*actual) <= tolerance)
+}
+
+fn golden_dir() -> PathBuf{
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("golden")
+}
+
+fn diff_dir() -> PathBuf{
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("target").join("golden")
+}
+
+///Reads an 8 bit rgba png into a snapshot
+pub fn read_png(path:&Path) -> Result<Snapshot, Error>{
+    let mut decoder = Decoder::new(BufReader::new(File::open(path)?));
+    decoder.set_transformations(Transformations::EXPAND);
+    let mut reader = decoder.read_info().map_err(Error::other)?;
+    let mut data = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut data).map_err(Error::other)?;
+    //goldens are only ever written by write_png so anything else is a corrupted or hand edited file
+    if info.color_type != ColorType::Rgba || info.bit_depth != BitDepth::Eight{
+        return Err(Error::other(format!("{} is not an 8 bit rgba png", path.display())));
+    }
+    data.truncate(info.buffer_size());
+    Ok(Snapshot{
+        size:Size::is(info.width as usize, info.height as usize),
+        data,
+    })
+}
+
+///Writes a snapshot out as an 8 bit rgba png, creating the parent folder if needed
+pub fn write_png(path:&Path, snapshot:&Snapshot) -> Result<(), Error>{
+    if let Some(parent) = path.parent(){
+        create_dir_all(parent)?;
+    }
+    let mut encoder = Encoder::new(
+        BufWriter::new(File::create(path)?),
+        snapshot.size.width as u32,
+        snapshot.size.height as u32
+    );
+    encoder.set_color(ColorType::Rgba);
+    encoder.set_depth(BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(Error::other)?;
+    writer.write_image_data(&snapshot.data).map_err(Error::other)?;
+    Ok(())
+}
@@ -0,0 +1,1210 @@
+use crate::graphics::parse_ttf_bindings::{load_font};
+
+pub mod harness;
+
+///loads every font in the fonts folder by name and checks the format read from its header, broken or missing fonts dont load
+#[test]
+fn test_font_load(){
+    for font_name in ["nonexistent_font.ttf", "invalid_font.ttf"]{
+        assert!(load_font(font_name).is_err(), "{font_name} should not load");
+    }
+    for (font_name, format) in [("ttf_test.ttf", "TrueType"), ("otf_test.otf", "OpenTypeCFF"), ("legacy_macos_test.ttf", "TrueType")]{
+        let loaded_font = load_font(font_name).unwrap_or_else(|error|panic!("{font_name} should load: {error}"));
+        assert_eq!(loaded_font.get_format(), format, "{font_name}");
+    }
+}
+///steps every screen headless for a few frames, makes sure nothing needs a window and the framebuffer ends up matching the output size
+#[test]
+fn headless_engine_steps_screens(){
+    use crate::{
+        engine::{engine::Engine, screens::{main_menu::MainMenu, snake::Snake, Screen}},
+        shared::{Point, Size, BPP},
+    };
+    let screens:Box<[(Box<dyn Screen>, Size)]> = vec![
+        //main menu renders through camera 0, which is 100x100
+        (MainMenu::init(), Size::is(100, 100)),
+        //snake has no output camera so the whole 22x32 display is copied, board and score under it
+        (Snake::init(), Size::is(22, 32)),
+    ].into_boxed_slice();
+    for (screen, output_size) in screens{
+        let mut engine = Engine::headless(60.0, Point::at(0.0,0.0), screen);
+        for _ in 0..10{
+            assert!(!engine.run_once(), "screen asked to quit without any input");
+        }
+        assert_eq!(engine.renderer.render_size, output_size);
+        assert_eq!(engine.renderer.target.frame().len(), output_size.pixels()*BPP);
+        //something has to have been drawn, every screen paints opaque pixels
+        assert!(engine.renderer.target.frame().chunks_exact(BPP).any(|pixel|pixel[3] != 0));
+    }
+}
+
+///walks the main menu object down and to the right and checks what camera 0 sees, covers buffer_object, composite and capture
+#[test]
+fn main_menu_camera_golden(){
+    use crate::{engine::screens::main_menu::MainMenu, io::input::InputState};
+    use harness::{assert_golden, ScreenHarness};
+    let mut harness = ScreenHarness::new(MainMenu::init());
+    let down_right = InputState{down:true, right:true, ..Default::default()};
+    assert!(!harness.run_for(8, down_right));
+    assert_golden("main_menu_camera", &harness.camera_frame(), 1);
+}
+
+///plays a few moves of a seeded snake game and checks the whole display buffer, covers buffer_pixels
+#[test]
+fn snake_display_golden(){
+    use crate::{engine::screens::snake::Snake, io::input::InputState};
+    use harness::{assert_golden, ScreenHarness};
+    let mut harness = ScreenHarness::new(Snake::init_seeded(7));
+    //snake moves once every 4 ticks so this is 3 moves right then 4 moves down, nowhere near a wall
+    assert!(!harness.run_for(12, InputState{right:true, ..Default::default()}));
+    assert!(!harness.run_for(16, InputState{down:true, ..Default::default()}));
+    assert_golden("snake_display", &harness.display_buffer(), 0);
+}
+
+///spawns past the old fixed 2048 slots, despawns and reuses slots and makes sure old handles are reported as stale
+#[test]
+fn object_registry_handles(){
+    use crate::{object::{Object, ObjectError, ObjectHandle, ObjectRegistry}, shared::Point};
+    let mut objects = ObjectRegistry::with_capacity(16);
+    let handles:Vec<ObjectHandle> = (0..3000).map(|i|objects.spawn(Object::new(Point::at(i as f32, 0.0), Object::default().sprite))).collect();
+    assert_eq!(objects.len(), 3000);
+    assert_eq!(objects.get(handles[2999]).unwrap().point, Point::at(2999.0, 0.0));
+    //despawned handle is stale, and stays stale after its slot is reused
+    let removed = handles[10];
+    assert!(objects.despawn(removed).is_ok());
+    assert_eq!(objects.get(removed).unwrap_err(), ObjectError::StaleHandle(removed));
+    let reused = objects.spawn(Object::new(Point::at(-1.0, 0.0), Object::default().sprite));
+    assert_eq!(objects.len(), 3000);
+    assert!(objects.get(removed).is_err());
+    assert!(objects.despawn(removed).is_err());
+    assert_eq!(objects.get(reused).unwrap().point, Point::at(-1.0, 0.0));
+    //clearing invalidates everything
+    objects.clear();
+    assert!(objects.is_empty());
+    assert!(!objects.contains(reused) && !objects.contains(handles[0]));
+    assert!(!objects.contains(ObjectHandle::DANGLING));
+}
+
+///draws a pixel on the ui layer before an object on the world layer and checks the pixel still ends up on top, then hides the ui layer
+#[test]
+fn layers_ignore_push_order(){
+    use crate::{
+        engine::{display::{DrawCall, LogicCall}, layer::Layer},
+        graphics::sprites::default_sprite::DEFAULT_SPRITE,
+        io::input::InputState,
+        object::Object,
+        shared::{Point, Size, BPP},
+    };
+    use harness::{ScreenHarness, ScriptedScreen};
+    let mut harness = ScreenHarness::new(ScriptedScreen::new(Size::is(50, 50),
+        |objects, _logic_buffer|objects.spawn(Object::new(Point::at(0.0,0.0), DEFAULT_SPRITE.clone())),
+        |_object, logic_buffer, input|logic_buffer.extend([
+            LogicCall::PixelAt(Point::at(1.0,1.0), [255,0,0,255]),
+            LogicCall::SetLayerVisible(Layer::UI, !input.north),
+        ]),
+        |&object, drawbuffer|drawbuffer.extend([
+            DrawCall::ClearDisplay,
+            DrawCall::DrawPixels(Layer::UI, 0),
+            DrawCall::DrawObject(object),
+        ]),
+    ));
+    let pixel_at = |harness:&ScreenHarness|{
+        let index = (50 + 1)*BPP;
+        harness.display_buffer().data[index..index+BPP].to_vec()
+    };
+    harness.step(InputState::default());
+    assert_eq!(pixel_at(&harness), [255,0,0,255]);
+    //hidden ui layer leaves the sprite underneath visible
+    harness.step(InputState{north:true, ..Default::default()});
+    assert_eq!(pixel_at(&harness), [0,0,0,255]);
+}
+
+///moves an object without ever clearing the display, checks it leaves no trail and that only the dirty area is copied to the backbuffer
+#[test]
+fn dirty_regions_redraw_only_changes(){
+    use crate::{
+        engine::display::{DrawCall, LogicCall},
+        graphics::sprites::default_sprite::DEFAULT_SPRITE,
+        io::input::InputState,
+        object::Object,
+        shared::{Point, Size, BPP},
+    };
+    use harness::{ScreenHarness, ScriptedScreen};
+    let mut harness = ScreenHarness::new(ScriptedScreen::new(Size::is(100, 100),
+        |objects, _logic_buffer|objects.spawn(Object::new(Point::at(0.0,0.0), DEFAULT_SPRITE.clone())),
+        |&mut object, logic_buffer, input|logic_buffer.push(LogicCall::MoveObject(object, input.direction, 5.0)),
+        |&object, drawbuffer|drawbuffer.push(DrawCall::DrawObject(object)),
+    ));
+    harness.step(InputState::default());
+    let pixel_index = |x:usize, y:usize|(y*100 + x)*BPP;
+    //the sprites top left pixel is opaque black, the empty display is all zeros
+    assert_eq!(harness.display_buffer().data[pixel_index(0,0)..pixel_index(0,0)+BPP], [0,0,0,255]);
+    //scribbles on the backbuffer somewhere the object never goes, a partial copy leaves it alone
+    let marker = pixel_index(99,99);
+    harness.engine.renderer.target.frame[marker..marker+BPP].copy_from_slice(&[1,2,3,4]);
+    harness.step(InputState{right:true, ..Default::default()});
+    let frame = harness.camera_frame();
+    assert_eq!(frame.data[marker..marker+BPP], [1,2,3,4]);
+    //the strip the object moved off of is cleared in both the display buffer and the backbuffer
+    for y in 0..40{
+        for x in 0..5{
+            let index = pixel_index(x, y);
+            assert_eq!(frame.data[index..index+BPP], [0,0,0,0], "trail at {x},{y}");
+        }
+    }
+    //everything apart from the marker matches the display buffer
+    assert_eq!(frame.data[..marker], harness.display_buffer().data[..marker]);
+    assert_eq!(frame.data[pixel_index(5,0)..pixel_index(5,0)+BPP], [0,0,0,255]);
+}
+
+///hangs unclamped objects off the top left and bottom right edges and scribbles pixels outside the display, everything outside gets clipped
+#[test]
+fn objects_clip_against_display(){
+    use crate::{
+        engine::{display::{DrawCall, LogicCall}, layer::Layer},
+        graphics::sprites::default_sprite::DEFAULT_SPRITE,
+        io::input::InputState,
+        object::Object,
+        shared::{Point, Size, BPP},
+    };
+    use harness::{ScreenHarness, ScriptedScreen};
+    let mut harness = ScreenHarness::new(ScriptedScreen::new(Size::is(50, 50),
+        |objects, logic_buffer|{
+            let handles = [
+                objects.spawn(Object::new(Point::at(0.0,0.0), DEFAULT_SPRITE.clone()).unclamped()),
+                objects.spawn(Object::new(Point::at(0.0,0.0), DEFAULT_SPRITE.clone()).unclamped()),
+                //clamped objects still get pushed back inside
+                objects.spawn(Object::new(Point::at(0.0,0.0), DEFAULT_SPRITE.clone()).on_layer(Layer::BACKGROUND)),
+            ];
+            logic_buffer.extend([
+                LogicCall::SetObject(handles[0], Point::at(-10.0,-5.0)),
+                LogicCall::SetObject(handles[1], Point::at(30.0,30.0)),
+                LogicCall::SetObject(handles[2], Point::at(-10.0,-5.0)),
+                LogicCall::PixelAt(Point::at(-1.0,-1.0), [255,0,0,255]),
+                LogicCall::PixelAt(Point::at(50.0,10.0), [255,0,0,255]),
+                LogicCall::PixelAt(Point::at(10.0,50.0), [255,0,0,255]),
+            ]);
+            handles
+        },
+        |_handles, _logic_buffer, _input|{},
+        |handles, drawbuffer|{
+            drawbuffer.extend(handles.map(DrawCall::DrawObject));
+            drawbuffer.push(DrawCall::DrawPixels(Layer::UI, 0));
+        },
+    ));
+    harness.step(InputState::default());
+    assert_eq!(harness.engine.display().objects.iter().nth(2).unwrap().1.point, Point::at(0.0,0.0));
+    let display = harness.display_buffer();
+    let sprite_pixel = |x:usize, y:usize|&DEFAULT_SPRITE.data[(y*41 + x)*BPP..(y*41 + x + 1)*BPP];
+    let display_pixel = |x:usize, y:usize|&display.data[(y*50 + x)*BPP..(y*50 + x + 1)*BPP];
+    //top left object is shifted up and left by its offset
+    assert_eq!(display_pixel(0,0), sprite_pixel(10,5));
+    assert_eq!(display_pixel(29,29), sprite_pixel(39,34));
+    //bottom right object starts at 30,30 and the rest falls off the display
+    assert_eq!(display_pixel(30,30), sprite_pixel(0,0));
+    assert_eq!(display_pixel(49,49), sprite_pixel(19,19));
+    //none of the out of bounds pixels wrapped around onto the display
+    assert!(display.data.chunks_exact(BPP).all(|pixel|pixel != [255,0,0,255]));
+}
+
+///pixel canvases only cover the part of the display that was painted and keep their pixels as they grow
+#[test]
+fn pixel_canvases_grow_to_fit(){
+    use crate::{
+        engine::{display::PixelCanvas, layer::Layer},
+        graphics::{blend::BlendMode, render_target::Framebuffer, renderer::{Pixel, RenderHook}},
+        shared::{Point, Rect, Size, BPP},
+    };
+    let mut hook = RenderHook::create_render_hook(Size::is(1, 1), Framebuffer::new(Size::is(1, 1)));
+    let mut canvas = PixelCanvas::new(Layer::UI, 0, BlendMode::Normal);
+    assert!(canvas.data.is_empty());
+    let mut paint = |canvas:&mut PixelCanvas, x:f32, y:f32, color:[u8;4]|{
+        let pixel = Pixel::with_color(Point::at(x, y), color);
+        canvas.grow(pixel.rect());
+        hook.buffer_pixels(&mut canvas.data, &canvas.bounds, &[pixel]);
+    };
+    paint(&mut canvas, 10.0, 20.0, [255,0,0,255]);
+    assert_eq!((canvas.bounds, canvas.data.len()), (Rect::new(10, 20, 11, 21), BPP));
+    paint(&mut canvas, 7.0, 22.0, [0,255,0,255]);
+    assert_eq!((canvas.bounds, canvas.data.len()), (Rect::new(7, 20, 11, 23), 4*3*BPP));
+    //the first pixel moved over to where it sits in the bigger canvas
+    assert_eq!(canvas.data[3*BPP..4*BPP], [255,0,0,255]);
+    assert_eq!(canvas.data[2*4*BPP..(2*4 + 1)*BPP], [0,255,0,255]);
+    canvas.clear();
+    assert!(canvas.bounds.is_empty() && canvas.data.is_empty());
+}
+
+///draws the default sprite flipped, quarter turned and scaled and checks every pixel against where it came from in the sprite
+#[test]
+fn object_transforms_remap_pixels(){
+    use crate::{
+        graphics::{sprites::default_sprite::DEFAULT_SPRITE, transform::{Rotation, Transform}},
+        io::input::InputState,
+        object::Object,
+        shared::{Point, Size, BPP},
+    };
+    use harness::{ObjectScreen, ScreenHarness, Snapshot};
+    let render = |transform:Transform|{
+        let object = Object::new(Point::at(0.0,0.0), DEFAULT_SPRITE.clone()).with_transform(transform);
+        let mut harness = ScreenHarness::new(ObjectScreen::new(Size::is(100, 100), vec![object]));
+        harness.step(InputState::default());
+        harness.display_buffer()
+    };
+    let sprite_pixel = |x:usize, y:usize|&DEFAULT_SPRITE.data[(y*41 + x)*BPP..(y*41 + x + 1)*BPP];
+    let check = |snapshot:&Snapshot, size:Size, source:&dyn Fn(usize, usize) -> (usize, usize)|{
+        for y in 0..size.height{
+            for x in 0..size.width{
+                let (src_x, src_y) = source(x, y);
+                let index = (y*100 + x)*BPP;
+                assert_eq!(&snapshot.data[index..index+BPP], sprite_pixel(src_x, src_y), "pixel {x},{y}");
+            }
+        }
+    };
+    check(&render(Transform::IDENTITY.flipped_x()), Size::is(41, 40), &|x, y|(40 - x, y));
+    check(&render(Transform::IDENTITY.flipped_y()), Size::is(41, 40), &|x, y|(x, 39 - y));
+    check(&render(Transform::IDENTITY.rotated(Rotation::Quarter)), Size::is(40, 41), &|x, y|(y, 39 - x));
+    check(&render(Transform::IDENTITY.rotated(Rotation::Half)), Size::is(41, 40), &|x, y|(40 - x, 39 - y));
+    check(&render(Transform::IDENTITY.rotated(Rotation::ThreeQuarter)), Size::is(40, 41), &|x, y|(40 - y, x));
+    check(&render(Transform::IDENTITY.scaled(2)), Size::is(82, 80), &|x, y|(x/2, y/2));
+    //flip happens before the turn
+    check(&render(Transform::IDENTITY.flipped_x().rotated(Rotation::Quarter)), Size::is(40, 41), &|x, y|(40 - y, 39 - x));
+}
+
+///rotates and scales the default sprite through the affine path, half turns have to match the quarter turn transform and bilinear output has to stay valid premultiplied color
+#[test]
+fn affine_objects_sample_sprites(){
+    use std::f32::consts::PI;
+    use crate::{
+        graphics::{sprites::default_sprite::DEFAULT_SPRITE, transform::{Affine, Rotation, Sampling, Transform}},
+        io::input::InputState,
+        object::Object,
+        shared::{Point, Size, BPP},
+    };
+    use harness::{ObjectScreen, ScreenHarness, Snapshot};
+    let render = |object:Object|{
+        let mut harness = ScreenHarness::new(ObjectScreen::new(Size::is(100, 100), vec![object]));
+        harness.step(InputState::default());
+        harness.display_buffer()
+    };
+    let sprite = |affine:Affine|Object::new(Point::at(20.0,20.0), DEFAULT_SPRITE.clone()).with_affine(affine);
+    let plain = render(Object::new(Point::at(20.0,20.0), DEFAULT_SPRITE.clone()));
+    //sampling at pixel centers without rotating or scaling is an exact copy in both modes
+    assert_eq!(render(sprite(Affine::default())), plain);
+    assert_eq!(render(sprite(Affine::default().with_sampling(Sampling::Bilinear))), plain);
+    assert_eq!(render(sprite(Affine::rotation(PI))), render(Object::new(Point::at(20.0,20.0), DEFAULT_SPRITE.clone()).with_transform(Transform::IDENTITY.rotated(Rotation::Half))));
+    //half scale covers about a quarter of the pixels
+    let covered = |snapshot:&Snapshot|snapshot.data.chunks_exact(BPP).filter(|pixel|pixel[3] != 0).count();
+    let half = covered(&render(sprite(Affine::scaling(0.5, 0.5))));
+    assert!(half.abs_diff(covered(&plain)/4) <= 41, "half scale covered {half} pixels");
+    //bilinear blends premultiplied colors so no channel can end up brighter than alpha, and the rotated edges come out soft
+    let spun = render(sprite(Affine::rotation(0.3).scaled(1.5, 1.5).with_sampling(Sampling::Bilinear)));
+    assert!(spun.data.chunks_exact(BPP).all(|pixel|pixel[..3].iter().all(|channel|*channel <= pixel[3])));
+    assert!(spun.data.chunks_exact(BPP).any(|pixel|pixel[3] != 0 && pixel[3] != 255));
+}
+
+///cuts the default sprite into a sheet and plays clips in every mode, both directly and through the display
+#[test]
+fn sprite_sheet_animation(){
+    use std::sync::Arc;
+    use crate::{
+        graphics::{animation::{Animator, PlayMode, SpriteSheet}, sprites::{default_sprite::DEFAULT_SPRITE, Sprite}},
+        io::input::InputState,
+        object::Object,
+        shared::{Point, Size},
+    };
+    use harness::{ObjectScreen, ScreenHarness};
+    //rebuilding the ranges at runtime matches what the converter generated
+    let rebuilt = Sprite::from_rgba(DEFAULT_SPRITE.data.to_vec(), DEFAULT_SPRITE.size);
+    let ranges = |sprite:&Sprite|[&*sprite.solid_ranges, &*sprite.translucent_ranges].map(|ranges|ranges.iter()
+        .map(|range|(range.line, range.src_index, range.src_end_index, range.x_begin, range.x_end))
+        .collect::<Vec<_>>());
+    assert_eq!(ranges(&rebuilt), ranges(&*DEFAULT_SPRITE));
+    //41x40 only fits two 20x20 cells each way, the leftover column is dropped
+    let sheet = SpriteSheet::from_grid(&DEFAULT_SPRITE, Size::is(20, 20))
+        .with_clip("loop", &[(0, 2), (1, 1), (2, 1)], PlayMode::Loop)
+        .with_clip("pingpong", &[(0, 1), (1, 1), (2, 1)], PlayMode::PingPong)
+        .with_clip("once", &[(3, 1), (1, 1)], PlayMode::Once);
+    assert_eq!(sheet.frames.len(), 4);
+    assert_eq!(sheet.frame(1).pixel(0, 0), DEFAULT_SPRITE.pixel(20, 0));
+    assert_eq!(sheet.frame(2).pixel(5, 3), DEFAULT_SPRITE.pixel(5, 23));
+    let sheet = Arc::new(sheet);
+    let play = |name:&str, ticks:usize|{
+        let mut animator = Animator::new(sheet.clone(), name).unwrap();
+        (0..ticks).map(|_|{animator.tick(); animator.frame()}).collect::<Vec<_>>()
+    };
+    assert_eq!(play("loop", 6), [0, 1, 2, 0, 0, 1]);
+    assert_eq!(play("pingpong", 6), [1, 2, 1, 0, 1, 2]);
+    assert_eq!(play("once", 4), [1, 1, 1, 1]);
+    assert!(Animator::new(sheet.clone(), "missing").is_none());
+    //the display ticks animators and swaps the sprite in
+    let object = Object::new(Point::at(0.0,0.0), DEFAULT_SPRITE.clone()).with_animator(Animator::new(sheet.clone(), "pingpong").unwrap());
+    assert_eq!(object.sprite.size, Size::is(20, 20));
+    let mut harness = ScreenHarness::new(ObjectScreen::new(Size::is(20, 20), vec![object]));
+    //the init frame already ran one logic tick
+    harness.step(InputState::default());
+    assert_eq!(harness.display_buffer().data, sheet.frame(2).data.to_vec());
+}
+
+///loads mario.png at runtime and checks it against the sprite build.rs generated from the same file, then round trips translucent pixels
+#[test]
+fn png_sprites_match_generated(){
+    use std::path::Path;
+    use crate::{graphics::sprites::{loader::SpriteError, mario_sprite::MARIO_SPRITE, Sprite}, shared::{Size, BPP}};
+    use harness::{write_png, Snapshot};
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mario = Sprite::load_png(root.join("assets/sprites/mario.png")).unwrap();
+    assert_eq!(mario.size, Size::is(12, 16));
+    assert_eq!(mario.size, MARIO_SPRITE.size);
+    assert_eq!(&*mario.data, &*MARIO_SPRITE.data);
+    let ranges = |sprite:&Sprite|[&*sprite.solid_ranges, &*sprite.translucent_ranges].map(|ranges|ranges.iter()
+        .map(|range|(range.line, range.src_index, range.src_end_index, range.x_begin, range.x_end))
+        .collect::<Vec<_>>());
+    assert_eq!(ranges(&mario), ranges(&*MARIO_SPRITE));
+    //straight alpha in, premultiplied out, rounded like the script
+    let path = root.join("target/sprites/translucent.png");
+    write_png(&path, &Snapshot{size:Size::is(3, 1), data:vec![200,100,50,128, 10,20,30,0, 255,255,255,255]}).unwrap();
+    let sprite = Sprite::load_png(&path).unwrap();
+    assert_eq!(&*sprite.data, &[100,50,25,128, 0,0,0,0, 255,255,255,255]);
+    assert_eq!((sprite.translucent_ranges.len(), sprite.solid_ranges.len()), (1, 1));
+    assert_eq!(sprite.solid_ranges[0].x_begin, 2*BPP);
+    assert!(matches!(Sprite::load_png(root.join("missing.png")), Err(SpriteError::Io(_))));
+    assert!(matches!(Sprite::from_png_bytes(b"not a png"), Err(SpriteError::Decode(_))));
+}
+
+///run length encoded sprites have to decode and draw exactly like the pngs they were made from, examples/rle_comparison.rs compares
+///their size and speed
+#[test]
+fn rle_sprites_match_raw(){
+    use std::path::Path;
+    use crate::{
+        graphics::{render_target::Framebuffer, renderer::RenderHook, sprites::{
+            blackbuck_sprite::BLACKBUCK_RLE, main_menu_sprite::MAIN_MENU_RLE, mario_sprite::MARIO_RLE, rle::RleSprite,
+            transparency2_sprite::TRANSPARENCY2_RLE, Sprite,
+        }},
+        object::Object,
+        shared::{Point, Rect, Size, BPP},
+    };
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let generated:[(&str, RleSprite);4] = [
+        ("blackbuck", BLACKBUCK_RLE),
+        ("main_menu", MAIN_MENU_RLE),
+        ("mario", MARIO_RLE),
+        ("transparency2", TRANSPARENCY2_RLE),
+    ];
+    for (name, generated) in generated{
+        let sprite = Sprite::load_png(root.join(format!("assets/sprites/{name}.png"))).unwrap();
+        //build.rs and the runtime encoder lay runs out the same way
+        let encoded = RleSprite::encode(&sprite);
+        assert_eq!(&*encoded.runs, &*generated.runs, "{name}");
+        assert_eq!(&*generated.decode().data, &*sprite.data, "{name}");
+        //drawn hanging off the top left corner over a background so clipping and compositing both get checked
+        let size = Size::is(sprite.size.width + 8, sprite.size.height + 8);
+        let point = Point::at(-3.0, -2.0);
+        let clip = Rect::of_size(size);
+        let background:Vec<u8> = (0..size.pixels()*BPP).map(|i|(i*7 % 256) as u8).collect();
+        let mut hook = RenderHook::create_render_hook(size, Framebuffer::new(size));
+        let (mut raw, mut rle, mut rle_object) = (background.clone(), background.clone(), background.clone());
+        hook.buffer_object(&Object::new(point, sprite.clone()).unclamped(), &mut raw, &size, &clip);
+        hook.buffer_rle(&generated, point, &mut rle, &size, &clip);
+        assert!(raw == rle, "{name} drew differently from its runs");
+        //objects made from runs draw their decoded sprite
+        hook.buffer_object(&Object::from_rle(point, &generated).unclamped(), &mut rle_object, &size, &clip);
+        assert!(raw == rle_object, "{name} drew differently as an rle object");
+    }
+}
+
+///indexed sprites have to draw exactly like their rgba version, and swapping or rotating a palette only changes that object
+#[test]
+fn palette_sprites_swap_colors(){
+    use std::sync::Arc;
+    use crate::{
+        graphics::{sprites::{palette::Palette, Sprite}, transform::{Rotation, Transform}},
+        io::input::InputState,
+        object::Object,
+        shared::{Point, Size},
+    };
+    use harness::{ObjectScreen, ScreenHarness, Snapshot};
+    let render = |object:Object|{
+        let mut harness = ScreenHarness::new(ObjectScreen::new(Size::is(8, 8), vec![Object::new(Point::at(0.0,0.0), Sprite::from_rgba(vec![40;8*8*4], Size::is(8, 8))), object.with_z(1)]));
+        harness.step(InputState::default());
+        harness.display_buffer()
+    };
+    let palette = Palette::new(&[[0,0,0,0], [255,0,0,255], [0,255,0,255], [0,0,255,128]]);
+    assert_eq!(palette.color(3), &[0,0,128,128]);
+    let sprite = Sprite::from_indices(vec![0,1,2,3, 3,2,1,0, 1,1,0,2], Size::is(4, 3), palette.clone());
+    assert_eq!((sprite.solid_ranges.len(), sprite.translucent_ranges.len()), (4, 2));
+    let at = |sprite:Sprite|Object::new(Point::at(2.0,3.0), sprite);
+    let expect = |palette:Option<&Palette>, transform:Transform|render(at(sprite.resolved(palette)).with_transform(transform));
+    let drawn:Snapshot = render(at(sprite.clone()));
+    assert_eq!(drawn, expect(None, Transform::IDENTITY));
+    let turned = Transform::IDENTITY.flipped_x().rotated(Rotation::Quarter).scaled(2);
+    assert_eq!(render(at(sprite.clone()).with_transform(turned)), expect(None, turned));
+    //a flash palette keeps every entrys alpha
+    let flash = palette.filled([255,255,255]);
+    assert_eq!(flash.colors(), &[[0,0,0,0], [255,255,255,255], [255,255,255,255], [128,128,128,128]]);
+    assert_eq!(render(at(sprite.clone()).with_palette(flash.clone())), expect(Some(&flash), Transform::IDENTITY));
+    //rotating swaps red and green on this object only, the sprite and its palette stay shared and untouched
+    let mut object = at(sprite.clone());
+    object.palette_mut().unwrap().rotate(1..3, 1);
+    assert_eq!(object.palette().unwrap().colors()[1..3], [[0,255,0,255], [255,0,0,255]]);
+    assert!(Arc::ptr_eq(object.sprite.palette.as_ref().unwrap(), sprite.palette.as_ref().unwrap()));
+    let mut rotated = palette.clone();
+    rotated.rotate(1..3, -1);
+    assert_eq!(render(object), expect(Some(&rotated), Transform::IDENTITY));
+    //cropped frames share the palette too
+    let frame = sprite.crop(1, 1, Size::is(2, 2));
+    assert!(Arc::ptr_eq(frame.palette.as_ref().unwrap(), sprite.palette.as_ref().unwrap()));
+    assert_eq!(frame.pixel(1, 0), &[255,0,0,255]);
+    //rgba sprites have no palette to change
+    assert!(at(sprite.resolved(None)).palette_mut().is_none());
+}
+
+///mixes objects and pixels over a flat background with every blend mode, opacity and tint
+#[test]
+fn blend_modes_mix_with_display(){
+    use crate::{
+        engine::{display::{DrawCall, LogicCall}, layer::Layer},
+        graphics::{blend::{Blend, BlendMode}, sprites::Sprite},
+        io::input::InputState,
+        object::Object,
+        shared::{Point, Size, BPP},
+    };
+    use harness::{ObjectScreen, ScreenHarness, ScriptedScreen};
+    let flat = |color:[u8;4], size:usize|Sprite::from_rgba(color.repeat(size*size), Size::is(size, size));
+    let background = ||Object::new(Point::at(0.0,0.0), flat([100,50,200,255], 4));
+    //top left pixel of a 2x2 sprite drawn with blend over the background
+    let mix = |color:[u8;4], blend:Blend|{
+        let mut harness = ScreenHarness::new(ObjectScreen::new(Size::is(4, 4), vec![
+            background(),
+            Object::new(Point::at(0.0,0.0), flat(color, 2)).with_z(1).with_blend(blend),
+        ]));
+        harness.step(InputState::default());
+        let data = harness.display_buffer().data;
+        //pixels the sprite doesnt cover keep the background
+        assert_eq!(data[(3*4 + 3)*BPP..(3*4 + 4)*BPP], [100,50,200,255]);
+        [data[0], data[1], data[2], data[3]]
+    };
+    let solid = [200,100,50,255];
+    let with = |mode:BlendMode|Blend::NORMAL.with_mode(mode);
+    assert_eq!(mix(solid, Blend::NORMAL), solid);
+    assert_eq!(mix(solid, with(BlendMode::Add)), [255,150,250,255]);
+    assert_eq!(mix(solid, with(BlendMode::Subtract)), [0,0,150,255]);
+    assert_eq!(mix(solid, with(BlendMode::Multiply)), [78,20,39,255]);
+    assert_eq!(mix(solid, with(BlendMode::Screen)), [222,130,211,255]);
+    //opacity and tint scale the source before it is mixed in
+    assert_eq!(mix(solid, Blend::NORMAL.with_opacity(0)), [100,50,200,255]);
+    assert_eq!(mix(solid, Blend::NORMAL.with_opacity(128)), [150,75,125,255]);
+    assert_eq!(mix(solid, Blend::NORMAL.with_tint([255,0,128])), [200,0,25,255]);
+    //translucent sources only add what they cover
+    assert_eq!(mix([64,32,16,128], with(BlendMode::Add)), [164,82,216,255]);
+
+    //pixels with a blend mode go on their own canvas and mix with the object under them. the pixels are sent once and drawn every frame
+    let paint = |mut pixels:Vec<LogicCall>|ScreenHarness::new(ScriptedScreen::new(Size::is(4, 4),
+        move |objects, logic_buffer|{
+            logic_buffer.extend(pixels.drain(..));
+            objects.spawn(Object::new(Point::at(0.0,0.0), Sprite::from_rgba([100,50,200,255].repeat(16), Size::is(4, 4))))
+        },
+        |_background, _logic_buffer, _input|{},
+        |&background, drawbuffer|drawbuffer.extend([DrawCall::DrawObject(background), DrawCall::DrawPixels(Layer::FOREGROUND, 0)]),
+    ));
+    let mut harness = paint(vec![
+        LogicCall::BlendedPixelAt(Point::at(0.0,0.0), [200,100,50,255], BlendMode::Subtract),
+        LogicCall::PixelAt(Point::at(1.0,0.0), [200,100,50,255]),
+    ]);
+    harness.step(InputState::default());
+    let data = harness.display_buffer().data;
+    assert_eq!(data[..2*BPP], [0,0,150,255, 200,100,50,255]);
+    //the canvases stick around, the next frame mixes the same way
+    harness.step(InputState::default());
+    assert_eq!(harness.display_buffer().data, data);
+    //pixels stack in the order they were sent whatever their mode, a normal pixel sent after an additive one covers it
+    let mut harness = paint(vec![
+        LogicCall::PixelAt(Point::at(0.0,0.0), [10,20,30,255]),
+        LogicCall::BlendedPixelAt(Point::at(0.0,0.0), [50,50,50,255], BlendMode::Add),
+        LogicCall::PixelAt(Point::at(0.0,0.0), [200,100,50,255]),
+        LogicCall::PixelAt(Point::at(1.0,0.0), [10,20,30,255]),
+        LogicCall::BlendedPixelAt(Point::at(1.0,0.0), [50,50,50,255], BlendMode::Add),
+    ]);
+    harness.step(InputState::default());
+    assert_eq!(harness.display_buffer().data[..2*BPP], [200,100,50,255, 60,70,80,255]);
+    //the same goes for pixels sent frames apart onto canvases that are already there
+    let mut harness = ScreenHarness::new(ScriptedScreen::new(Size::is(4, 4),
+        |_objects, _logic_buffer|(),
+        |_, logic_buffer, input|{
+            let at = Point::at(0.0,0.0);
+            if input.up{logic_buffer.push(LogicCall::PixelAt(at, [10,20,30,255]));}
+            if input.down{logic_buffer.push(LogicCall::BlendedPixelAt(at, [50,50,50,255], BlendMode::Add));}
+            if input.right{logic_buffer.push(LogicCall::PixelAt(at, [200,100,50,255]));}
+        },
+        |_, drawbuffer|drawbuffer.push(DrawCall::DrawPixels(Layer::FOREGROUND, 0)),
+    ));
+    let mut pixel = |input:InputState|{
+        harness.step(input);
+        harness.display_buffer().data[..BPP].to_vec()
+    };
+    assert_eq!(pixel(InputState{up:true, ..Default::default()}), [10,20,30,255]);
+    assert_eq!(pixel(InputState{down:true, ..Default::default()}), [60,70,80,255]);
+    assert_eq!(pixel(InputState{right:true, ..Default::default()}), [200,100,50,255]);
+    assert_eq!(pixel(InputState::default()), [200,100,50,255]);
+}
+
+///sizes a font and draws strings through the display, checks glyph metrics, kerning and that text is cleaned up once it stops being drawn
+#[test]
+fn text_renders_through_display(){
+    use std::sync::Arc;
+    use crate::{
+        engine::display::DrawCall,
+        graphics::{parse_ttf_bindings, texthandler::{self, ascii_charset, Text}},
+        io::input::InputState,
+        shared::{Point, Size, BPP},
+    };
+    use harness::{ScreenHarness, ScriptedScreen};
+    let loaded_font = parse_ttf_bindings::load_font("ttf_test.ttf").expect("ttf_test.ttf should load");
+    let mut font = texthandler::load_font(loaded_font);
+    let sized = font.sized(16.0, &ascii_charset()).unwrap();
+    //asking for the same size again reuses the rasterized glyphs
+    assert!(Arc::ptr_eq(&sized, &font.sized(16.0, &['a']).unwrap()));
+    //a size asked for with fewer chars first is rasterized again with the rest, not handed back without them
+    let few = font.sized(12.0, &['a']).unwrap();
+    assert_eq!(few.glyphs_len(), 1);
+    let more = font.sized(12.0, &['a', 'b']).unwrap();
+    assert!(!Arc::ptr_eq(&few, &more) && more.glyph('a').is_some() && more.glyph('b').is_some());
+    assert!(Arc::ptr_eq(&more, &font.sized(12.0, &['b']).unwrap()));
+    let space = sized.glyph(' ').unwrap();
+    assert_eq!(space.size, Size::is(0, 0));
+    assert!(space.advance > 0.0);
+    let a = sized.glyph('A').unwrap();
+    assert!(a.size.pixels() != 0 && a.yoff < 0);
+    assert!(sized.glyph('\u{e9}').is_none());
+    assert!(sized.ascent > 0 && sized.line_height() as i32 >= sized.ascent);
+    //kerning shows up in how wide a pair measures
+    let pair = sized.measure("AV").width as i32;
+    let apart = (sized.glyph('A').unwrap().advance + sized.glyph('V').unwrap().advance).ceil() as i32;
+    assert!((pair - apart - sized.kerning('A', 'V').round() as i32).abs() <= 2, "{pair} {apart}");
+    let (sprite, offset) = sized.render("Hi", [255,255,255,255]);
+    assert_eq!(sprite.size, sized.measure("Hi"));
+    assert_eq!(offset.y, 0.0);
+    assert!(sprite.data.chunks_exact(BPP).any(|pixel|pixel == [255,255,255,255]));
+
+    //draws "Hi" while up is held and "Hi!" while down is, nothing otherwise
+    let mut harness = ScreenHarness::new(ScriptedScreen::new(Size::is(64, 32),
+        |_objects, _logic_buffer|None,
+        |text, _logic_buffer, input|*text = if input.up {Some("Hi")} else if input.down {Some("Hi!")} else {None},
+        move |text, drawbuffer|if let Some(text) = *text{
+            drawbuffer.push(DrawCall::DrawText(Text::new(sized.clone(), text, Point::at(2.0, 2.0), [255,0,0,255])));
+        },
+    ));
+    let covered = |data:&[u8]|data.chunks_exact(BPP).filter(|pixel|pixel[3] != 0).count();
+    let mut draw = |input:InputState|{
+        harness.step(input);
+        harness.display_buffer().data
+    };
+    let first = draw(InputState{up:true, ..Default::default()});
+    assert!(covered(&first) > 0);
+    assert!(first.chunks_exact(BPP).all(|pixel|pixel[1] == 0 && pixel[2] == 0));
+    //the same text next frame draws the same pixels, different text replaces it
+    assert_eq!(draw(InputState{up:true, ..Default::default()}), first);
+    let changed = draw(InputState{down:true, ..Default::default()});
+    assert!(covered(&changed) > covered(&first));
+    //text not sent again is gone the frame after
+    assert_eq!(covered(&draw(InputState::default())), 0);
+}
+
+///lays text out in boxes, checks wrapping, newlines, alignment, truncation and that the measured bounds match what gets drawn
+#[test]
+fn text_layout_wraps_and_aligns(){
+    use crate::{
+        graphics::{parse_ttf_bindings, texthandler::{self, ascii_charset, Text}, textlayout::{layout_text, HorizontalAlign, TextBox, VerticalAlign, ELLIPSIS}},
+        shared::{Point, Size},
+    };
+    let loaded_font = parse_ttf_bindings::load_font("ttf_test.ttf").expect("ttf_test.ttf should load");
+    let font = texthandler::load_font(loaded_font).sized(16.0, &ascii_charset()).unwrap();
+    assert!(font.line_spacing > 0 && font.line_gap >= 0);
+    let texts = |text:&str, text_box:&TextBox|layout_text(&font, text, text_box).lines.into_iter().map(|line|line.text).collect::<Vec<_>>();
+    //wide enough for two words a line
+    let width = font.measure("aaa bbb").width;
+    assert_eq!(texts("aaa bbb ccc ddd e", &TextBox::with_width(width)), ["aaa bbb", "ccc ddd", "e"]);
+    //newlines always break, blank lines are kept
+    assert_eq!(texts("aaa\n\nbbb", &TextBox::with_width(width)), ["aaa", "", "bbb"]);
+    //a word too long for any line is broken between chars
+    let long = texts("abcdefghijklmnop", &TextBox::with_width(width));
+    assert!(long.len() > 1 && long.concat() == "abcdefghijklmnop");
+    assert!(long.iter().all(|line|font.measure(line).width <= width));
+    //lines are spaced from the fonts metrics and the bounds cover all of them
+    let layout = layout_text(&font, "aaa bbb ccc ddd e", &TextBox::with_width(width));
+    assert_eq!(layout.lines[1].y - layout.lines[0].y, font.line_spacing);
+    assert_eq!(layout.size().height, 2*font.line_spacing as usize + font.line_height());
+    assert!(!layout.truncated);
+    let spaced = layout_text(&font, "aaa bbb ccc ddd e", &TextBox::with_width(width).with_line_spacing(2.0));
+    assert_eq!(spaced.lines[1].y, (font.line_spacing as f32*2.0).round() as i32);
+
+    //alignment inside a box bigger than the text
+    let size = Size::is(width*2, font.line_height()*4);
+    let placed = |align:HorizontalAlign, vertical_align:VerticalAlign|{
+        let layout = layout_text(&font, "aaa", &TextBox::new(size).aligned(align, vertical_align));
+        (layout.bounds.x0, layout.bounds.y0, layout.bounds.x1, layout.bounds.y1)
+    };
+    let line_width = font.measure("aaa").width as i32;
+    let line_height = font.line_height() as i32;
+    let (box_width, box_height) = (size.width as i32, size.height as i32);
+    assert_eq!(placed(HorizontalAlign::Left, VerticalAlign::Top), (0, 0, line_width, line_height));
+    assert_eq!(placed(HorizontalAlign::Right, VerticalAlign::Bottom), (box_width - line_width, box_height - line_height, box_width, box_height));
+    let (x0, y0, _, _) = placed(HorizontalAlign::Center, VerticalAlign::Middle);
+    assert_eq!((x0, y0), ((box_width - line_width)/2, (box_height - line_height)/2));
+
+    //lines that dont fit under the box are dropped, the last one left ends in an ellipsis
+    let short = TextBox::new(Size::is(width, font.line_height())).with_ellipsis();
+    let cut = layout_text(&font, "aaa bbb ccc ddd", &short);
+    assert!(cut.truncated);
+    assert_eq!(cut.lines.len(), 1);
+    assert!(cut.lines[0].text.ends_with(ELLIPSIS) && cut.lines[0].width <= width);
+    //unwrapped lines are cut short instead
+    let unwrapped = layout_text(&font, "aaa bbb ccc ddd", &TextBox::with_width(width).unwrapped().with_ellipsis());
+    assert_eq!(unwrapped.lines.len(), 1);
+    assert!(unwrapped.lines[0].text.starts_with("aaa") && unwrapped.lines[0].text.ends_with(ELLIPSIS));
+
+    //drawing the text gives a sprite the size that was measured, placed where the bounds say
+    let text = Text::new(font.clone(), "aaa bbb ccc", Point::at(0.0, 0.0), [255,255,255,255]).in_box(TextBox::new(size).aligned(HorizontalAlign::Center, VerticalAlign::Bottom));
+    let layout = text.layout();
+    let (sprite, offset) = text.render();
+    assert_eq!(sprite.size, layout.size());
+    assert_eq!(offset, Point::at(layout.bounds.x0 as f32, layout.bounds.y0 as f32));
+}
+
+///reads glyph metrics straight from the font and checks they line up with the rasterized glyphs
+#[test]
+fn font_metrics_match_glyphs(){
+    use crate::graphics::{parse_ttf_bindings::*, texthandler::{self, ascii_charset}};
+    let mut loaded_font = load_font("ttf_test.ttf").expect("ttf_test.ttf should load");
+    let scale = scale_for_pixel_height(&mut loaded_font, 16.0);
+    //missing glyphs are told apart from ones the font has
+    assert!(find_glyph_index(&mut loaded_font, 'A').is_some());
+    assert!(find_glyph_index(&mut loaded_font, '\u{10ffff}').is_none());
+    let (ascent, descent, line_gap) = get_font_vmetrics(&mut loaded_font);
+    assert!(ascent > 0 && descent < 0 && line_gap >= 0);
+    let (advance, left_side_bearing) = get_codepoint_hmetrics(&mut loaded_font, 'A');
+    assert!(advance > 0);
+    //the outline box starts at the left side bearing and sits inside the box of the whole font
+    let outline = get_codepoint_box(&mut loaded_font, 'A').unwrap();
+    assert_eq!(outline.x0, left_side_bearing);
+    let font_box = get_font_bounding_box(&mut loaded_font);
+    assert_eq!(outline.union(&font_box), font_box);
+    assert!(get_codepoint_box(&mut loaded_font, ' ').is_none());
+    //the bitmap box is the same size and offset the rasterized glyph comes out at
+    for char in ['A', 'g', 'j', '.', '~']{
+        let bitmap_box = get_codepoint_bitmap_box(&mut loaded_font, char, scale);
+        let glyph = generate_glyph(&mut loaded_font, char, 16.0).unwrap();
+        assert_eq!((bitmap_box.x0, bitmap_box.y0), (glyph.xoff, glyph.yoff), "{char}");
+        assert_eq!((bitmap_box.width(), bitmap_box.height()), (glyph.width, glyph.height), "{char}");
+    }
+    //measuring without rasterizing gives the same width as the sized font
+    let mut font = texthandler::load_font(loaded_font);
+    let sized = font.sized(16.0, &ascii_charset()).unwrap();
+    for text in ["Hello world", "AVATAR", "j.", "  "]{
+        assert_eq!(font.measure(16.0, text).width, sized.measure(text).width, "{text}");
+    }
+    assert!(font.has_glyph('A') && !font.has_glyph('\u{10ffff}'));
+}
+
+///loads the same font by name, by path and from bytes, and checks bad data is turned away on every route
+#[test]
+fn fonts_load_from_paths_and_bytes(){
+    use std::path::Path;
+    use crate::graphics::{parse_ttf_bindings::*, texthandler::{self, ascii_charset}};
+    static TTF_TEST:&[u8] = include_bytes!("../../fonts/ttf_test.ttf");
+    let fonts = Path::new(env!("CARGO_MANIFEST_DIR")).join("fonts");
+    let by_name = load_font("ttf_test.ttf").expect("ttf_test.ttf should load by name");
+    let by_path = load_font_from_path(&fonts.join("ttf_test.ttf")).expect("ttf_test.ttf should load by path");
+    let from_bytes = load_font_from_bytes(TTF_TEST, "ttf_test.ttf").expect("ttf_test.ttf should load from bytes");
+    assert_eq!(from_bytes.font_size, TTF_TEST.len());
+    //static data is used in place, not copied
+    assert_eq!(from_bytes.font_ptr as *const u8, TTF_TEST.as_ptr());
+    //all three rasterize the same text
+    let render = |loaded_font|texthandler::load_font(loaded_font).sized(12.0, &ascii_charset()).unwrap().render("Hello", [255,255,255,255]).0.data.to_vec();
+    let rendered = render(by_name);
+    assert_eq!(render(by_path), rendered);
+    assert_eq!(render(from_bytes), rendered);
+    //fonts loaded from bytes are dropped without freeing the static data, loading again still works
+    assert!(load_font_from_bytes(TTF_TEST, "ttf_test.ttf").is_ok());
+    //missing files, directories, non fonts and buffers too short for a header all fail cleanly
+    assert!(load_font_from_path(&fonts.join("nonexistent_font.ttf")).is_err());
+    assert!(load_font_from_path(&fonts).is_err());
+    assert!(load_font_from_path(&fonts.join("invalid_font.ttf")).is_err());
+    assert!(load_font_from_bytes(b"", "empty").is_err());
+    assert!(load_font_from_bytes(b"\x00\x01", "short").is_err());
+    assert!(load_font_from_bytes(b"not a font at all", "text").is_err());
+}
+
+///packs the test fonts into a collection, lists its faces and picks them by index and by name
+#[test]
+fn collections_list_and_select_faces(){
+    use std::fs;
+    use crate::graphics::{parse_ttf_bindings::*, texthandler::{self, ascii_charset}};
+    let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fonts");
+    let members:Vec<Vec<u8>> = ["ttf_test.ttf", "otf_test.otf"].iter().map(|name|fs::read(root.join(name)).unwrap()).collect();
+    //ttcf header, version 1.0, face count and an offset per face. every face is copied in after it with its table offsets moved to match
+    let mut collection = b"ttcf\x00\x01\x00\x00".to_vec();
+    collection.extend_from_slice(&(members.len() as u32).to_be_bytes());
+    let mut offset = collection.len() + 4*members.len();
+    let mut faces = Vec::new();
+    for member in members.iter(){
+        collection.extend_from_slice(&(offset as u32).to_be_bytes());
+        let mut face = member.clone();
+        let tables = u16::from_be_bytes([face[4], face[5]]) as usize;
+        for table in 0..tables{
+            let record = 12 + table*16 + 8;
+            let table_offset = u32::from_be_bytes(face[record..record + 4].try_into().unwrap()) + offset as u32;
+            face[record..record + 4].copy_from_slice(&table_offset.to_be_bytes());
+        }
+        face.resize(face.len().next_multiple_of(4), 0);
+        offset += face.len();
+        faces.push(face);
+    }
+    collection.extend(faces.concat());
+    let collection:&'static [u8] = Box::leak(collection.into_boxed_slice());
+
+    //names straight from the fonts on their own to compare against
+    let names:Vec<(String, String)> = ["ttf_test.ttf", "otf_test.otf"].iter().map(|name|{
+        let mut font = load_font(name).unwrap();
+        assert_eq!(face_count(&mut font), 1);
+        (get_font_name(&mut font, NAME_FAMILY).unwrap(), get_font_name(&mut font, NAME_STYLE).unwrap())
+    }).collect();
+    let mut font = load_font_from_bytes(collection, "collection.ttc").expect("collection should load");
+    assert_eq!(font.get_format(), "TrueTypeCollection");
+    assert_eq!(face_count(&mut font), 2);
+    let listed = list_faces(&mut font);
+    assert_eq!(listed.iter().map(|face|(face.family.clone(), face.style.clone())).collect::<Vec<_>>(), names);
+    assert_eq!(font.face(), 0);
+    //switching faces changes what gets read, faces that dont exist leave the current one alone
+    select_face(&mut font, 1).unwrap();
+    assert_eq!(font.face(), 1);
+    assert_eq!(get_font_name(&mut font, NAME_FAMILY).unwrap(), names[1].0);
+    assert!(select_face(&mut font, 2).is_err());
+    assert_eq!(font.face(), 1);
+    //selecting by name, full name first
+    select_face_named(&mut font, &format!("{} {}", names[0].0, names[0].1)).unwrap();
+    assert_eq!(font.face(), 0);
+    select_face_named(&mut font, &names[1].0.to_uppercase()).unwrap();
+    assert_eq!(font.face(), 1);
+    assert!(select_face_named(&mut font, "no such face").is_err());
+    //the second face rasterizes the same as the font it came from
+    let render = |loaded_font|texthandler::load_font(loaded_font).sized(12.0, &ascii_charset()).unwrap().render("Face", [255,255,255,255]).0.data.to_vec();
+    assert_eq!(render(font), render(load_font("otf_test.otf").unwrap()));
+    //a collection header with nothing after it has no faces to load
+    assert!(load_font_from_bytes(b"ttcf\x00\x01\x00\x00\x00\x00\x00\x09", "broken.ttc").is_err());
+}
+
+///runs good, broken and fuzzed fonts through the validator, nothing malformed should make it to stbtt
+#[test]
+fn font_validation_rejects_malformed_files(){
+    use std::{fs, path::Path};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use crate::graphics::{parse_ttf_bindings::{load_font, load_font_from_bytes}, sfnt::{checksum, table_directory, validate_font, FontError, Tag}};
+    let fonts = Path::new(env!("CARGO_MANIFEST_DIR")).join("fonts");
+    for name in ["ttf_test.ttf", "otf_test.otf", "legacy_macos_test.ttf"]{
+        validate_font(&fs::read(fonts.join(name)).unwrap()).unwrap_or_else(|error|panic!("{name}: {error}"));
+    }
+    assert!(matches!(load_font("invalid_font.ttf"), Err(FontError::UnknownFormat(_))));
+    assert!(matches!(load_font("nonexistent_font.ttf"), Err(FontError::Io(_))));
+    let font = fs::read(fonts.join("ttf_test.ttf")).unwrap();
+    let tables = table_directory(&font, 0).unwrap();
+    let record_of = |tag:&[u8;4]|tables.iter().position(|record|record.tag == Tag(*tag)).unwrap();
+    //writes value as a big endian u32 at at in a copy of the font
+    let patched = |at:usize, value:u32|{
+        let mut font = font.clone();
+        font[at..at + 4].copy_from_slice(&value.to_be_bytes());
+        font
+    };
+    let record = |tag:&[u8;4]|12 + record_of(tag)*16;
+    //tables pointing or reaching past the end
+    assert!(matches!(validate_font(&patched(record(b"glyf") + 8, font.len() as u32)), Err(FontError::TableOutOfBounds(Tag(tag))) if &tag == b"glyf"));
+    assert!(matches!(validate_font(&patched(record(b"loca") + 12, u32::MAX)), Err(FontError::TableOutOfBounds(_))));
+    //renaming a table loses it, renaming it to another tables name lists that one twice
+    assert!(matches!(validate_font(&patched(record(b"cmap"), u32::from_be_bytes(*b"cmaq"))), Err(FontError::MissingTable(Tag(tag))) if &tag == b"cmap"));
+    assert!(matches!(validate_font(&patched(record(b"prep"), u32::from_be_bytes(*b"cmap"))), Err(FontError::DuplicateTable(_))));
+    //changing a table trips its checksum, fixing that up still trips the checksum of the whole file
+    let glyf = tables[record_of(b"glyf")];
+    let mut corrupted = font.clone();
+    corrupted[glyf.offset + glyf.length/2] ^= 0x55;
+    assert!(matches!(validate_font(&corrupted), Err(FontError::ChecksumMismatch{tag:Tag(tag), ..}) if &tag == b"glyf"));
+    let fixed_checksum = checksum(&corrupted[glyf.offset..glyf.offset + glyf.length]);
+    corrupted[record(b"glyf") + 4..record(b"glyf") + 8].copy_from_slice(&fixed_checksum.to_be_bytes());
+    assert!(matches!(validate_font(&corrupted), Err(FontError::FileChecksumMismatch{..})));
+    //fixes up the directory checksum of a changed table so only what changed inside it gets looked at
+    let rechecked = |mut font:Vec<u8>, tag:&[u8;4]|{
+        let table = tables[record_of(tag)];
+        let mut sum = checksum(&font[table.offset..table.offset + table.length]);
+        if tag == b"head"{
+            sum = sum.wrapping_sub(u32::from_be_bytes(font[table.offset + 8..table.offset + 12].try_into().unwrap()));
+        }
+        font[record(tag) + 4..record(tag) + 8].copy_from_slice(&sum.to_be_bytes());
+        font
+    };
+    let bad_table = |font:Vec<u8>, tag:&[u8;4]|matches!(validate_font(&rechecked(font, tag)), Err(FontError::BadTable(Tag(bad), _)) if &bad == tag);
+    //a head table that isnt one
+    let head = tables[record_of(b"head")];
+    assert!(bad_table(patched(head.offset + 12, 0), b"head"));
+    //offsets stbtt follows inside tables, no horizontal metrics, a glyph past the end of glyf and a cmap subtable outside cmap
+    let hhea = tables[record_of(b"hhea")];
+    let mut no_metrics = font.clone();
+    no_metrics[hhea.offset + 34..hhea.offset + 36].copy_from_slice(&[0, 0]);
+    assert!(bad_table(no_metrics, b"hhea"));
+    assert!(bad_table(patched(tables[record_of(b"loca")].offset + 4, u32::MAX), b"loca"));
+    assert!(bad_table(patched(tables[record_of(b"cmap")].offset + 8, u32::MAX), b"cmap"));
+    //every cut short copy is turned away
+    for len in (0..font.len()).step_by(97).chain([1, 4, 11, 12, font.len() - 1]){
+        assert!(validate_font(&font[..len]).is_err(), "{len} bytes");
+    }
+    //random bytes changed anywhere, including the directory, are always caught and never panic
+    let mut rng = StdRng::seed_from_u64(20);
+    let directory_end = 12 + tables.len()*16;
+    for round in 0..2000{
+        let mut fuzzed = font.clone();
+        for _ in 0..rng.random_range(1..=8){
+            //half the time aims at the directory, where offsets and lengths live
+            let at = if round % 2 == 0 {rng.random_range(0..directory_end)} else {rng.random_range(0..fuzzed.len())};
+            fuzzed[at] = fuzzed[at].wrapping_add(rng.random_range(1..=255));
+        }
+        if fuzzed == font{continue;}
+        assert!(validate_font(&fuzzed).is_err(), "fuzz round {round} was accepted");
+    }
+    //and never make it past loading
+    let fuzzed:&'static [u8] = Box::leak(patched(record(b"hmtx") + 8, font.len() as u32 - 2).into_boxed_slice());
+    assert!(load_font_from_bytes(fuzzed, "fuzzed.ttf").is_err());
+}
+
+///loads the same two glyphs from a bdf, both psf versions and a font sheet, they should all draw the same crisp pixels
+#[test]
+fn bitmap_fonts_load_into_sized_fonts(){
+    use crate::{
+        graphics::{bitmapfont::{parse_bdf, parse_psf, BitmapFontError, FontSheet}, sprites::Sprite, texthandler::{SizedFont, Text}},
+        shared::{Point, Size, BPP},
+    };
+    //a 4x4 box and a 4x4 diagonal, 4 wide cells with nothing below the baseline
+    const BOX:[u8;4] = [0xf0, 0x90, 0x90, 0xf0];
+    const SLASH:[u8;4] = [0x10, 0x20, 0x40, 0x80];
+    let bdf = format!("STARTFONT 2.1\nFONT test\nSIZE 4 75 75\nFONTBOUNDINGBOX 4 4 0 0\nSTARTPROPERTIES 2\nFONT_ASCENT 4\nFONT_DESCENT 0\nENDPROPERTIES\nCHARS 3\n\
+        STARTCHAR box\nENCODING 65\nDWIDTH 5 0\nBBX 4 4 0 0\nBITMAP\n{}\nENDCHAR\n\
+        STARTCHAR slash\nENCODING 47\nDWIDTH 5 0\nBBX 4 4 0 0\nBITMAP\n{}\nENDCHAR\n\
+        STARTCHAR unencoded\nENCODING -1\nDWIDTH 5 0\nBBX 4 4 0 0\nBITMAP\n{}\nENDCHAR\nENDFONT\n",
+        BOX.map(|row|format!("{row:02X}")).join("\n"), SLASH.map(|row|format!("{row:02X}")).join("\n"), BOX.map(|row|format!("{row:02X}")).join("\n"));
+    let bdf = parse_bdf(&bdf).unwrap();
+    assert!(bdf.glyph('A').is_some() && bdf.glyph('/').is_some());
+    assert_eq!(bdf.glyphs_len(), 2);
+    assert_eq!((bdf.ascent, bdf.descent, bdf.line_height()), (4, 0, 4));
+    //psf1 with 256 glyphs and no unicode table, glyph n is char n
+    let mut psf1 = vec![0x36, 0x04, 0x00, 4];
+    psf1.extend((0..256).flat_map(|index|match index{65 => BOX, 47 => SLASH, _ => [0;4]}));
+    let psf1 = parse_psf(&psf1).unwrap();
+    //psf2 with two glyphs and a unicode table, the slash also draws as a backslash
+    let mut psf2 = vec![0x72, 0xb5, 0x4a, 0x86];
+    for field in [0u32, 32, 1, 2, 4, 4, 4]{
+        psf2.extend_from_slice(&field.to_le_bytes());
+    }
+    psf2.extend(BOX.iter().chain(SLASH.iter()));
+    psf2.extend(b"A\xff/\\\xff");
+    let psf2 = parse_psf(&psf2).unwrap();
+    assert!(psf2.glyph('\\').is_some() && psf2.glyph('B').is_none());
+    //font sheet, a 2x1 grid of 4x4 cells. the proportional one trims the slash to what it draws
+    let mut sheet = vec![0u8;8*4*BPP];
+    for (cell, rows) in [BOX, SLASH].iter().enumerate(){
+        for (y, row) in rows.iter().enumerate(){
+            for x in 0..4{
+                if row & (0x80 >> x) != 0{
+                    let at = (y*8 + cell*4 + x)*BPP;
+                    sheet[at..at + BPP].copy_from_slice(&[255,255,255,255]);
+                }
+            }
+        }
+    }
+    let sheet = Sprite::from_rgba(sheet, Size::is(8, 4));
+    let monospaced = FontSheet::new(Size::is(4, 4), "A/").load(&sheet).unwrap();
+    let proportional = FontSheet::new(Size::is(4, 4), "A/").proportional(1).load(&sheet).unwrap();
+    assert_eq!(proportional.glyph('A').unwrap().advance, 5.0);
+    assert!(FontSheet::new(Size::is(4, 4), "A/B").load(&sheet).is_err());
+    //every font draws the text the same, with no partly covered pixels
+    let draw = |font:&SizedFont|Text::new(std::sync::Arc::new(font.clone()), "A/A", Point::at(0.0, 0.0), [255,255,255,255]).render().0.data.to_vec();
+    let drawn = draw(&bdf);
+    assert!(drawn.chunks_exact(BPP).all(|pixel|pixel[3] == 0 || pixel[3] == 255));
+    assert_eq!(drawn.chunks_exact(BPP).filter(|pixel|pixel[3] == 255).count(), 2*12 + 4);
+    //psf glyphs are a whole cell apart with no gap, psf1 cells are always 8 wide
+    assert_eq!(psf1.measure("A/A").width, 24);
+    assert_eq!(psf2.measure("A/A").width, 12);
+    for font in [&psf1, &psf2]{
+        assert_eq!(draw(font).chunks_exact(BPP).filter(|pixel|pixel[3] == 255).count(), 2*12 + 4);
+    }
+    assert_eq!(draw(&proportional), drawn);
+    assert_eq!(monospaced.measure("A/A").width, 12);
+    //broken files are turned away instead of panicking
+    assert!(parse_bdf("not a font").is_err());
+    assert!(parse_bdf("STARTFONT 2.1\nFONTBOUNDINGBOX 4 4 0 0\nSTARTCHAR a\nENCODING 97\nBBX 4 4 0 0\nBITMAP\nZZ\nENDCHAR\nENDFONT").is_err());
+    //half a byte of hex, a bbx past the font and a bbx with rows missing are malformed rather than guessed at or allocated
+    let glyph = |bbx:&str, rows:&str|parse_bdf(&format!("STARTFONT 2.1\nFONTBOUNDINGBOX 4 4 0 0\nSTARTCHAR a\nENCODING 97\nBBX {bbx}\nBITMAP\n{rows}\nENDCHAR\nENDFONT"));
+    assert!(glyph("4 4 0 0", "F0\n90\n90\nF0").is_ok());
+    assert!(matches!(glyph("4 4 0 0", "F0\n90\n9\nF0"), Err(BitmapFontError::Malformed(_))));
+    assert!(matches!(glyph("4 100000000 0 0", "F0\n90\n90\nF0"), Err(BitmapFontError::Malformed(_))));
+    assert!(matches!(glyph("4 4 0 0", "F0\n90"), Err(BitmapFontError::Malformed(_))));
+    assert!(parse_psf(&[0x36, 0x04, 0x00, 16, 0xff]).is_err());
+    assert!(parse_psf(&[0x72, 0xb5, 0x4a, 0x86, 0]).is_err());
+    //a psf2 header claiming u32::MAX glyphs of 0x0 pixels is turned away before anything is allocated for them
+    let empty_glyphs:Vec<u8> = [0x864ab572u32, 0, 32, 0, u32::MAX, 0, 0, 0].iter().flat_map(|field|field.to_le_bytes()).collect();
+    assert_eq!(empty_glyphs.len(), 32);
+    assert!(matches!(parse_psf(&empty_glyphs), Err(BitmapFontError::Malformed(_))));
+}
+
+///rasterizes glyphs through the cache as text asks for them and checks the budget holds while font sizes animate
+#[test]
+fn glyph_cache_rasterizes_lazily_and_evicts(){
+    use std::sync::Arc;
+    use crate::graphics::{glyphcache::GlyphCache, parse_ttf_bindings::load_font, texthandler::{self, ascii_charset}};
+    let mut cache = GlyphCache::new(1 << 20);
+    let font = cache.add_font(load_font("ttf_test.ttf").unwrap());
+    assert!(cache.is_empty());
+    //only the chars of the text get rasterized, asking again reuses them
+    let sized = cache.sized(font, 16.0, "Hello").unwrap();
+    assert_eq!(cache.len(), 4);
+    let used = cache.used_bytes();
+    assert!(used > 0);
+    assert!(Arc::ptr_eq(&sized, &cache.sized(font, 16.0, "Hell").unwrap()));
+    assert_eq!((cache.len(), cache.used_bytes()), (4, used));
+    //glyphs come out the same as the ones rasterized up front for a whole charset
+    let full = texthandler::load_font(load_font("ttf_test.ttf").unwrap()).sized(16.0, &ascii_charset()).unwrap();
+    for char in "Helo".chars(){
+        let (cached, upfront) = (sized.glyph(char).unwrap(), full.glyph(char).unwrap());
+        assert_eq!((cached.size, cached.xoff, cached.yoff, cached.advance), (upfront.size, upfront.xoff, upfront.yoff, upfront.advance));
+        assert_eq!(sized.coverage(cached), full.coverage(upfront));
+    }
+    assert_eq!(sized.measure("Hello").width, full.measure("Hello").width);
+    assert_eq!(sized.line_spacing, full.line_spacing);
+    //every size is its own glyph, missing chars are left out
+    cache.sized(font, 17.0, "H\u{10ffff}").unwrap();
+    assert!(cache.contains(font, 16.0, 'H') && cache.contains(font, 17.0, 'H'));
+    assert!(cache.glyph(font, 16.0, '\u{10ffff}').unwrap().is_none());
+    //lines are as tall as the font says whatever glyphs the text has
+    let short = cache.sized(font, 18.0, "o").unwrap();
+    let tall = cache.sized(font, 18.0, "Hl").unwrap();
+    assert_eq!((short.ascent, short.descent), (tall.ascent, tall.descent));
+    assert!(short.ascent > -short.glyph('o').unwrap().yoff);
+
+    //a budget a few glyphs big, the least recently used go first
+    let mut cache = GlyphCache::new(used*2);
+    let font = cache.add_font(load_font("ttf_test.ttf").unwrap());
+    cache.glyph(font, 16.0, 'W').unwrap();
+    for char in "abcdefghijklmnopqrstuvwxyz".chars(){
+        //keeps W in use so it outlives everything else
+        cache.glyph(font, 16.0, 'W').unwrap();
+        cache.glyph(font, 16.0, char).unwrap();
+        assert!(cache.used_bytes() <= cache.budget());
+        assert!(cache.atlas_bytes() <= cache.budget());
+    }
+    assert!(cache.contains(font, 16.0, 'W') && cache.contains(font, 16.0, 'z'));
+    assert!(!cache.contains(font, 16.0, 'a'));
+    //animating the size keeps to the budget too, and what survives still matches a fresh rasterization
+    for step in 0..60{
+        let font_size = 10.0 + step as f32*0.5;
+        let sized = cache.sized(font, font_size, "Zoom").unwrap();
+        assert!(cache.used_bytes() <= cache.budget() && cache.atlas_bytes() <= cache.budget());
+        let glyph = cache.glyph(font, font_size, 'Z').unwrap().cloned().unwrap();
+        assert_eq!(cache.coverage(&glyph), sized.coverage(sized.glyph('Z').unwrap()));
+    }
+    let check = texthandler::load_font(load_font("ttf_test.ttf").unwrap()).sized(16.0, &['W']).unwrap();
+    let glyph = cache.glyph(font, 16.0, 'W').unwrap().cloned().unwrap();
+    assert_eq!(cache.coverage(&glyph), check.coverage(check.glyph('W').unwrap()));
+    cache.evict_font(font);
+    assert!(cache.is_empty());
+    assert_eq!((cache.used_bytes(), cache.atlas_bytes()), (0, 0));
+}
+
+///text with chars the primary font lacks draws them from fallbacks, and chars no font has as tofu
+#[test]
+fn font_stack_falls_back_and_draws_tofu(){
+    use std::sync::Arc;
+    use crate::{graphics::{fontstack::{tofu, FontStack, Replacement}, parse_ttf_bindings::*, texthandler::{self, Text}}, shared::Point};
+    let mut primary = load_font("ttf_test.ttf").unwrap();
+    let mut fallback = load_font("otf_test.otf").unwrap();
+    //a char only the fallback has, whatever the test fonts happen to cover
+    let only_fallback = (0x21u32..0x3000).filter_map(char::from_u32)
+        .find(|&char|find_glyph_index(&mut primary, char).is_none() && find_glyph_index(&mut fallback, char).is_some() && !is_glyph_empty(&mut fallback, char))
+        .expect("otf_test.otf should have a char ttf_test.ttf doesnt");
+    let missing = '\u{10ffff}';
+    let mut stack = FontStack::new(primary).with_fallback(fallback);
+    assert_eq!(stack.len(), 2);
+    assert_eq!((stack.font_for('A'), stack.font_for(only_fallback), stack.font_for(missing)), (Some(0), Some(1), None));
+    let charset = ['A', ' ', only_fallback, missing];
+    let sized = stack.sized(16.0, &charset).unwrap();
+    //chars from the primary font and line metrics match a font of its own, the fallback char comes from the fallback
+    let own = texthandler::load_font(load_font("ttf_test.ttf").unwrap()).sized(16.0, &['A', ' ']).unwrap();
+    assert_eq!(sized.coverage(sized.glyph('A').unwrap()), own.coverage(own.glyph('A').unwrap()));
+    assert_eq!((sized.line_spacing, sized.line_gap), (own.line_spacing, own.line_gap));
+    let from_fallback = texthandler::load_font(load_font("otf_test.otf").unwrap()).sized(16.0, &[only_fallback]).unwrap();
+    assert_eq!(sized.coverage(sized.glyph(only_fallback).unwrap()), from_fallback.coverage(from_fallback.glyph(only_fallback).unwrap()));
+    //missing chars are an outlined box
+    let glyph = sized.glyph(missing).unwrap();
+    let mut check = load_font("ttf_test.ttf").unwrap();
+    let ascent = (get_font_vmetrics(&mut check).0 as f32*scale_for_pixel_height(&mut check, 16.0)).round() as i32;
+    let expected = tofu(16.0, ascent);
+    assert_eq!(glyph.size, expected.size);
+    assert_eq!(sized.coverage(glyph), &expected.coverage[..]);
+    assert!(sized.coverage(glyph).contains(&255) && sized.coverage(glyph).contains(&0));
+    //lines are at least as tall as the primary font says, even with only a short glyph to go on
+    let short = stack.sized(16.0, &['.']).unwrap();
+    let descent = (-get_font_vmetrics(&mut check).1 as f32*scale_for_pixel_height(&mut check, 16.0)).round() as i32;
+    assert!(short.ascent >= ascent && short.descent >= descent);
+    //the other replacements
+    let skipped = stack.with_replacement(Replacement::Skip).sized(16.0, &charset).unwrap();
+    assert!(skipped.glyph(missing).is_none() && skipped.glyph('A').is_some());
+    let mut stack = FontStack::new(load_font("ttf_test.ttf").unwrap()).with_replacement(Replacement::Char('?'));
+    let replaced = stack.sized(16.0, &['?', missing]).unwrap();
+    assert_eq!(replaced.coverage(replaced.glyph(missing).unwrap()), replaced.coverage(replaced.glyph('?').unwrap()));
+    //mixed text draws without leaving anything out
+    let text = Text::new(Arc::new(sized), format!("A {only_fallback}{missing}"), Point::at(0.0, 0.0), [255, 255, 255, 255]);
+    let (sprite, _) = text.render();
+    assert!(sprite.size.width >= 3 && sprite.data.to_vec().iter().any(|&value|value != 0));
+}
+
+///distance fields rasterized once draw at any size, with outlines, shadows and glows worked out from them
+#[test]
+fn sdf_text_scales_and_draws_effects(){
+    use std::sync::Arc;
+    use crate::{graphics::{parse_ttf_bindings::load_font, sdf::{create_sdf_font, TextEffects}, texthandler::{self, ascii_charset, Text}}, shared::Point};
+    let mut loaded_font = load_font("ttf_test.ttf").unwrap();
+    let sdf = Arc::new(create_sdf_font(&mut loaded_font, 32.0, 6, &ascii_charset()).unwrap());
+    let field = sdf.field(sdf.glyph('O').unwrap());
+    //the middle of the ring is outside the glyph and the ring itself is inside
+    assert!(field.iter().any(|&value|value > 128) && field.iter().any(|&value|value < 128));
+    assert_eq!(sdf.glyph(' ').unwrap().size.pixels(), 0);
+    //resampled sizes measure the same as fonts rasterized straight at those sizes, give or take rounding
+    let mut font = texthandler::load_font(load_font("ttf_test.ttf").unwrap());
+    for font_size in [12.0, 24.0, 48.0]{
+        let (resampled, rasterized) = (sdf.sized(font_size), font.sized(font_size, &ascii_charset()).unwrap());
+        let (width, expected) = (resampled.measure("Hello world").width as i32, rasterized.measure("Hello world").width as i32);
+        assert!((width - expected).abs() <= 2, "{font_size}: {width} against {expected}");
+        assert_eq!(resampled.line_spacing, rasterized.line_spacing);
+        assert_eq!(resampled.ascent, rasterized.ascent);
+        let glyph = resampled.glyph('H').unwrap();
+        assert!(resampled.coverage(glyph).contains(&255));
+    }
+    //plain sdf text covers the same pixels as the sized font drawn on its own
+    let white = [255, 255, 255, 255];
+    let metrics = sdf.sized(24.0);
+    let (plain, plain_at) = sdf.render(&metrics, "Hi", white, &TextEffects::default());
+    let (sized, sized_at) = metrics.render("Hi", white);
+    assert_eq!((plain.size, plain_at), (sized.size, sized_at));
+    let alpha = |sprite:&crate::graphics::sprites::Sprite|sprite.data.to_vec().chunks_exact(4).map(|pixel|pixel[3] as i32).collect::<Vec<i32>>();
+    assert!(alpha(&plain).iter().zip(alpha(&sized)).all(|(a, b)|(a - b).abs() <= 1));
+    //an outline grows the sprite by its width on every side and puts its color around the glyphs
+    let black = [0, 0, 0, 255];
+    let outlined_effects = TextEffects::default().with_outline(2.0, black);
+    let (outlined, outlined_at) = sdf.render(&metrics, "Hi", white, &outlined_effects);
+    assert_eq!((outlined.size.width, outlined.size.height), (plain.size.width + 4, plain.size.height + 4));
+    assert_eq!(outlined_at, Point::at(plain_at.x - 2.0, plain_at.y - 2.0));
+    let pixels = outlined.data.to_vec();
+    let opaque_black = pixels.chunks_exact(4).filter(|pixel|pixel == &[0, 0, 0, 255]).count();
+    let opaque_white = pixels.chunks_exact(4).filter(|pixel|pixel == &[255, 255, 255, 255]).count();
+    assert!(opaque_black > 0 && opaque_white > 0);
+    //a hard shadow moves the sprite out by its offset and is drawn where the text isnt
+    let (shadowed, shadowed_at) = sdf.render(&metrics, "Hi", white, &TextEffects::default().with_shadow(Point::at(3.0, 3.0), 0.0, black));
+    assert_eq!((shadowed.size.width, shadowed.size.height, shadowed_at), (plain.size.width + 3, plain.size.height + 3, plain_at));
+    assert!(alpha(&shadowed).iter().sum::<i32>() > alpha(&plain).iter().sum::<i32>());
+    //a glow fades out the further it gets from the text, and never reaches past the padding
+    let (glowing, glowing_at) = sdf.render(&metrics, "Hi", white, &TextEffects::default().with_glow(20.0, [255, 200, 0, 255]));
+    let reach = sdf.reach(24.0).ceil();
+    assert_eq!(glowing_at, Point::at(plain_at.x - reach, plain_at.y - reach));
+    let glow_alpha = alpha(&glowing);
+    let row = glowing.size.height/2*glowing.size.width;
+    assert_eq!(glow_alpha[row], 0);
+    assert!(glow_alpha[row + 2] < glow_alpha[row + reach as usize]);
+    //sdf text goes through Text like any other, effects and size included
+    let text = Text::sdf(&sdf, 24.0, "Hi", Point::at(0.0, 0.0), white).with_effects(outlined_effects);
+    assert!(text.same_as(&text.clone()) && !text.same_as(&Text::sdf(&sdf, 24.0, "Hi", Point::at(0.0, 0.0), white)));
+    let (sprite, at) = text.render();
+    assert_eq!((sprite.data.to_vec(), at), (outlined.data.to_vec(), outlined_at));
+    assert_eq!(Text::sdf(&sdf, 48.0, "Hi", Point::at(0.0, 0.0), white).font.font_size, 48.0);
+    //the same text sent again next frame is resampled once and reused by the display
+    let frame = ||Text::sdf(&sdf, 20.0, "Hi", Point::at(0.0, 0.0), white).with_effects(outlined_effects);
+    assert!(frame().same_as(&frame()));
+    assert!(Arc::ptr_eq(&sdf.sized(24.0), &sdf.sized(24.0)));
+}
+
+///markup is parsed into styled runs, laid out with inline sprites on the baseline and drawn with per glyph motion
+#[test]
+fn rich_text_markup_styles_runs(){
+    use std::sync::Arc;
+    use crate::{
+        graphics::{
+            parse_ttf_bindings::load_font,
+            richtext::{parse_markup, MarkupError, Motion, RichText, Run, RunStyle, BOLD, MAX_AMOUNT},
+            sprites::Sprite, texthandler::{self, ascii_charset, Text}, textlayout::TextBox,
+        },
+        shared::{Point, Size},
+    };
+    let white = [255, 255, 255, 255];
+    let runs = parse_markup("a [color=#ff0]b[b]c[/b][/color][[d[sprite=apple][wave=3]e[/wave]", white).unwrap();
+    let style = |color, font:Option<&str>, motion|RunStyle{color, font:font.map(str::to_string), motion};
+    assert_eq!(runs, vec![
+        Run::Text{text:"a ".into(), style:style(white, None, None)},
+        Run::Text{text:"b".into(), style:style([255, 255, 0, 255], None, None)},
+        Run::Text{text:"c".into(), style:style([255, 255, 0, 255], Some(BOLD), None)},
+        Run::Text{text:"[d".into(), style:style(white, None, None)},
+        Run::Sprite("apple".into()),
+        Run::Text{text:"e".into(), style:style(white, None, Some(Motion::Wave(3.0)))},
+    ]);
+    //mistakes are caught instead of drawn wrong
+    assert_eq!(parse_markup("[b]a[/i]", white), Err(MarkupError::UnexpectedClose("i".into())));
+    assert_eq!(parse_markup("[b]a", white), Err(MarkupError::Unclosed("b".into())));
+    assert_eq!(parse_markup("a[blink]", white), Err(MarkupError::UnknownTag("blink".into())));
+    assert_eq!(parse_markup("[color=red]", white), Err(MarkupError::BadValue{tag:"color".into(), value:"red".into()}));
+    assert_eq!(parse_markup("[shake=1e9]a[/shake]", white), Err(MarkupError::BadValue{tag:"shake".into(), value:"1e9".into()}));
+    assert!(parse_markup("[wave=64]a[/wave]", white).is_ok());
+    assert_eq!(parse_markup("a [b", white), Err(MarkupError::UnterminatedTag(2)));
+
+    let mut font = texthandler::load_font(load_font("ttf_test.ttf").unwrap());
+    let regular = font.sized(12.0, &ascii_charset()).unwrap();
+    let bold = font.sized(20.0, &ascii_charset()).unwrap();
+    let red = [255, 0, 0, 255];
+    let apple = Sprite::from_rgba(red.repeat(30), Size::is(5, 6));
+    let rich = Arc::new(RichText::new(regular.clone()).with_font(BOLD, bold.clone()).with_sprite("apple", apple));
+    //font switches change the glyphs, sprites stand on the baseline of their line
+    let layout = rich.layout(&parse_markup("Hi[b]Hi[/b][sprite=apple]", white).unwrap(), &TextBox::default()).unwrap();
+    assert_eq!(layout.glyphs.len(), 4);
+    assert!(Arc::ptr_eq(&layout.glyphs[0].font, &regular) && Arc::ptr_eq(&layout.glyphs[2].font, &bold));
+    let baseline = layout.glyphs[0].baseline;
+    assert_eq!(baseline, bold.ascent);
+    assert!(layout.glyphs.iter().all(|glyph|glyph.baseline == baseline));
+    let sprite = &layout.sprites[0];
+    assert_eq!(sprite.y + 6, baseline);
+    assert!(sprite.x > layout.glyphs[3].x && sprite.x <= regular.measure("Hi").width as i32 + bold.measure("Hi").width as i32);
+    assert!(rich.layout(&parse_markup("[font=serif]a[/font]", white).unwrap(), &TextBox::default()).is_err());
+    assert!(rich.layout(&parse_markup("[sprite=pear]", white).unwrap(), &TextBox::default()).is_err());
+    //lines wrap at the box width with runs of every style mixed in
+    let wrapped = rich.layout(&parse_markup("one [color=#0f0]two[/color] three four", white).unwrap(), &TextBox::with_width(regular.measure("one two").width)).unwrap();
+    let lines:std::collections::BTreeSet<i32> = wrapped.glyphs.iter().map(|glyph|glyph.baseline).collect();
+    assert_eq!(lines.len(), 3);
+    assert!(wrapped.bounds.width() as usize <= regular.measure("one two").width);
+    //colors and sprites end up in the drawn pixels
+    let (drawn, _) = rich.render_markup("[color=#00f]H[/color][sprite=apple]", white, &TextBox::default(), 0).unwrap();
+    let pixels = drawn.data.to_vec();
+    assert!(pixels.chunks_exact(4).any(|pixel|pixel == red));
+    assert!(pixels.chunks_exact(4).any(|pixel|pixel == [0, 0, 255, 255]));
+    assert!(!pixels.chunks_exact(4).any(|pixel|pixel[1] != 0));
+    //motion moves glyphs from tick to tick without ever leaving the sprite
+    let wave = Motion::Wave(3.0);
+    assert!((0..120).all(|tick|wave.offset(0, tick).1.abs() <= 3) && wave.offset(0, 0) != wave.offset(0, 15));
+    let shake = Motion::Shake(2.0);
+    assert!((0..50).all(|tick|{let (x, y) = shake.offset(tick as usize, tick); x.abs() <= 2 && y.abs() <= 2}));
+    //motions made in code are held to the same cap markup is
+    let wild = Motion::Shake(f32::MAX);
+    assert_eq!(wild.reach(), MAX_AMOUNT as i32);
+    assert!((0..50).all(|tick|{let (x, y) = wild.offset(tick as usize, tick); x.abs() <= wild.reach() && y.abs() <= wild.reach()}));
+    let (still, _) = rich.render_markup("[wave=3]Hello[/wave]", white, &TextBox::default(), 0).unwrap();
+    let (moved, _) = rich.render_markup("[wave=3]Hello[/wave]", white, &TextBox::default(), 15).unwrap();
+    assert_eq!(still.size, moved.size);
+    assert_ne!(still.data.to_vec(), moved.data.to_vec());
+    //markup goes through Text, every tick is its own frame and bad markup is turned down when the text is made
+    let text = Text::markup(&rich, "[wave]Hello[/wave]", Point::at(0.0, 0.0), white, 0).unwrap();
+    assert!(text.same_as(&text.clone()) && !text.same_as(&Text::markup(&rich, "[wave]Hello[/wave]", Point::at(0.0, 0.0), white, 1).unwrap()));
+    let (sprite, _) = Text::markup(&rich, "Hi[sprite=apple]", Point::at(0.0, 0.0), white, 0).unwrap().render();
+    assert!(sprite.data.to_vec().chunks_exact(4).any(|pixel|pixel == red));
+    assert!(matches!(Text::markup(&rich, "[b]Hi", Point::at(0.0, 0.0), white, 0), Err(MarkupError::Unclosed(_))));
+    assert!(matches!(Text::markup(&rich, "Hi[sprite=pear]", Point::at(0.0, 0.0), white, 0), Err(MarkupError::UnknownSprite(_))));
+}